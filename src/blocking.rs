@@ -0,0 +1,319 @@
+//! Synchronous client (`blocking` feature)
+//!
+//! [`TodoistWrapper`] mirrors the async [`crate::TodoistWrapper`] method for
+//! method, with the same arguments and return types but without `async`. Each
+//! call drives the async client to completion on a private single-threaded
+//! Tokio runtime, so models, errors and retry behaviour are shared with it.
+//!
+//! Like `reqwest::blocking`, this client must not be used from within an async
+//! runtime: calling it from async code panics.
+//!
+//! ```rust,no_run
+//! use todoist_api::blocking::TodoistWrapper;
+//!
+//! fn main() -> todoist_api::TodoistResult<()> {
+//!     let todoist = TodoistWrapper::new("your-api-token".to_string());
+//!     let tasks = todoist.get_tasks(None, None)?;
+//!     println!("Found {} tasks", tasks.results.len());
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::models::*;
+use crate::wrapper::{RequestObserver, RetryConfig};
+
+/// A synchronous Todoist client
+///
+/// Cloning is cheap: clones share the HTTP connection pool and the runtime.
+#[derive(Clone)]
+pub struct TodoistWrapper {
+    inner: crate::TodoistWrapper,
+    runtime: Arc<Runtime>,
+}
+
+impl std::fmt::Debug for TodoistWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TodoistWrapper").finish_non_exhaustive()
+    }
+}
+
+impl TodoistWrapper {
+    /// Create a new synchronous Todoist client
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying Tokio runtime cannot be created.
+    #[must_use]
+    pub fn new(api_token: String) -> Self {
+        Self::from(crate::TodoistWrapper::new(api_token))
+    }
+
+    /// Create a new synchronous Todoist client, rejecting an empty token up front
+    ///
+    /// See [`crate::TodoistWrapper::try_new`]. Also returns a `Generic` error,
+    /// instead of panicking, if the underlying Tokio runtime cannot be created.
+    pub fn try_new(api_token: String) -> TodoistResult<Self> {
+        let inner = crate::TodoistWrapper::try_new(api_token)?;
+        let runtime =
+            build_runtime().map_err(|e| generic_error(None, format!("Failed to build Tokio runtime: {}", e)))?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Create a new synchronous Todoist client with a custom base URL
+    ///
+    /// See [`crate::TodoistWrapper::with_base_url`].
+    #[must_use]
+    pub fn with_base_url(api_token: String, base_url: String) -> Self {
+        Self::from(crate::TodoistWrapper::with_base_url(api_token, base_url))
+    }
+
+    /// Enable automatic retries of rate-limited and transiently failing requests
+    ///
+    /// See [`crate::TodoistWrapper::with_retry`].
+    #[must_use]
+    pub fn with_retry(self, config: RetryConfig) -> Self {
+        Self {
+            inner: self.inner.with_retry(config),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Report every request attempt to `observer`
+    ///
+    /// See [`crate::TodoistWrapper::with_observer`].
+    #[must_use]
+    pub fn with_observer(self, observer: impl RequestObserver + 'static) -> Self {
+        Self {
+            inner: self.inner.with_observer(observer),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Fill in the priority, labels and project of created tasks when their arguments leave them unset
+    ///
+    /// See [`crate::TodoistWrapper::with_defaults`].
+    #[must_use]
+    pub fn with_defaults(self, defaults: TaskDefaults) -> Self {
+        Self {
+            inner: self.inner.with_defaults(defaults),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Cache GET responses that carry an `ETag` and revalidate them on the next identical request
+    ///
+    /// See [`crate::TodoistWrapper::with_etag_cache`].
+    #[must_use]
+    pub fn with_etag_cache(self) -> Self {
+        Self {
+            inner: self.inner.with_etag_cache(),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Send extra headers with every request, e.g. a gateway's authentication or tracing headers
+    ///
+    /// See [`crate::TodoistWrapper::with_default_headers`].
+    #[must_use]
+    pub fn with_default_headers(self, headers: reqwest::header::HeaderMap) -> Self {
+        Self {
+            inner: self.inner.with_default_headers(headers),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Drop every response kept by [`Self::with_etag_cache`]
+    pub fn clear_etag_cache(&self) {
+        self.inner.clear_etag_cache()
+    }
+
+    /// Get a copy of this client whose requests time out after `timeout`
+    ///
+    /// See [`crate::TodoistWrapper::with_timeout`].
+    #[must_use]
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        Self {
+            inner: self.inner.with_timeout(timeout),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Get the rate-limit quota reported by the most recent response that carried one
+    ///
+    /// See [`crate::TodoistWrapper::rate_limit_status`].
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.inner.rate_limit_status()
+    }
+}
+
+impl From<crate::TodoistWrapper> for TodoistWrapper {
+    /// Wrap an async client, e.g. one configured with [`crate::TodoistWrapper::builder`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying Tokio runtime cannot be created.
+    fn from(inner: crate::TodoistWrapper) -> Self {
+        let runtime = build_runtime().expect("failed to build Tokio runtime for the blocking client");
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+}
+
+/// Build the private single-threaded runtime that drives the async client
+fn build_runtime() -> std::io::Result<Runtime> {
+    tokio::runtime::Builder::new_current_thread().enable_all().build()
+}
+
+/// Generate synchronous counterparts of async `crate::TodoistWrapper` methods
+macro_rules! blocking_methods {
+    ($( fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty; )*) => {
+        impl TodoistWrapper {
+            $(
+                #[doc = concat!("Blocking version of [`crate::TodoistWrapper::", stringify!($name), "`]")]
+                pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                    self.runtime.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    // ===== SYNC OPERATIONS =====
+    fn sync_commands(&self, commands: &[SyncCommand]) -> TodoistResult<SyncResult>;
+    fn sync(&self, sync_token: &str, resource_types: &[SyncResourceType]) -> TodoistResult<SyncResponse>;
+
+    // ===== USER OPERATIONS =====
+    fn get_user(&self) -> TodoistResult<User>;
+    fn validate_token(&self) -> TodoistResult<bool>;
+
+    // ===== ID MAPPING OPERATIONS =====
+    fn get_id_mappings(&self, object: IdMappingObject, ids: &[&str]) -> TodoistResult<Vec<IdMapping>>;
+
+    // ===== WORKSPACE OPERATIONS =====
+    fn load_workspace(&self) -> TodoistResult<Workspace>;
+    fn get_project_data(&self, project_id: impl AsRef<str>) -> TodoistResult<ProjectData>;
+
+    // ===== PROJECT OPERATIONS =====
+    fn get_projects(&self, limit: Option<i32>, cursor: Option<String>) -> TodoistResult<PaginatedResponse<Project>>;
+    fn get_projects_filtered(&self, args: &ProjectFilterArgs) -> TodoistResult<PaginatedResponse<Project>>;
+    fn get_all_projects(&self) -> TodoistResult<Vec<Project>>;
+    fn get_inbox_project(&self) -> TodoistResult<Project>;
+    fn get_project(&self, project_id: impl AsRef<str>) -> TodoistResult<Project>;
+    fn create_project(&self, args: &CreateProjectArgs) -> TodoistResult<Project>;
+    fn apply_template(&self, template: &ProjectTemplate) -> TodoistResult<Project>;
+    fn update_project(&self, project_id: impl AsRef<str>, args: &UpdateProjectArgs) -> TodoistResult<Project>;
+    fn collapse_project(&self, project_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn expand_project(&self, project_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn set_project_collapsed(&self, project_id: impl AsRef<str>, collapsed: bool) -> TodoistResult<()>;
+    fn reorder_projects(&self, orders: &[(String, i32)]) -> TodoistResult<()>;
+    fn delete_project(&self, project_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn delete_project_with_report(&self, project_id: impl AsRef<str>) -> TodoistResult<DeletionReport>;
+    fn get_collaborators(&self, project_id: impl AsRef<str>) -> TodoistResult<Vec<Collaborator>>;
+    fn invite_collaborator(&self, project_id: impl AsRef<str>, email: &str) -> TodoistResult<()>;
+    fn remove_collaborator(&self, project_id: impl AsRef<str>, email_or_id: &str) -> TodoistResult<()>;
+
+    // ===== TASK OPERATIONS =====
+    fn get_tasks(&self, limit: Option<i32>, cursor: Option<String>) -> TodoistResult<PaginatedResponse<Task>>;
+    fn get_tasks_for_project(
+        &self,
+        project_id: impl AsRef<str>,
+        limit: Option<i32>,
+        cursor: Option<String>
+    ) -> TodoistResult<PaginatedResponse<Task>>;
+    fn get_all_tasks_for_project(&self, project_id: impl AsRef<str>) -> TodoistResult<Vec<Task>>;
+    fn get_task(&self, task_id: impl AsRef<str>) -> TodoistResult<Task>;
+    fn get_subtasks(&self, parent_task_id: impl AsRef<str>) -> TodoistResult<Vec<Task>>;
+    fn get_tasks_by_ids(&self, ids: &[&str]) -> TodoistResult<Vec<Task>>;
+    fn get_tasks_by_ids_with_chunk_size(&self, ids: &[&str], chunk_size: usize) -> TodoistResult<Vec<Task>>;
+    fn get_tasks_by_filter(&self, args: &TaskFilterArgs) -> TodoistResult<PaginatedResponse<Task>>;
+    fn get_tasks_due_today(&self) -> TodoistResult<Vec<Task>>;
+    fn get_overdue_tasks(&self) -> TodoistResult<Vec<Task>>;
+    fn create_task(&self, args: &CreateTaskArgs) -> TodoistResult<Task>;
+    fn create_task_with_request_id(&self, args: &CreateTaskArgs, request_id: impl AsRef<str>) -> TodoistResult<Task>;
+    fn create_tasks(&self, args: &[CreateTaskArgs]) -> Vec<TodoistResult<Task>>;
+    fn create_tasks_with_concurrency(&self, args: &[CreateTaskArgs], concurrency: usize) -> Vec<TodoistResult<Task>>;
+    fn quick_add_task(&self, text: &str) -> TodoistResult<Task>;
+    fn duplicate_task(&self, task_id: impl AsRef<str>, into_project: Option<&str>) -> TodoistResult<Task>;
+    fn duplicate_task_with_options(
+        &self,
+        task_id: impl AsRef<str>,
+        into_project: Option<&str>,
+        options: &DuplicateOptions
+    ) -> TodoistResult<Task>;
+    fn update_task(&self, task_id: impl AsRef<str>, args: &UpdateTaskArgs) -> TodoistResult<Task>;
+    fn move_task(&self, task_id: impl AsRef<str>, args: &MoveTaskArgs) -> TodoistResult<Task>;
+    fn complete_task(&self, task_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn complete_task_returning(&self, task_id: impl AsRef<str>) -> TodoistResult<Task>;
+    fn complete_recurring_task(&self, task_id: impl AsRef<str>) -> TodoistResult<Task>;
+    fn reopen_task(&self, task_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn reopen_task_returning(&self, task_id: impl AsRef<str>) -> TodoistResult<Task>;
+    fn delete_task(&self, task_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn complete_tasks(&self, ids: &[&str]) -> Vec<TodoistResult<()>>;
+    fn delete_tasks(&self, ids: &[&str]) -> Vec<TodoistResult<()>>;
+    fn get_completed_tasks(&self, args: &CompletedTasksFilterArgs) -> TodoistResult<PaginatedResponse<Task>>;
+    fn get_completed_tasks_by_completion_date(
+        &self,
+        args: &CompletedTasksFilterArgs
+    ) -> TodoistResult<PaginatedResponse<Task>>;
+    fn get_completed_tasks_by_due_date(&self, args: &CompletedTasksFilterArgs) -> TodoistResult<PaginatedResponse<Task>>;
+
+    // ===== LABEL OPERATIONS =====
+    fn get_labels(&self, limit: Option<i32>, cursor: Option<String>) -> TodoistResult<PaginatedResponse<Label>>;
+    fn get_labels_filtered(&self, args: &LabelFilterArgs) -> TodoistResult<PaginatedResponse<Label>>;
+    fn get_all_labels(&self) -> TodoistResult<Vec<Label>>;
+    fn get_label_index(&self) -> TodoistResult<LabelIndex>;
+    fn get_label(&self, label_id: impl AsRef<str>) -> TodoistResult<Label>;
+    fn create_label(&self, args: &CreateLabelArgs) -> TodoistResult<Label>;
+    fn update_label(&self, label_id: impl AsRef<str>, args: &UpdateLabelArgs) -> TodoistResult<Label>;
+    fn delete_label(&self, label_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn get_shared_labels(&self, omit_personal: bool) -> TodoistResult<Vec<String>>;
+    fn rename_shared_label(&self, name: &str, new_name: &str) -> TodoistResult<()>;
+    fn remove_shared_label(&self, name: &str) -> TodoistResult<()>;
+    fn get_label_usage(&self) -> TodoistResult<HashMap<String, usize>>;
+
+    // ===== SECTION OPERATIONS =====
+    fn get_sections(&self, limit: Option<i32>, cursor: Option<String>) -> TodoistResult<PaginatedResponse<Section>>;
+    fn get_sections_filtered(&self, args: &SectionFilterArgs) -> TodoistResult<PaginatedResponse<Section>>;
+    fn get_all_sections(&self) -> TodoistResult<Vec<Section>>;
+    fn get_sections_grouped(&self) -> TodoistResult<HashMap<String, Vec<Section>>>;
+    fn get_section(&self, section_id: impl AsRef<str>) -> TodoistResult<Section>;
+    fn count_tasks_in_section(&self, section_id: impl AsRef<str>) -> TodoistResult<usize>;
+    fn get_sections_with_counts(&self, project_id: impl AsRef<str>) -> TodoistResult<Vec<(Section, usize)>>;
+    fn create_section(&self, args: &CreateSectionArgs) -> TodoistResult<Section>;
+    fn update_section(&self, section_id: impl AsRef<str>, args: &UpdateSectionArgs) -> TodoistResult<Section>;
+    fn collapse_section(&self, section_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn expand_section(&self, section_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn set_section_collapsed(&self, section_id: impl AsRef<str>, collapsed: bool) -> TodoistResult<()>;
+    fn reorder_sections(&self, orders: &[(String, i32)]) -> TodoistResult<()>;
+    fn move_section(&self, section_id: impl AsRef<str>, target_project_id: impl AsRef<str>) -> TodoistResult<Section>;
+    fn delete_section(&self, section_id: impl AsRef<str>) -> TodoistResult<()>;
+
+    // ===== COMMENT OPERATIONS =====
+    fn get_comments(&self) -> TodoistResult<PaginatedResponse<Comment>>;
+    fn get_comments_filtered(&self, args: &CommentFilterArgs) -> TodoistResult<PaginatedResponse<Comment>>;
+    fn get_comments_for_task(&self, task_id: impl AsRef<str>) -> TodoistResult<Vec<Comment>>;
+    fn get_comment(&self, comment_id: impl AsRef<str>) -> TodoistResult<Comment>;
+    fn create_comment(&self, args: &CreateCommentArgs) -> TodoistResult<Comment>;
+    fn update_comment(&self, comment_id: impl AsRef<str>, args: &UpdateCommentArgs) -> TodoistResult<Comment>;
+    fn delete_comment(&self, comment_id: impl AsRef<str>) -> TodoistResult<()>;
+    fn download_attachment(&self, attachment: &Attachment) -> TodoistResult<Vec<u8>>;
+
+    // ===== REMINDER OPERATIONS =====
+    fn get_reminders(&self) -> TodoistResult<Vec<Reminder>>;
+    fn add_reminder(&self, task_id: impl AsRef<str>, reminder: &CreateReminderArgs) -> TodoistResult<String>;
+    fn delete_reminder(&self, reminder_id: impl AsRef<str>) -> TodoistResult<()>;
+
+    // ===== ACTIVITY OPERATIONS =====
+    fn get_activity_log(&self, args: &ActivityFilterArgs) -> TodoistResult<PaginatedResponse<ActivityEvent>>;
+}
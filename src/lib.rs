@@ -24,10 +24,10 @@
 //!     // Get all tasks with error handling
 //!     match todoist.get_tasks(None, None).await {
 //!         Ok(response) => println!("Found {} tasks", response.results.len()),
-//!         Err(TodoistError::RateLimited { retry_after, message }) => {
+//!         Err(TodoistError::RateLimited { retry_after, message, .. }) => {
 //!             println!("Rate limited: {} (retry after {} seconds)", message, retry_after.unwrap_or(0));
 //!         }
-//!         Err(TodoistError::AuthenticationError { message }) => {
+//!         Err(TodoistError::AuthenticationError { message, .. }) => {
 //!             println!("Authentication failed: {}", message);
 //!         }
 //!         Err(e) => println!("Other error: {}", e),
@@ -46,11 +46,21 @@
 //! }
 //! ```
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod models;
+pub mod oauth;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 pub mod wrapper;
 
 pub use models::*;
-pub use wrapper::TodoistWrapper;
+pub use wrapper::{
+    CommentPageIterator, LabelPageIterator, PageIterator, ProjectPageIterator, RequestInfo, RequestObserver,
+    ResponseInfo, RetryConfig, TaskPageIterator, TodoistWrapper, TodoistWrapperBuilder,
+};
 
 // Re-export commonly used types
 pub use models::{TodoistError, TodoistResult};
@@ -86,12 +96,12 @@ mod tests {
             duration: None,
             checked: false,
             is_deleted: false,
-            added_at: "2024-01-01T00:00:00Z".to_string(),
+            added_at: "2024-01-01T00:00:00Z".parse().unwrap(),
             completed_at: None,
             completed_by_uid: None,
             updated_at: None,
             due: None,
-            priority: 1,
+            priority: Priority::Normal,
             child_order: 0,
             note_count: 0,
             day_order: 0,
@@ -101,11 +111,11 @@ mod tests {
         let _project: Project = Project {
             id: "test".to_string(),
             name: "test".to_string(),
-            color: "blue".to_string(),
+            color: Color::Blue,
             is_shared: false,
             is_favorite: false,
             inbox_project: false,
-            view_style: "list".to_string(),
+            view_style: ViewStyle::List,
             parent_id: None,
             child_order: 0,
             creator_uid: None,
@@ -125,9 +135,10 @@ mod tests {
         let _label: Label = Label {
             id: "test".to_string(),
             name: "test".to_string(),
-            color: "red".to_string(),
+            color: Color::Red,
             order: Some(1),
             is_favorite: false,
+            is_deleted: false,
         };
 
         let _wrapper: TodoistWrapper = TodoistWrapper::new("test".to_string());
@@ -141,20 +152,20 @@ mod tests {
         // Test that argument types can be created and used
         let task_args = CreateTaskArgs {
             content: "Test task".to_string(),
-            priority: Some(3),
+            priority: Some(Priority::High),
             ..Default::default()
         };
 
         assert_eq!(task_args.content, "Test task");
-        assert_eq!(task_args.priority, Some(3));
+        assert_eq!(task_args.priority, Some(Priority::High));
 
         let project_args = CreateProjectArgs {
             name: "Test project".to_string(),
-            color: Some("blue".to_string()),
+            color: Some(Color::Blue),
             ..Default::default()
         };
 
         assert_eq!(project_args.name, "Test project");
-        assert_eq!(project_args.color, Some("blue".to_string()));
+        assert_eq!(project_args.color, Some(Color::Blue));
     }
 }
@@ -0,0 +1,152 @@
+//! In-memory responses for testing code built on this crate (`mock` feature)
+//!
+//! A [`MockBackend`] answers requests from pre-registered responses keyed by
+//! HTTP method and endpoint, so a [`TodoistWrapper`] built with
+//! [`TodoistWrapper::with_mock`] never touches the network and needs no real token.
+//! Requests without a registered response get a `404`, which surfaces as
+//! [`TodoistError::NotFound`](crate::TodoistError::NotFound). Every request is
+//! recorded and can be inspected with [`MockBackend::requests`].
+//!
+//! ```rust
+//! use serde_json::json;
+//! use todoist_api::mock::MockBackend;
+//! use todoist_api::TodoistWrapper;
+//!
+//! # async fn example() -> todoist_api::TodoistResult<()> {
+//! let mock = MockBackend::new().with_json(
+//!     "GET",
+//!     "/labels",
+//!     json!({"results": [{"id": "1", "name": "errand"}], "next_cursor": null}),
+//! );
+//! let todoist = TodoistWrapper::with_mock(mock.clone());
+//!
+//! let labels = todoist.get_labels(None, None).await?;
+//! assert_eq!(labels.results[0].name, "errand");
+//! assert_eq!(mock.requests()[0].endpoint, "/labels");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use crate::wrapper::TodoistWrapper;
+
+/// Base URL of clients built with [`TodoistWrapper::with_mock`]; never resolved
+const MOCK_BASE_URL: &str = "http://todoist.mock";
+
+/// A canned response
+#[derive(Debug, Clone)]
+struct MockResponse {
+    status: u16,
+    body: String,
+}
+
+/// A request received by a [`MockBackend`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    /// HTTP method, e.g. `GET`
+    pub method: String,
+    /// Endpoint path, e.g. `/tasks/123`
+    pub endpoint: String,
+    /// Query string, without the leading `?`
+    pub query: Option<String>,
+    /// Request body, empty for GET and DELETE requests
+    pub body: String,
+}
+
+/// In-memory stand-in for the Todoist API
+///
+/// Clones share their responses and recorded requests, so keep a clone to
+/// inspect the requests after handing one to [`TodoistWrapper::with_mock`].
+#[derive(Debug, Clone, Default)]
+pub struct MockBackend {
+    responses: Arc<Mutex<HashMap<(String, String), MockResponse>>>,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockBackend {
+    /// Create a backend with no registered responses
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `method` requests to `endpoint` with `200 OK` and a JSON body
+    #[must_use]
+    pub fn with_json(self, method: &str, endpoint: &str, body: Value) -> Self {
+        self.with_response(method, endpoint, 200, body.to_string())
+    }
+
+    /// Answer `method` requests to `endpoint` with the given status and raw body
+    ///
+    /// The endpoint is matched against the request path without its query string.
+    /// Registering the same method and endpoint again replaces the earlier response.
+    #[must_use]
+    pub fn with_response(self, method: &str, endpoint: &str, status: u16, body: impl Into<String>) -> Self {
+        let key = (method.to_ascii_uppercase(), normalize_endpoint(endpoint));
+        let response = MockResponse {
+            status,
+            body: body.into(),
+        };
+        lock(&self.responses).insert(key, response);
+        self
+    }
+
+    /// Requests received so far, oldest first
+    pub fn requests(&self) -> Vec<MockRequest> {
+        lock(&self.requests).clone()
+    }
+
+    /// Record a request and build its response
+    pub(crate) fn respond(&self, request: &reqwest::Request) -> reqwest::Response {
+        let method = request.method().as_str().to_string();
+        let endpoint = request.url().path().to_string();
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+            .unwrap_or_default();
+        lock(&self.requests).push(MockRequest {
+            method: method.clone(),
+            endpoint: endpoint.clone(),
+            query: request.url().query().map(str::to_string),
+            body,
+        });
+
+        let response = lock(&self.responses)
+            .get(&(method.clone(), endpoint.clone()))
+            .cloned()
+            .unwrap_or_else(|| MockResponse {
+                status: 404,
+                body: format!("No mock response for {} {}", method, endpoint),
+            });
+        let response = http::Response::builder()
+            .status(response.status)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(response.body)
+            .expect("mock response status is a valid HTTP status code");
+        reqwest::Response::from(response)
+    }
+}
+
+impl TodoistWrapper {
+    /// Create a client answered by a [`MockBackend`] instead of the Todoist API
+    #[must_use]
+    pub fn with_mock(backend: MockBackend) -> Self {
+        let mut todoist = Self::builder("mock-token").base_url(MOCK_BASE_URL).build();
+        todoist.mock = Some(backend);
+        todoist
+    }
+}
+
+/// Ensure an endpoint starts with a single `/`, as request paths do
+fn normalize_endpoint(endpoint: &str) -> String {
+    format!("/{}", endpoint.trim_start_matches('/'))
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -1,15 +1,119 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Define an enum of API string values that keeps unknown values in an `Other(String)`
+/// variant, so deserialization never fails when Todoist adds a value
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value not known to this crate
+            Other(String),
+        }
+
+        impl $name {
+            /// The value as sent to and received from the API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    other => $name::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+
+/// Timestamp of an API event (creation, update, completion, ...)
+/// `String` (ISO 8601) by default, `chrono::DateTime<Utc>` with the `chrono` feature
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Timestamp of an API event (creation, update, completion, ...)
+/// `String` (ISO 8601) by default, `chrono::DateTime<Utc>` with the `chrono` feature
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// Calendar date of a due date or deadline
+/// `String` (`YYYY-MM-DD`) by default, `chrono::NaiveDate` with the `chrono` feature
+#[cfg(not(feature = "chrono"))]
+pub type DueDate = String;
+
+/// Calendar date of a due date or deadline
+/// `String` (`YYYY-MM-DD`) by default, `chrono::NaiveDate` with the `chrono` feature
+#[cfg(feature = "chrono")]
+pub type DueDate = chrono::NaiveDate;
+
+/// Date and time of a due date
+/// `String` (ISO 8601) by default, [`chrono_types::DueDateTime`] with the `chrono` feature
+#[cfg(not(feature = "chrono"))]
+pub type DueDateTime = String;
+
+#[cfg(feature = "chrono")]
+pub use chrono_types::DueDateTime;
+
 /// Todoist Task model (API v1)
 /// Represents a task item as returned by the Unified API v1 (ItemSyncView)
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Task {
     pub id: String,
     /// User ID of the task creator (API returns this as `user_id`)
     #[serde(alias = "creator_id")]
     pub user_id: String,
     pub content: String,
+    #[serde(default)]
     pub description: String,
     pub project_id: String,
     pub section_id: Option<String>,
@@ -17,46 +121,409 @@ pub struct Task {
     pub added_by_uid: Option<String>,
     pub assigned_by_uid: Option<String>,
     pub responsible_uid: Option<String>,
+    #[serde(default)]
     pub labels: Vec<String>,
     pub deadline: Option<Deadline>,
     pub duration: Option<Duration>,
     /// Whether the task is completed
+    ///
+    /// Never set by completing a recurring task, which moves `due` to the next
+    /// occurrence instead; see [`Task::is_recurring`].
     #[serde(default)]
     pub checked: bool,
     /// Whether the task is deleted
     #[serde(default)]
     pub is_deleted: bool,
-    pub added_at: String,
+    pub added_at: Timestamp,
     /// When the task was completed (ISO 8601)
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
     /// User ID who completed the task
     pub completed_by_uid: Option<String>,
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     pub due: Option<Due>,
-    pub priority: i32,
+    #[serde(default)]
+    pub priority: Priority,
+    #[serde(default)]
     pub child_order: i32,
     /// Deprecated: always returns 0
     #[serde(default)]
     pub note_count: i32,
+    #[serde(default)]
     pub day_order: i32,
+    #[serde(default)]
     pub is_collapsed: bool,
 }
 
+impl Task {
+    /// Whether the task repeats, according to its due date
+    ///
+    /// Completing a recurring task does not close it: the same task stays open
+    /// (`checked` stays false) and its `due` moves to the next occurrence.
+    pub fn is_recurring(&self) -> bool {
+        self.due.as_ref().is_some_and(|due| due.is_recurring)
+    }
+
+    /// Link opening this task in the Todoist web app
+    pub fn web_url(&self) -> String {
+        task_web_url(&self.id)
+    }
+
+    /// Deep link opening this task in the Todoist desktop and mobile apps
+    pub fn app_url(&self) -> String {
+        format!("todoist://task?id={}", self.id)
+    }
+
+    /// Compute a key that orders tasks the way the Todoist app does by default
+    ///
+    /// Tasks are ordered by priority (urgent first), then by due date with undated
+    /// tasks last, then by their position in the project (`child_order`).
+    /// Tasks due on the same day are ordered by due time, with all-day tasks after
+    /// timed ones. Due times carrying a UTC offset are compared as instants, while
+    /// floating due times (no offset) are compared as wall-clock times.
+    pub fn default_sort_key(&self) -> TaskSortKey {
+        let (due_date, due_time) = match &self.due {
+            Some(due) => {
+                let (date, time) = due.sort_parts();
+                (Some(date), time)
+            }
+            None => (None, None),
+        };
+
+        TaskSortKey {
+            priority: std::cmp::Reverse(self.priority),
+            is_undated: due_date.is_none(),
+            due_date: due_date.unwrap_or_default(),
+            is_all_day: due_time.is_none(),
+            due_time: due_time.unwrap_or_default(),
+            child_order: self.child_order,
+        }
+    }
+}
+
+/// Link opening a task in the Todoist web app, built from its ID alone
+pub fn task_web_url(id: &str) -> String {
+    format!("https://app.todoist.com/app/task/{id}")
+}
+
+/// Sort key reproducing the Todoist app's default task ordering
+/// Obtained from [`Task::default_sort_key`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TaskSortKey {
+    priority: std::cmp::Reverse<Priority>,
+    is_undated: bool,
+    due_date: String,
+    is_all_day: bool,
+    due_time: i64,
+    child_order: i32,
+}
+
+/// Sort tasks in place the way the Todoist app displays them by default
+/// The sort is stable, so tasks with equal keys keep their relative order
+pub fn sort_tasks_like_app(tasks: &mut [Task]) {
+    tasks.sort_by_cached_key(Task::default_sort_key);
+}
+
+/// A task with its sub-tasks, as built by [`build_task_tree`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskNode {
+    pub task: Task,
+    pub children: Vec<TaskNode>,
+}
+
+/// Nest tasks under their parents by `parent_id`
+///
+/// Roots and children keep their order from `tasks`. A task whose parent is not
+/// in `tasks` (e.g. a sub-task of a completed task, or from another page) becomes
+/// a root, as does the first task reached in a `parent_id` cycle.
+pub fn build_task_tree(tasks: &[Task]) -> Vec<TaskNode> {
+    let ids: std::collections::HashSet<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    let mut children: std::collections::HashMap<&str, Vec<&Task>> = std::collections::HashMap::new();
+    for task in tasks {
+        if let Some(parent_id) = task.parent_id.as_deref().filter(|id| ids.contains(id)) {
+            children.entry(parent_id).or_default().push(task);
+        }
+    }
+
+    fn node<'a>(
+        task: &'a Task,
+        children: &std::collections::HashMap<&str, Vec<&'a Task>>,
+        placed: &mut std::collections::HashSet<&'a str>,
+    ) -> TaskNode {
+        placed.insert(&task.id);
+        let mut nodes = Vec::new();
+        for child in children.get(task.id.as_str()).into_iter().flatten() {
+            if !placed.contains(child.id.as_str()) {
+                nodes.push(node(child, children, placed));
+            }
+        }
+        TaskNode {
+            task: task.clone(),
+            children: nodes,
+        }
+    }
+
+    let mut placed = std::collections::HashSet::new();
+    let mut roots: Vec<TaskNode> = tasks
+        .iter()
+        .filter(|task| !task.parent_id.as_deref().is_some_and(|id| ids.contains(id)))
+        .map(|task| node(task, &children, &mut placed))
+        .collect();
+    for task in tasks {
+        if !placed.contains(task.id.as_str()) {
+            roots.push(node(task, &children, &mut placed));
+        }
+    }
+    roots
+}
+
+/// Sort sibling tasks in place by their position (`child_order`)
+/// The sort is stable, so tasks with the same `child_order` keep their relative order
+pub fn sort_tasks_by_order(tasks: &mut [Task]) {
+    tasks.sort_by_key(|task| task.child_order);
+}
+
+/// Sort a project's sections in place by their position (`section_order`)
+/// The sort is stable, so sections with the same `section_order` keep their relative order
+pub fn sort_sections(sections: &mut [Section]) {
+    sections.sort_by_key(|section| section.section_order);
+}
+
+/// Order projects as the Todoist sidebar lists them, each with its nesting depth
+///
+/// Every project is followed by its sub-projects (depth one more than their parent's),
+/// and siblings are ordered by `child_order`. As with [`build_task_tree`], a project
+/// whose parent is not in `projects` is placed at depth 0, as is the first project
+/// reached in a `parent_id` cycle.
+pub fn sort_projects_hierarchical(projects: &[Project]) -> Vec<(Project, usize)> {
+    let mut sorted: Vec<&Project> = projects.iter().collect();
+    sorted.sort_by_key(|project| project.child_order);
+
+    let ids: std::collections::HashSet<&str> = projects.iter().map(|project| project.id.as_str()).collect();
+    let mut children: std::collections::HashMap<&str, Vec<&Project>> = std::collections::HashMap::new();
+    for project in &sorted {
+        if let Some(parent_id) = project.parent_id.as_deref().filter(|id| ids.contains(id)) {
+            children.entry(parent_id).or_default().push(project);
+        }
+    }
+
+    fn visit<'a>(
+        project: &'a Project,
+        depth: usize,
+        children: &std::collections::HashMap<&str, Vec<&'a Project>>,
+        placed: &mut std::collections::HashSet<&'a str>,
+        ordered: &mut Vec<(Project, usize)>,
+    ) {
+        placed.insert(&project.id);
+        ordered.push((project.clone(), depth));
+        for child in children.get(project.id.as_str()).into_iter().flatten() {
+            if !placed.contains(child.id.as_str()) {
+                visit(child, depth + 1, children, placed, ordered);
+            }
+        }
+    }
+
+    let mut placed = std::collections::HashSet::new();
+    let mut ordered = Vec::with_capacity(projects.len());
+    for project in &sorted {
+        if !project.parent_id.as_deref().is_some_and(|id| ids.contains(id)) {
+            visit(project, 0, &children, &mut placed, &mut ordered);
+        }
+    }
+    for project in &sorted {
+        if !placed.contains(project.id.as_str()) {
+            visit(project, 0, &children, &mut placed, &mut ordered);
+        }
+    }
+    ordered
+}
+
+impl Due {
+    /// Whether the due date has a time of day, as opposed to being all-day
+    pub fn has_time(&self) -> bool {
+        self.datetime.is_some()
+    }
+
+    /// Whether the due datetime is floating: a wall-clock time without a timezone
+    pub fn is_floating(&self) -> bool {
+        self.datetime.is_some() && self.timezone.is_none()
+    }
+
+    /// Split the due date into a sortable calendar date and an optional instant in seconds
+    ///
+    /// Only `datetime` carries the time: a time embedded in `date` is ignored, as it
+    /// is when `date` is parsed with the `chrono` feature.
+    #[cfg(not(feature = "chrono"))]
+    fn sort_parts(&self) -> (String, Option<i64>) {
+        let date = self.date.get(..10).unwrap_or(&self.date).to_string();
+        let time = self.datetime.as_deref().and_then(parse_datetime_seconds);
+        (date, time)
+    }
+
+    /// Split the due date into a sortable calendar date and an optional instant in seconds
+    #[cfg(feature = "chrono")]
+    fn sort_parts(&self) -> (String, Option<i64>) {
+        let time = self.datetime.as_ref().map(|datetime| match datetime {
+            DueDateTime::Fixed(instant) => instant.timestamp(),
+            DueDateTime::Floating(wall_clock) => wall_clock.and_utc().timestamp(),
+        });
+        (self.date.to_string(), time)
+    }
+
+    /// The calendar date the task is due on, as seen from timezone `tz` (`chrono` feature)
+    ///
+    /// A fixed due datetime is an instant, so its date depends on `tz`: 23:30 UTC is
+    /// already the next day in Paris. A floating due datetime and an all-day due date
+    /// are wall-clock values and fall on the same date in every timezone. For a
+    /// recurring task this is the date of the upcoming occurrence, as computed by Todoist.
+    #[cfg(feature = "chrono")]
+    pub fn date_in<Tz: chrono::TimeZone>(&self, tz: &Tz) -> chrono::NaiveDate {
+        match self.datetime {
+            Some(DueDateTime::Fixed(instant)) => instant.with_timezone(tz).date_naive(),
+            Some(DueDateTime::Floating(wall_clock)) => wall_clock.date(),
+            None => self.date,
+        }
+    }
+
+    /// Whether the task is due on `date` in timezone `tz` (`chrono` feature)
+    ///
+    /// See [`Due::date_in`]. Pass the user's timezone, e.g. a `chrono_tz::Tz` built
+    /// from [`User::timezone`], to match what the Todoist apps show.
+    #[cfg(feature = "chrono")]
+    pub fn is_due_on<Tz: chrono::TimeZone>(&self, date: chrono::NaiveDate, tz: &Tz) -> bool {
+        self.date_in(tz) == date
+    }
+
+    /// The first date after `date` on which the task is due, as seen from timezone `tz` (`chrono` feature)
+    ///
+    /// Starts from [`Due::date_in`], the upcoming occurrence computed by Todoist, and
+    /// steps forward by the interval of a simple English recurrence: `every day`,
+    /// `every 3 weeks`, `every other month`, `yearly` and the like, optionally
+    /// followed by `at <time>`. Month steps keep the day of the month, falling back
+    /// to the last day of shorter months. A non-recurring task only has its due date.
+    ///
+    /// Returns `None` when there is no such date or the recurrence cannot be expanded
+    /// here: weekday and ordinal rules (`every monday`, `every 3rd friday`), `every!`
+    /// (counted from completion), `starting` / `until` bounds and non-English strings.
+    /// A fixed due datetime is stepped in the calendar of `tz`, so the result is one
+    /// day off around a daylight saving change that `tz` and the task's timezone do
+    /// not share.
+    #[cfg(feature = "chrono")]
+    pub fn next_occurrence_after<Tz: chrono::TimeZone>(
+        &self,
+        date: chrono::NaiveDate,
+        tz: &Tz,
+    ) -> Option<chrono::NaiveDate> {
+        let first = self.date_in(tz);
+        if first > date {
+            return Some(first);
+        }
+        if !self.is_recurring {
+            return None;
+        }
+        match parse_simple_recurrence(&self.string)? {
+            RecurrenceStep::Days(days) => {
+                let periods = (date - first).num_days() / days + 1;
+                first.checked_add_signed(chrono::Duration::days(periods * days))
+            }
+            RecurrenceStep::Months(months) => {
+                use chrono::Datelike;
+                let elapsed = (date.year() - first.year()) * 12 + date.month() as i32 - first.month() as i32;
+                let mut period = (elapsed / months as i32).max(0) as u32;
+                loop {
+                    let next = first.checked_add_months(chrono::Months::new(period * months))?;
+                    if next > date {
+                        return Some(next);
+                    }
+                    period += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Interval between two occurrences of a simple recurrence
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecurrenceStep {
+    Days(i64),
+    Months(u32),
+}
+
+/// Parse `every [N|other] day|week|month|year[s] [at <time>]` and `daily` / `weekly` / `monthly` / `yearly`
+#[cfg(feature = "chrono")]
+fn parse_simple_recurrence(string: &str) -> Option<RecurrenceStep> {
+    let lowercase = string.trim().to_lowercase();
+    let rule = lowercase.split(" at ").next().unwrap_or_default();
+    let words: Vec<&str> = rule.split_whitespace().collect();
+    let (count, unit) = match words.as_slice() {
+        [single] => (1, single.strip_suffix("ly")?),
+        ["every", unit] => (1, *unit),
+        ["every", "other", unit] => (2, *unit),
+        ["every", count, unit] => (count.parse::<u32>().ok().filter(|count| *count > 0)?, *unit),
+        _ => return None,
+    };
+    match unit.strip_suffix('s').unwrap_or(unit) {
+        "dai" | "day" => Some(RecurrenceStep::Days(i64::from(count))),
+        "week" => Some(RecurrenceStep::Days(7 * i64::from(count))),
+        "month" => Some(RecurrenceStep::Months(count)),
+        "year" | "annual" => Some(RecurrenceStep::Months(12 * count)),
+        _ => None,
+    }
+}
+
+/// Parse an ISO 8601 datetime (`YYYY-MM-DDTHH:MM:SS[.fff][Z|±HH:MM]`) into seconds since the epoch
+/// Floating datetimes without an offset are interpreted as UTC wall-clock times
+#[cfg(not(feature = "chrono"))]
+fn parse_datetime_seconds(value: &str) -> Option<i64> {
+    let number = |range: std::ops::Range<usize>| value.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute) = (number(11..13)?, number(14..16)?);
+    let second = number(17..19).unwrap_or(0);
+
+    let zone = value[16..].trim_start_matches(|c: char| c == ':' || c == '.' || c.is_ascii_digit());
+    let offset = match zone.as_bytes().first() {
+        Some(sign @ (b'+' | b'-')) => {
+            let hours = zone.get(1..3)?.parse::<i64>().ok()?;
+            let minutes = zone.get(zone.len() - 2..)?.parse::<i64>().ok()?;
+            let offset = hours * 3600 + minutes * 60;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => 0,
+    };
+
+    // Days from civil date (proleptic Gregorian calendar)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
 /// Todoist Project model (API v1)
 /// Represents a project as returned by the Unified API v1 (PersonalProjectSyncView)
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Project {
     pub id: String,
     pub name: String,
-    pub color: String,
+    #[serde(default)]
+    pub color: Color,
     /// Whether the project is shared with other users
-    #[serde(alias = "shared")]
+    #[serde(default, alias = "shared")]
     pub is_shared: bool,
+    #[serde(default)]
     pub is_favorite: bool,
     /// Whether this is the inbox project
-    #[serde(alias = "is_inbox_project")]
+    #[serde(default, alias = "is_inbox_project")]
     pub inbox_project: bool,
-    pub view_style: String,
+    #[serde(default)]
+    pub view_style: ViewStyle,
     pub parent_id: Option<String>,
     /// Child order in the project list
     #[serde(default)]
@@ -64,9 +531,9 @@ pub struct Project {
     /// User ID of the project creator
     pub creator_uid: Option<String>,
     /// When the project was created (ISO 8601)
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     /// When the project was last updated (ISO 8601)
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     /// Whether the project is archived
     #[serde(default)]
     pub is_archived: bool,
@@ -97,45 +564,131 @@ pub struct Project {
 
 /// Todoist Label model (API v1)
 /// Represents a label as returned by the Unified API v1 (LabelRestView)
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Label {
     pub id: String,
     pub name: String,
-    pub color: String,
-    /// Order in the label list (can be null for some labels)
+    #[serde(default)]
+    pub color: Color,
+    /// Order in the label list (null or absent for some labels, e.g. shared ones)
+    #[serde(default)]
     pub order: Option<i32>,
+    #[serde(default)]
     pub is_favorite: bool,
+    /// Whether the label is deleted (only reported by the Sync API)
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+/// Lookup between label names and IDs
+///
+/// Tasks refer to labels by name (`Task::labels`) while label endpoints take IDs.
+/// Build one from [`crate::TodoistWrapper::get_label_index`], or from labels already
+/// fetched. Shared labels have no ID of their own, so their names do not resolve.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelIndex {
+    labels: Vec<Label>,
+    by_name: std::collections::HashMap<String, usize>,
+    by_id: std::collections::HashMap<String, usize>,
+}
+
+impl LabelIndex {
+    /// Index `labels` by name and by ID
+    pub fn new(labels: Vec<Label>) -> Self {
+        let by_name = labels
+            .iter()
+            .enumerate()
+            .map(|(position, label)| (label.name.clone(), position))
+            .collect();
+        let by_id = labels
+            .iter()
+            .enumerate()
+            .map(|(position, label)| (label.id.clone(), position))
+            .collect();
+        LabelIndex { labels, by_name, by_id }
+    }
+
+    /// Every indexed label, in the order given to [`Self::new`]
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Find a label by its exact name
+    pub fn get_by_name(&self, name: &str) -> Option<&Label> {
+        self.by_name.get(name).map(|&position| &self.labels[position])
+    }
+
+    /// Find a label by ID
+    pub fn get_by_id(&self, id: &str) -> Option<&Label> {
+        self.by_id.get(id).map(|&position| &self.labels[position])
+    }
+
+    /// ID of the label with this name, if there is one
+    pub fn name_to_id(&self, name: &str) -> Option<&str> {
+        self.get_by_name(name).map(|label| label.id.as_str())
+    }
+
+    /// Name of the label with this ID, if there is one
+    pub fn id_to_name(&self, id: &str) -> Option<&str> {
+        self.get_by_id(id).map(|label| label.name.as_str())
+    }
+
+    /// IDs of the labels with these names, in the same order
+    ///
+    /// Returns a `NotFound` error for the first name without a label.
+    pub fn resolve_names(&self, names: &[impl AsRef<str>]) -> TodoistResult<Vec<&str>> {
+        names
+            .iter()
+            .map(|name| {
+                let name = name.as_ref();
+                self.name_to_id(name)
+                    .ok_or_else(|| not_found_error("Label", Some(name), "No label with this name"))
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<Label>> for LabelIndex {
+    fn from(labels: Vec<Label>) -> Self {
+        LabelIndex::new(labels)
+    }
 }
 
 /// Todoist Section model (API v1)
 /// Represents a section as returned by the Unified API v1 (SectionSyncView)
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Section {
     pub id: String,
     /// User ID of the section creator (API returns this as `user_id`)
     #[serde(alias = "creator_id")]
     pub user_id: String,
     pub project_id: String,
-    pub added_at: String,
-    pub updated_at: Option<String>,
-    pub archived_at: Option<String>,
+    pub added_at: Timestamp,
+    pub updated_at: Option<Timestamp>,
+    pub archived_at: Option<Timestamp>,
     pub name: String,
+    #[serde(default)]
     pub section_order: i32,
+    /// Whether the section is archived
+    #[serde(default)]
     pub is_archived: bool,
     /// Whether the section is deleted
     #[serde(default)]
     pub is_deleted: bool,
+    /// Whether the section is collapsed in the UI
+    #[serde(default)]
     pub is_collapsed: bool,
 }
 
 /// Todoist Comment model (API v1)
 /// Represents a comment as returned by the Unified API v1 (NoteSyncView)
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Comment {
     pub id: String,
     #[serde(default)]
     pub content: String,
-    pub posted_at: Option<String>,
+    pub posted_at: Option<Timestamp>,
+    /// ID of the user who posted the comment; see [`Comment::author`]
     pub posted_uid: Option<String>,
     /// File attachment (API returns this as `file_attachment`)
     #[serde(alias = "attachment")]
@@ -156,16 +709,85 @@ pub struct Comment {
 }
 
 /// Todoist Attachment model
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Attachment {
     pub file_name: String,
     pub file_type: String,
     pub file_url: String,
-    pub resource_type: String,
+    pub resource_type: ResourceType,
+}
+
+/// A user with access to a shared project, as returned by `get_collaborators`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct Collaborator {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+}
+
+impl Comment {
+    /// Find the comment's author, matching `posted_uid` against `collaborators`
+    ///
+    /// Returns `None` when the comment has no `posted_uid` or its author is not
+    /// among `collaborators` (e.g. they have since left the project).
+    pub fn author<'a>(&self, collaborators: &'a [Collaborator]) -> Option<&'a Collaborator> {
+        let posted_uid = self.posted_uid.as_deref()?;
+        collaborators.iter().find(|collaborator| collaborator.id == posted_uid)
+    }
+}
+
+/// Todoist Reminder model
+/// Represents a reminder as returned by the Sync API
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct Reminder {
+    pub id: String,
+    /// Task the reminder is about (`item_id` in the Sync API)
+    #[serde(rename = "item_id")]
+    pub task_id: String,
+    #[serde(rename = "type")]
+    pub reminder_type: ReminderType,
+    /// User notified by the reminder
+    pub notify_uid: Option<String>,
+    /// When an absolute reminder triggers
+    pub due: Option<Due>,
+    /// Minutes before the task's due time at which a relative reminder triggers
+    pub minute_offset: Option<i32>,
+    /// Name of the location of a location reminder
+    pub name: Option<String>,
+    pub loc_lat: Option<String>,
+    pub loc_long: Option<String>,
+    /// `on_enter` or `on_leave`
+    pub loc_trigger: Option<String>,
+    /// Radius around the location, in meters
+    pub radius: Option<i32>,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+/// An entry of the activity log, as returned by `get_activity_log`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ActivityEvent {
+    /// ID of the log entry itself
+    pub id: Option<i64>,
+    pub object_type: ActivityObjectType,
+    /// ID of the task, project or comment the event is about
+    pub object_id: String,
+    pub event_type: ActivityEventType,
+    pub event_date: Timestamp,
+    /// Project containing the object (the project itself for project events)
+    pub parent_project_id: Option<String>,
+    /// Task a comment belongs to, for comment events (`parent_item_id` in the API)
+    #[serde(rename = "parent_item_id")]
+    pub parent_task_id: Option<String>,
+    /// User who caused the event in a shared project, `None` for the user's own changes
+    pub initiator_id: Option<String>,
+    /// Event details, e.g. the task content or the previous values of updated fields
+    pub extra_data: Option<serde_json::Value>,
 }
 
 /// Todoist User model
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "UserPayload")]
 pub struct User {
     pub id: String,
     pub name: String,
@@ -173,48 +795,443 @@ pub struct User {
     pub avatar_url: Option<String>,
     pub is_premium: bool,
     pub is_business_account: bool,
+    /// The user's timezone settings
+    pub tz_info: Option<TzInfo>,
+}
+
+impl User {
+    /// The user's IANA timezone name (e.g. `Europe/Paris`), if known
+    pub fn timezone(&self) -> Option<&str> {
+        self.tz_info.as_ref().map(|tz_info| tz_info.timezone.as_str())
+    }
+}
+
+/// User as returned by the API (`full_name`, `avatar_big`, `business_account_id`),
+/// also accepting the field names `User` serializes to
+#[derive(Deserialize)]
+struct UserPayload {
+    id: String,
+    #[serde(alias = "full_name")]
+    name: String,
+    email: String,
+    #[serde(alias = "avatar_big")]
+    avatar_url: Option<String>,
+    #[serde(default)]
+    is_premium: bool,
+    #[serde(default)]
+    is_business_account: bool,
+    business_account_id: Option<serde_json::Value>,
+    tz_info: Option<TzInfo>,
+}
+
+impl From<UserPayload> for User {
+    fn from(payload: UserPayload) -> Self {
+        let has_business_account = payload.business_account_id.is_some_and(|id| !id.is_null());
+        User {
+            id: payload.id,
+            name: payload.name,
+            email: payload.email,
+            avatar_url: payload.avatar_url,
+            is_premium: payload.is_premium,
+            is_business_account: payload.is_business_account || has_business_account,
+            tz_info: payload.tz_info,
+        }
+    }
+}
+
+/// Timezone settings of a user
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct TzInfo {
+    /// IANA timezone name, e.g. `Europe/Paris`
+    pub timezone: String,
+    /// Hours of the current UTC offset
+    #[serde(default)]
+    pub hours: i32,
+    /// Minutes of the current UTC offset
+    #[serde(default)]
+    pub minutes: i32,
+    /// Whether daylight saving time is currently in effect
+    #[serde(default, deserialize_with = "deserialize_bool_or_int")]
+    pub is_dst: bool,
+    /// The current UTC offset as a string, e.g. `+01:00`
+    #[serde(default)]
+    pub gmt_string: String,
+}
+
+/// Accept booleans sent either as `true`/`false` or as `1`/`0`
+fn deserialize_bool_or_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrInt {
+        Bool(bool),
+        Int(i64),
+    }
+
+    Ok(match BoolOrInt::deserialize(deserializer)? {
+        BoolOrInt::Bool(value) => value,
+        BoolOrInt::Int(value) => value != 0,
+    })
+}
+
+/// Accept integers sent either as numbers or as numeric strings, e.g. `30` or `"30"`
+fn deserialize_int_or_string<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum IntOrString {
+        Int(i32),
+        String(String),
+    }
+
+    match IntOrString::deserialize(deserializer)? {
+        IntOrString::Int(value) => Ok(value),
+        IntOrString::String(value) => value.trim().parse().map_err(serde::de::Error::custom),
+    }
 }
 
 /// Todoist Due date model (API v1)
 /// Represents a due date as returned by the Unified API v1
-#[derive(Debug, Serialize, Deserialize, Clone)]
+///
+/// A due date with a time comes in two flavours. A *fixed* due datetime carries a
+/// UTC offset and has `timezone` set to the IANA zone it was created in. A *floating*
+/// due datetime has no offset and `timezone` is `None`: it happens at the given
+/// wall-clock time in whatever timezone the user is in.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Due {
+    /// Due date as written by the user, e.g. `every monday at 9am`
     pub string: String,
-    pub date: String,
+    #[cfg_attr(feature = "chrono", serde(deserialize_with = "chrono_types::deserialize_due_date"))]
+    pub date: DueDate,
     pub is_recurring: bool,
-    pub datetime: Option<String>,
+    /// Date and time, or `None` for an all-day due date
+    pub datetime: Option<DueDateTime>,
+    /// IANA timezone of a fixed due datetime (e.g. `Europe/Paris`); `None` when floating or all-day
     pub timezone: Option<String>,
     /// Language of the due string
-    pub lang: Option<String>,
+    pub lang: Option<Lang>,
+}
+
+/// Task priority
+///
+/// Serialized as the integer the API expects. Note that the API numbering is the
+/// reverse of the app's labels: `Urgent` is "p1" in the app but `4` on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// Normal priority ("p4" in the app, `1` on the wire)
+    #[default]
+    Normal,
+    /// Medium priority ("p3" in the app, `2` on the wire)
+    Medium,
+    /// High priority ("p2" in the app, `3` on the wire)
+    High,
+    /// Urgent priority ("p1" in the app, `4` on the wire)
+    Urgent,
+}
+
+impl From<Priority> for i32 {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::Normal => 1,
+            Priority::Medium => 2,
+            Priority::High => 3,
+            Priority::Urgent => 4,
+        }
+    }
+}
+
+impl TryFrom<i32> for Priority {
+    type Error = TodoistError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Priority::Normal),
+            2 => Ok(Priority::Medium),
+            3 => Ok(Priority::High),
+            4 => Ok(Priority::Urgent),
+            _ => Err(validation_error(
+                Some("priority"),
+                format!("Priority must be between 1 and 4, got {}", value),
+            )),
+        }
+    }
+}
+
+impl Serialize for Priority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(i32::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = i32::deserialize(deserializer)?;
+        Priority::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+string_enum! {
+    /// Named color from the Todoist palette, used by projects and labels
+    ///
+    /// Serialized as the snake_case name the API expects (e.g. `berry_red`). Colors
+    /// not known to this crate are kept as [`Color::Other`] so deserialization never
+    /// fails when Todoist extends the palette. Defaults to `charcoal`, the API's default.
+    #[derive(Default)]
+    pub enum Color {
+        BerryRed => "berry_red",
+        Red => "red",
+        Orange => "orange",
+        Yellow => "yellow",
+        OliveGreen => "olive_green",
+        LimeGreen => "lime_green",
+        Green => "green",
+        MintGreen => "mint_green",
+        Teal => "teal",
+        SkyBlue => "sky_blue",
+        LightBlue => "light_blue",
+        Blue => "blue",
+        Grape => "grape",
+        Violet => "violet",
+        Lavender => "lavender",
+        Magenta => "magenta",
+        Salmon => "salmon",
+        #[default]
+        Charcoal => "charcoal",
+        Grey => "grey",
+        Taupe => "taupe",
+    }
+}
+
+string_enum! {
+    /// Unit of a task [`Duration`]
+    ///
+    /// The API currently accepts `minute` and `day` for task durations; `Hour` is
+    /// only read back, and `CreateTaskArgs::validate` / `UpdateTaskArgs::validate`
+    /// reject it before a request is sent.
+    pub enum DurationUnit {
+        Minute => "minute",
+        Hour => "hour",
+        Day => "day",
+    }
+}
+
+string_enum! {
+    /// Kind of an [`Attachment`], e.g. to choose between a thumbnail and a download link
+    pub enum ResourceType {
+        File => "file",
+        Website => "website",
+        Image => "image",
+        Audio => "audio",
+        Video => "video",
+    }
+}
+
+string_enum! {
+    /// Kind of a [`Reminder`]: relative to the task's due time, at an absolute time, or on location
+    pub enum ReminderType {
+        Relative => "relative",
+        Absolute => "absolute",
+        Location => "location",
+    }
+}
+
+string_enum! {
+    /// Language used to parse a due or deadline string, or a filter query
+    ///
+    /// Serialized as the lowercase code the API expects (e.g. `de`). Codes are
+    /// case-sensitive: `"EN"` is kept as [`Lang::Other`], which the API does not
+    /// recognize.
+    pub enum Lang {
+        English => "en",
+        Danish => "da",
+        Polish => "pl",
+        /// Simplified Chinese
+        Chinese => "zh",
+        Korean => "ko",
+        German => "de",
+        Portuguese => "pt",
+        Japanese => "ja",
+        Italian => "it",
+        French => "fr",
+        Swedish => "sv",
+        Russian => "ru",
+        Spanish => "es",
+        Dutch => "nl",
+        Finnish => "fi",
+        /// Norwegian Bokmål
+        Norwegian => "nb",
+        /// Traditional Chinese
+        TraditionalChinese => "tw",
+    }
+}
+
+string_enum! {
+    /// Kind of object whose IDs [`crate::TodoistWrapper::get_id_mappings`] translates
+    pub enum IdMappingObject {
+        Tasks => "tasks",
+        Projects => "projects",
+        Sections => "sections",
+        Comments => "comments",
+        Reminders => "reminders",
+        LocationReminders => "location_reminders",
+    }
+}
+
+string_enum! {
+    /// Kind of object an [`ActivityEvent`] is about
+    pub enum ActivityObjectType {
+        Project => "project",
+        /// A task (`item` in the API)
+        Task => "item",
+        /// A comment (`note` in the API)
+        Comment => "note",
+    }
+}
+
+string_enum! {
+    /// What happened in an [`ActivityEvent`]
+    pub enum ActivityEventType {
+        Added => "added",
+        Updated => "updated",
+        Deleted => "deleted",
+        Completed => "completed",
+        Uncompleted => "uncompleted",
+        Archived => "archived",
+        Unarchived => "unarchived",
+        Shared => "shared",
+        Left => "left",
+        Reordered => "reordered",
+        Moved => "moved",
+    }
+}
+
+string_enum! {
+    /// A resource requested from [`crate::TodoistWrapper::sync`]
+    pub enum SyncResourceType {
+        /// Every resource type
+        All => "all",
+        Tasks => "items",
+        Projects => "projects",
+        Sections => "sections",
+        Labels => "labels",
+    }
+}
+
+string_enum! {
+    /// How a project is displayed in the Todoist apps (`list` by default)
+    #[derive(Default)]
+    pub enum ViewStyle {
+        #[default]
+        List => "list",
+        Board => "board",
+        Calendar => "calendar",
+    }
 }
 
 /// Todoist Deadline model (API v1)
 /// Represents a deadline as returned by the Unified API v1
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Deadline {
-    pub date: String,
+    pub date: DueDate,
     /// Language of the deadline string
-    pub lang: Option<String>,
+    pub lang: Option<Lang>,
 }
 
 /// Todoist Duration model
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Duration {
+    /// Accepted either as a number or as a numeric string
+    #[serde(deserialize_with = "deserialize_int_or_string")]
     pub amount: i32,
-    pub unit: String, // "minute", "hour", "day"
+    pub unit: DurationUnit,
 }
 
 /// Paginated response wrapper for API v1
-/// All list endpoints in API v1 return results in this format
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// All list endpoints in API v1 return results in this format.
+/// A bare JSON array is also accepted and treated as a single, final page, and
+/// the `items` key used by the completed-tasks endpoints is read as `results`.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 pub struct PaginatedResponse<T> {
     pub results: Vec<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_cursor: Option<String>,
 }
 
+/// Short alias for [`PaginatedResponse`]
+pub type Page<T> = PaginatedResponse<T>;
+
+impl<'de, T> Deserialize<'de> for PaginatedResponse<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PageVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for PageVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = PaginatedResponse<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a paginated object with `results` or a bare array")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut results = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(item) = seq.next_element()? {
+                    results.push(item);
+                }
+                Ok(PaginatedResponse {
+                    results,
+                    next_cursor: None,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut results = None;
+                let mut next_cursor = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "results" | "items" => results = Some(map.next_value()?),
+                        "next_cursor" => next_cursor = map.next_value()?,
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PaginatedResponse {
+                    results: results.ok_or_else(|| serde::de::Error::missing_field("results"))?,
+                    next_cursor,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(PageVisitor(std::marker::PhantomData))
+    }
+}
+
 /// Task creation arguments
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct CreateTaskArgs {
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -228,7 +1245,8 @@ pub struct CreateTaskArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<i32>,
+    pub priority: Option<Priority>,
+    /// Label names; `Some(vec![])` sends an explicit empty list
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -238,15 +1256,231 @@ pub struct CreateTaskArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_datetime: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_lang: Option<String>,
+    pub due_lang: Option<Lang>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deadline_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline_lang: Option<String>,
+    pub deadline_lang: Option<Lang>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration_unit: Option<String>,
+    pub duration_unit: Option<DurationUnit>,
+}
+
+impl CreateTaskArgs {
+    /// Start building task creation arguments; only `content` is required
+    pub fn builder(content: impl Into<String>) -> CreateTaskArgsBuilder {
+        CreateTaskArgsBuilder {
+            args: CreateTaskArgs {
+                content: content.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Check the constraints the API enforces, without sending a request
+    ///
+    /// `create_task` runs this first. Returns a `ValidationError` naming the offending
+    /// field when `content` is blank, more than one of `due_string`, `due_date` and
+    /// `due_datetime` is set, only one of `duration` and `duration_unit` is set, or
+    /// `duration_unit` is [`DurationUnit::Hour`].
+    pub fn validate(&self) -> TodoistResult<()> {
+        validate_content(Some(&self.content))?;
+        validate_due(
+            self.due_string.is_some(),
+            self.due_date.is_some(),
+            self.due_datetime.is_some(),
+        )?;
+        validate_duration_unit(self.duration_unit.as_ref())?;
+        match (self.duration, &self.duration_unit) {
+            (Some(_), None) => Err(validation_error(
+                Some("duration_unit"),
+                "A duration needs a duration_unit",
+            )),
+            (None, Some(_)) => Err(validation_error(Some("duration"), "A duration_unit needs a duration")),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Values a client fills in on the tasks it creates, see [`crate::TodoistWrapper::with_defaults`]
+///
+/// A default only applies when the creation arguments leave that field unset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskDefaults {
+    pub priority: Option<Priority>,
+    pub labels: Option<Vec<String>>,
+    /// Only applies to tasks created without a project, section or parent task
+    pub project_id: Option<String>,
+}
+
+impl TaskDefaults {
+    /// Check whether no default is set
+    pub fn is_empty(&self) -> bool {
+        self.priority.is_none() && self.labels.is_none() && self.project_id.is_none()
+    }
+
+    /// Fill the fields `args` leaves unset with these defaults
+    pub fn apply<'a>(&self, args: &'a CreateTaskArgs) -> std::borrow::Cow<'a, CreateTaskArgs> {
+        if self.is_empty() {
+            return std::borrow::Cow::Borrowed(args);
+        }
+        let mut args = args.clone();
+        if args.priority.is_none() {
+            args.priority = self.priority;
+        }
+        if args.labels.is_none() {
+            args.labels = self.labels.clone();
+        }
+        if args.project_id.is_none() && args.section_id.is_none() && args.parent_id.is_none() {
+            args.project_id = self.project_id.clone();
+        }
+        std::borrow::Cow::Owned(args)
+    }
+}
+
+/// Reject blank task content
+fn validate_content(content: Option<&str>) -> TodoistResult<()> {
+    match content {
+        Some(content) if content.trim().is_empty() => {
+            Err(validation_error(Some("content"), "Task content cannot be empty"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject the duration units the API does not accept for tasks
+fn validate_duration_unit(unit: Option<&DurationUnit>) -> TodoistResult<()> {
+    match unit {
+        Some(DurationUnit::Hour) => Err(validation_error(
+            Some("duration_unit"),
+            "Task durations must be in minutes or days",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject setting the due date in more than one way, naming the second field set
+fn validate_due(due_string: bool, due_date: bool, due_datetime: bool) -> TodoistResult<()> {
+    let set = [
+        ("due_string", due_string),
+        ("due_date", due_date),
+        ("due_datetime", due_datetime),
+    ];
+    match set.iter().filter(|(_, is_set)| *is_set).nth(1) {
+        Some((field, _)) => Err(validation_error(
+            Some(field),
+            "Only one of due_string, due_date and due_datetime can be set",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Fluent builder for [`CreateTaskArgs`], created with [`CreateTaskArgs::builder`]
+#[derive(Debug)]
+pub struct CreateTaskArgsBuilder {
+    args: CreateTaskArgs,
+}
+
+impl CreateTaskArgsBuilder {
+    /// Set the task description (Markdown)
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.args.description = Some(description.into());
+        self
+    }
+
+    /// Create the task in this project (the Inbox when unset)
+    pub fn project_id(mut self, project_id: impl Into<String>) -> Self {
+        self.args.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Create the task in this section
+    pub fn section_id(mut self, section_id: impl Into<String>) -> Self {
+        self.args.section_id = Some(section_id.into());
+        self
+    }
+
+    /// Create the task as a sub-task of this task
+    pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+        self.args.parent_id = Some(parent_id.into());
+        self
+    }
+
+    /// Set the position among sibling tasks
+    pub fn order(mut self, order: i32) -> Self {
+        self.args.order = Some(order);
+        self
+    }
+
+    /// Set the priority (see [`Priority`] for how it maps to the app's p1-p4)
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.args.priority = Some(priority);
+        self
+    }
+
+    /// Set the label names, e.g. `["errand", "home"]`
+    pub fn labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.labels = Some(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the due date in natural language, e.g. `"every monday at 9am"`
+    pub fn due_string(mut self, due_string: impl Into<String>) -> Self {
+        self.args.due_string = Some(due_string.into());
+        self
+    }
+
+    /// Set an all-day due date as `YYYY-MM-DD`
+    pub fn due_date(mut self, due_date: impl Into<String>) -> Self {
+        self.args.due_date = Some(due_date.into());
+        self
+    }
+
+    /// Set a due date and time in RFC 3339, e.g. `"2024-01-20T09:00:00Z"`
+    pub fn due_datetime(mut self, due_datetime: impl Into<String>) -> Self {
+        self.args.due_datetime = Some(due_datetime.into());
+        self
+    }
+
+    /// Set the language of the due string, as a [`Lang`] or a code such as `"de"`
+    pub fn due_lang(mut self, due_lang: impl Into<Lang>) -> Self {
+        self.args.due_lang = Some(due_lang.into());
+        self
+    }
+
+    /// Set a deadline as `YYYY-MM-DD`
+    pub fn deadline_date(mut self, deadline_date: impl Into<String>) -> Self {
+        self.args.deadline_date = Some(deadline_date.into());
+        self
+    }
+
+    /// Set the language of the deadline, as a [`Lang`] or a code such as `"de"`
+    pub fn deadline_lang(mut self, deadline_lang: impl Into<Lang>) -> Self {
+        self.args.deadline_lang = Some(deadline_lang.into());
+        self
+    }
+
+    /// Set the duration, in the unit given with [`Self::duration_unit`] (both are required)
+    pub fn duration(mut self, duration: i32) -> Self {
+        self.args.duration = Some(duration);
+        self
+    }
+
+    /// Set the unit of [`Self::duration`]
+    pub fn duration_unit(mut self, duration_unit: DurationUnit) -> Self {
+        self.args.duration_unit = Some(duration_unit);
+        self
+    }
+
+    /// Finish building the task creation arguments
+    pub fn build(self) -> CreateTaskArgs {
+        self.args
+    }
 }
 
 /// Task update arguments
@@ -257,9 +1491,11 @@ pub struct UpdateTaskArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<i32>,
+    pub priority: Option<Priority>,
+    /// Replaces all of the task's labels; `Some(vec![])` removes them
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<String>>,
+    /// Natural language due date; `"no date"` removes the due date, including a recurring one
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_string: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,18 +1503,56 @@ pub struct UpdateTaskArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_datetime: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_lang: Option<String>,
+    pub due_lang: Option<Lang>,
+    /// `Some(None)` removes the deadline
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline_date: Option<String>,
+    pub deadline_date: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline_lang: Option<String>,
+    pub deadline_lang: Option<Lang>,
+    /// `Some(None)` removes the duration, together with `duration_unit: Some(None)`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<i32>,
+    pub duration: Option<Option<i32>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration_unit: Option<String>,
+    pub duration_unit: Option<Option<DurationUnit>>,
 }
 
-impl UpdateTaskArgs {
+impl UpdateTaskArgs {
+    /// Value of `due_string` that removes a task's due date
+    pub const NO_DUE_DATE: &'static str = "no date";
+
+    /// Arguments that only remove the task's due date
+    pub fn clear_due() -> Self {
+        Self {
+            due_string: Some(Self::NO_DUE_DATE.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Arguments that only remove all of the task's labels
+    pub fn clear_labels() -> Self {
+        Self {
+            labels: Some(Vec::new()),
+            ..Default::default()
+        }
+    }
+
+    /// Arguments that only remove the task's deadline
+    pub fn clear_deadline() -> Self {
+        Self {
+            deadline_date: Some(None),
+            ..Default::default()
+        }
+    }
+
+    /// Arguments that only remove the task's duration
+    pub fn clear_duration() -> Self {
+        Self {
+            duration: Some(None),
+            duration_unit: Some(None),
+            ..Default::default()
+        }
+    }
+
     /// Check if any fields are set for updating
     pub fn has_updates(&self) -> bool {
         self.content.is_some()
@@ -294,6 +1568,155 @@ impl UpdateTaskArgs {
             || self.duration.is_some()
             || self.duration_unit.is_some()
     }
+
+    /// Check the constraints the API enforces, without sending a request
+    ///
+    /// `update_task` runs this first. Returns a `ValidationError` naming the offending
+    /// field when `content` is set but blank, more than one of `due_string`,
+    /// `due_date` and `due_datetime` is set, or `duration_unit` is [`DurationUnit::Hour`].
+    pub fn validate(&self) -> TodoistResult<()> {
+        validate_content(self.content.as_deref())?;
+        validate_due(
+            self.due_string.is_some(),
+            self.due_date.is_some(),
+            self.due_datetime.is_some(),
+        )?;
+        validate_duration_unit(self.duration_unit.as_ref().and_then(Option::as_ref))
+    }
+}
+
+/// Task move arguments
+/// Exactly one destination should be set
+#[derive(Debug, Serialize, Default)]
+pub struct MoveTaskArgs {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+}
+
+impl MoveTaskArgs {
+    /// Check if a destination is set for the move
+    pub fn has_destination(&self) -> bool {
+        self.destination_count() > 0
+    }
+
+    /// Number of destinations set (a valid move has exactly one)
+    pub fn destination_count(&self) -> usize {
+        [&self.project_id, &self.section_id, &self.parent_id]
+            .iter()
+            .filter(|destination| destination.is_some())
+            .count()
+    }
+}
+
+/// Options for [`crate::TodoistWrapper::duplicate_task_with_options`]
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateOptions {
+    /// Also copy the task's active sub-tasks, recursively
+    pub include_subtasks: bool,
+}
+
+impl CreateTaskArgs {
+    /// Arguments re-creating `task`'s content, description, labels, priority,
+    /// due date, deadline and duration, used to duplicate it
+    ///
+    /// The due date is converted as in [`DueArgs::from_due`]. The placement fields
+    /// (`project_id`, `section_id`, `parent_id`, `order`) are left unset.
+    pub(crate) fn duplicate_of(task: &Task) -> Self {
+        let due = task.due.as_ref().map(DueArgs::from_due).unwrap_or_default();
+        CreateTaskArgs {
+            content: task.content.clone(),
+            description: Some(task.description.clone()).filter(|description| !description.is_empty()),
+            priority: Some(task.priority),
+            labels: Some(task.labels.clone()),
+            due_string: due.due_string,
+            due_date: due.due_date,
+            due_datetime: due.due_datetime,
+            due_lang: due.due_lang,
+            deadline_date: task.deadline.as_ref().map(|deadline| deadline.date.to_string()),
+            deadline_lang: task.deadline.as_ref().and_then(|deadline| deadline.lang.clone()),
+            duration: task.duration.as_ref().map(|duration| duration.amount),
+            duration_unit: task.duration.as_ref().map(|duration| duration.unit.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Arguments re-creating `task` in the same project, section and parent task
+///
+/// The due date is copied as its due string when recurring or floating, and as an
+/// exact date or datetime otherwise, so the new task is due at the same moment.
+impl From<&Task> for CreateTaskArgs {
+    fn from(task: &Task) -> Self {
+        CreateTaskArgs {
+            project_id: Some(task.project_id.clone()),
+            section_id: task.section_id.clone(),
+            parent_id: task.parent_id.clone(),
+            ..CreateTaskArgs::duplicate_of(task)
+        }
+    }
+}
+
+/// Arguments setting every editable field to `task`'s current value, as a starting
+/// point for edit-then-save flows
+///
+/// The due date is converted as for [`CreateTaskArgs`]. A deadline or duration the
+/// task doesn't have is left unset; set it to `Some(None)` to clear it explicitly.
+impl From<&Task> for UpdateTaskArgs {
+    fn from(task: &Task) -> Self {
+        let due = task.due.as_ref().map(DueArgs::from_due).unwrap_or_default();
+        UpdateTaskArgs {
+            content: Some(task.content.clone()),
+            description: Some(task.description.clone()),
+            priority: Some(task.priority),
+            labels: Some(task.labels.clone()),
+            due_string: due.due_string,
+            due_date: due.due_date,
+            due_datetime: due.due_datetime,
+            due_lang: due.due_lang,
+            deadline_date: task.deadline.as_ref().map(|deadline| Some(deadline.date.to_string())),
+            deadline_lang: task.deadline.as_ref().and_then(|deadline| deadline.lang.clone()),
+            duration: task.duration.as_ref().map(|duration| Some(duration.amount)),
+            duration_unit: task.duration.as_ref().map(|duration| Some(duration.unit.clone())),
+        }
+    }
+}
+
+/// Due date fields of the task argument structs
+#[derive(Debug, Default)]
+struct DueArgs {
+    due_string: Option<String>,
+    due_date: Option<String>,
+    due_datetime: Option<String>,
+    due_lang: Option<Lang>,
+}
+
+impl DueArgs {
+    /// Fields re-creating `due`
+    ///
+    /// Recurring and floating due dates are sent as their due string, so the
+    /// recurrence or the wall-clock time is kept; other due dates are sent as an
+    /// exact date or datetime.
+    fn from_due(due: &Due) -> Self {
+        match &due.datetime {
+            _ if due.is_recurring || due.is_floating() => DueArgs {
+                due_string: Some(due.string.clone()),
+                due_lang: due.lang.clone(),
+                ..Default::default()
+            },
+            Some(datetime) => DueArgs {
+                due_datetime: Some(datetime.to_string()),
+                ..Default::default()
+            },
+            None => DueArgs {
+                due_date: Some(due.date.to_string()),
+                ..Default::default()
+            },
+        }
+    }
 }
 
 /// Project creation arguments
@@ -301,13 +1724,19 @@ impl UpdateTaskArgs {
 pub struct CreateProjectArgs {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub color: Option<String>,
+    pub color: Option<Color>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_favorite: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub view_style: Option<String>,
+    pub view_style: Option<ViewStyle>,
+    /// Position among sibling projects, as in [`Project::child_order`]
+    ///
+    /// The REST endpoint has no ordering field, so `create_project` applies this
+    /// with a `project_reorder` Sync API command once the project exists.
+    #[serde(skip)]
+    pub child_order: Option<i32>,
 }
 
 /// Project update arguments
@@ -315,12 +1744,13 @@ pub struct CreateProjectArgs {
 pub struct UpdateProjectArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// The API cannot remove a color; `Some(Color::Charcoal)` restores the default one
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub color: Option<String>,
+    pub color: Option<Color>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_favorite: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub view_style: Option<String>,
+    pub view_style: Option<ViewStyle>,
 }
 
 impl UpdateProjectArgs {
@@ -335,7 +1765,7 @@ impl UpdateProjectArgs {
 pub struct CreateLabelArgs {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub color: Option<String>,
+    pub color: Option<Color>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -348,7 +1778,7 @@ pub struct UpdateLabelArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub color: Option<String>,
+    pub color: Option<Color>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -374,7 +1804,19 @@ pub struct CreateSectionArgs {
 /// Section update arguments
 #[derive(Debug, Serialize, Default)]
 pub struct UpdateSectionArgs {
-    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New `section_order` within the project
+    /// The REST endpoint only accepts a name, so this is applied with the Sync API `section_reorder` command
+    #[serde(skip)]
+    pub order: Option<i32>,
+}
+
+impl UpdateSectionArgs {
+    /// Check if any fields are set for updating
+    pub fn has_updates(&self) -> bool {
+        self.name.is_some() || self.order.is_some()
+    }
 }
 
 /// Comment creation arguments
@@ -403,13 +1845,84 @@ impl UpdateCommentArgs {
     }
 }
 
+/// Reminder creation arguments
+///
+/// Build them with [`CreateReminderArgs::relative`] or [`CreateReminderArgs::absolute`].
+#[derive(Debug, Serialize, Clone)]
+pub struct CreateReminderArgs {
+    #[serde(rename = "type")]
+    pub reminder_type: ReminderType,
+    /// Minutes before the task's due time, for relative reminders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minute_offset: Option<i32>,
+    /// Date and time to trigger at (e.g. `2024-01-20T09:00:00Z`), for absolute reminders
+    #[serde(
+        rename = "due",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_reminder_due"
+    )]
+    pub due_datetime: Option<String>,
+    /// User to notify, defaulting to the current user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_uid: Option<String>,
+}
+
+impl CreateReminderArgs {
+    /// A reminder triggering `minutes` before the task's due time
+    ///
+    /// The task must have a due date with a time.
+    pub fn relative(minutes: i32) -> Self {
+        Self {
+            reminder_type: ReminderType::Relative,
+            minute_offset: Some(minutes),
+            due_datetime: None,
+            notify_uid: None,
+        }
+    }
+
+    /// A reminder triggering at the given date and time
+    pub fn absolute(datetime: impl Into<String>) -> Self {
+        Self {
+            reminder_type: ReminderType::Absolute,
+            minute_offset: None,
+            due_datetime: Some(datetime.into()),
+            notify_uid: None,
+        }
+    }
+}
+
+/// Serialize a reminder datetime as the due object the Sync API expects
+fn serialize_reminder_due<S>(datetime: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serde_json::json!({ "date": datetime }).serialize(serializer)
+}
+
 /// Task filter arguments
-#[derive(Debug, Serialize)]
+///
+/// Either set `query` to a Todoist filter (e.g. `today & #Work`), or leave it empty
+/// and narrow tasks with `label`, `project_id`, `section_id` and `ids`. The API
+/// cannot combine the two.
+#[derive(Debug, Serialize, Default)]
 pub struct TaskFilterArgs {
     pub query: String,
-    pub lang: Option<String>,
+    pub lang: Option<Lang>,
     pub limit: Option<i32>,
     pub cursor: Option<String>,
+    /// Only tasks with this label (by name)
+    pub label: Option<String>,
+    pub project_id: Option<String>,
+    pub section_id: Option<String>,
+    /// Only the tasks with these IDs
+    pub ids: Option<Vec<String>>,
+}
+
+impl TaskFilterArgs {
+    /// Check if any of the structured filters (`label`, `project_id`, `section_id`, `ids`) is set
+    pub fn has_structured_filters(&self) -> bool {
+        self.label.is_some() || self.project_id.is_some() || self.section_id.is_some() || self.ids.is_some()
+    }
 }
 
 /// Project filter arguments
@@ -435,6 +1948,9 @@ pub struct SectionFilterArgs {
 }
 
 /// Comment filter arguments
+///
+/// The endpoint has no date-range or ordering parameters: filter and sort by
+/// `posted_at` once the comments are fetched.
 #[derive(Debug, Serialize)]
 pub struct CommentFilterArgs {
     pub task_id: Option<String>,
@@ -461,33 +1977,413 @@ pub struct CompletedTasksFilterArgs {
     pub cursor: Option<String>,
 }
 
+/// Activity log filter arguments
+///
+/// The endpoint has no date-range parameters: filter by `event_date` once the
+/// events are fetched.
+#[derive(Debug, Default)]
+pub struct ActivityFilterArgs {
+    pub object_type: Option<ActivityObjectType>,
+    /// Only events about this object; requires `object_type`
+    pub object_id: Option<String>,
+    pub event_type: Option<ActivityEventType>,
+    /// Only events about this project, its tasks and their comments
+    pub parent_project_id: Option<String>,
+    /// Only events about this task and its comments
+    pub parent_task_id: Option<String>,
+    /// Only events caused by this collaborator
+    pub initiator_id: Option<String>,
+    /// At most 100
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+}
+
+/// Snapshot of a user's projects, sections, labels and active tasks, as returned by `load_workspace`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Workspace {
+    pub projects: Vec<Project>,
+    pub sections: Vec<Section>,
+    pub labels: Vec<Label>,
+    /// Active tasks keyed by project ID, each list in the order the API returned it
+    pub tasks_by_project: std::collections::HashMap<String, Vec<Task>>,
+}
+
+impl Workspace {
+    /// Group tasks by project ID, keeping their relative order
+    pub(crate) fn group_tasks(tasks: Vec<Task>) -> std::collections::HashMap<String, Vec<Task>> {
+        let mut tasks_by_project: std::collections::HashMap<String, Vec<Task>> = std::collections::HashMap::new();
+        for task in tasks {
+            tasks_by_project.entry(task.project_id.clone()).or_default().push(task);
+        }
+        tasks_by_project
+    }
+
+    /// Get the tasks of a project, or an empty slice if it has none
+    pub fn tasks_for_project(&self, project_id: &str) -> &[Task] {
+        self.tasks_by_project.get(project_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterate over every task in the workspace
+    pub fn tasks(&self) -> impl Iterator<Item = &Task> {
+        self.tasks_by_project.values().flatten()
+    }
+}
+
+/// A project with its sections and active tasks, as returned by `get_project_data`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectData {
+    pub project: Project,
+    /// Sections in `section_order`, each with its tasks
+    pub sections: Vec<SectionWithTasks>,
+    /// Tasks outside any section, in `child_order`
+    pub unsectioned_tasks: Vec<Task>,
+}
+
+/// A section and its active tasks, in `child_order`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionWithTasks {
+    pub section: Section,
+    pub tasks: Vec<Task>,
+}
+
+impl ProjectData {
+    /// Sort sections and tasks and group the project's tasks under their sections
+    ///
+    /// Tasks from other projects are dropped. A task whose section is not among
+    /// `sections` lands in `unsectioned_tasks`.
+    pub(crate) fn new(project: Project, mut sections: Vec<Section>, mut tasks: Vec<Task>) -> Self {
+        sections.retain(|section| section.project_id == project.id);
+        sections.sort_by_key(|section| section.section_order);
+        tasks.retain(|task| task.project_id == project.id);
+        tasks.sort_by_key(|task| task.child_order);
+
+        let mut tasks_by_section: std::collections::HashMap<String, Vec<Task>> = std::collections::HashMap::new();
+        let mut unsectioned_tasks = Vec::new();
+        for task in tasks {
+            match task
+                .section_id
+                .as_deref()
+                .filter(|id| sections.iter().any(|section| section.id == *id))
+            {
+                Some(section_id) => tasks_by_section.entry(section_id.to_string()).or_default().push(task),
+                None => unsectioned_tasks.push(task),
+            }
+        }
+
+        let sections = sections
+            .into_iter()
+            .map(|section| SectionWithTasks {
+                tasks: tasks_by_section.remove(&section.id).unwrap_or_default(),
+                section,
+            })
+            .collect();
+        ProjectData {
+            project,
+            sections,
+            unsectioned_tasks,
+        }
+    }
+
+    /// Iterate over every task in the project, unsectioned tasks first
+    pub fn tasks(&self) -> impl Iterator<Item = &Task> {
+        self.unsectioned_tasks
+            .iter()
+            .chain(self.sections.iter().flat_map(|section| &section.tasks))
+    }
+}
+
+/// Blueprint of a project, its sections and tasks, created by `apply_template`
+#[derive(Debug, Default)]
+pub struct ProjectTemplate {
+    pub project: CreateProjectArgs,
+    /// Tasks outside any section
+    pub tasks: Vec<TaskTemplate>,
+    /// Sections in the order they should appear
+    pub sections: Vec<SectionTemplate>,
+}
+
+/// A section of a [`ProjectTemplate`] and its tasks
+#[derive(Debug, Default)]
+pub struct SectionTemplate {
+    pub name: String,
+    pub tasks: Vec<TaskTemplate>,
+}
+
+/// A task of a [`ProjectTemplate`] and its sub-tasks
+///
+/// The placement fields of `task` (`project_id`, `section_id`, `parent_id`) are
+/// ignored; they are filled in from the task's position in the template.
+#[derive(Debug, Default)]
+pub struct TaskTemplate {
+    pub task: CreateTaskArgs,
+    pub subtasks: Vec<TaskTemplate>,
+}
+
+impl From<CreateTaskArgs> for TaskTemplate {
+    fn from(task: CreateTaskArgs) -> Self {
+        TaskTemplate {
+            task,
+            subtasks: Vec::new(),
+        }
+    }
+}
+
+/// Correspondence between a legacy numeric ID (REST v2, Sync v9) and its API v1 ID
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct IdMapping {
+    /// Legacy numeric ID, e.g. `918273645`
+    pub old_id: Option<String>,
+    /// API v1 ID, e.g. `6VfWjjjFg2xqX6Pa`
+    pub new_id: Option<String>,
+}
+
+/// Rate-limit quota reported by the API in response headers
+///
+/// Each field is `None` when the matching header was absent or not a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimitStatus {
+    /// Requests allowed in the current window (`X-RateLimit-Limit`)
+    pub limit: Option<u64>,
+    /// Requests left in the current window (`X-RateLimit-Remaining`)
+    pub remaining: Option<u64>,
+    /// When the window resets, in seconds as sent by the API (`X-RateLimit-Reset`)
+    pub reset: Option<u64>,
+}
+
+/// A Sync API command, sent in a batch with `sync_commands`
+///
+/// See the Sync API documentation for the available command types and their
+/// `args`. Commands creating an object can carry a `temp_id`, which later commands
+/// in the same batch may use in place of the object's real ID.
+#[derive(Debug, Serialize, Clone)]
+pub struct SyncCommand {
+    #[serde(rename = "type")]
+    pub command_type: String,
+    /// Unique ID of this command, used as its key in `SyncResult::sync_status`
+    pub uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_id: Option<String>,
+    pub args: serde_json::Value,
+}
+
+impl SyncCommand {
+    /// Create a command of the given type with a fresh random `uuid`
+    pub fn new(command_type: impl Into<String>, args: serde_json::Value) -> Self {
+        Self {
+            command_type: command_type.into(),
+            uuid: uuid::Uuid::new_v4().to_string(),
+            temp_id: None,
+            args,
+        }
+    }
+
+    /// Set a temporary ID for the object this command creates
+    pub fn with_temp_id(mut self, temp_id: impl Into<String>) -> Self {
+        self.temp_id = Some(temp_id.into());
+        self
+    }
+}
+
+/// Outcome of a batch of Sync API commands
+#[derive(Debug, Clone, Default)]
+pub struct SyncResult {
+    /// Status of each command, keyed by its `uuid`
+    pub sync_status: std::collections::HashMap<String, TodoistResult<()>>,
+    /// Real IDs of the objects created with a `temp_id`, keyed by that `temp_id`
+    pub temp_id_mapping: std::collections::HashMap<String, String>,
+}
+
+/// Resources returned by [`crate::TodoistWrapper::sync`]
+///
+/// After an incremental sync, each list only holds the objects that changed since
+/// the token passed in; deleted objects are included with `is_deleted` set.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SyncResponse {
+    /// Token to pass to the next call to only receive later changes
+    pub sync_token: String,
+    /// Whether the lists hold every object rather than the changes since the given token
+    pub full_sync: bool,
+    /// Tasks (`items` in the Sync API)
+    #[serde(default, rename = "items")]
+    pub tasks: Vec<Task>,
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    pub sections: Vec<Section>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+}
+
+impl SyncResult {
+    /// Check whether every command succeeded
+    pub fn is_ok(&self) -> bool {
+        self.sync_status.values().all(Result::is_ok)
+    }
+
+    /// Get the status of a command, or `None` if the API did not report one
+    pub fn status(&self, command: &SyncCommand) -> Option<&TodoistResult<()>> {
+        self.sync_status.get(&command.uuid)
+    }
+
+    /// Get the real ID assigned to an object created with the given `temp_id`
+    pub fn real_id(&self, temp_id: &str) -> Option<&str> {
+        self.temp_id_mapping.get(temp_id).map(String::as_str)
+    }
+}
+
+/// Outcome of [`crate::TodoistWrapper::delete_project_with_report`]
+#[derive(Debug, Clone, Default)]
+pub struct DeletionReport {
+    pub project_id: String,
+    /// Sections the project held that are now gone
+    pub deleted_sections: Vec<String>,
+    /// Active tasks the project held that are now gone
+    pub deleted_tasks: Vec<String>,
+    /// Sections still present after the delete, with the error that kept them from being removed
+    pub remaining_sections: Vec<(String, TodoistError)>,
+    /// Tasks still present after the delete, with the error that kept them from being removed
+    pub remaining_tasks: Vec<(String, TodoistError)>,
+}
+
+impl DeletionReport {
+    /// Check whether every section and task of the project is gone
+    pub fn is_complete(&self) -> bool {
+        self.remaining_sections.is_empty() && self.remaining_tasks.is_empty()
+    }
+}
+
+/// What went wrong when a request got no response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NetworkErrorKind {
+    /// The request or the client timeout elapsed
+    Timeout,
+    /// The connection could not be established (DNS lookup, refused connection, TLS handshake)
+    Connect,
+    /// Following a redirect failed
+    Redirect,
+    /// Sending the request body or reading the response body failed
+    Body,
+    /// Any other failure
+    Other,
+}
+
+impl NetworkErrorKind {
+    /// Classify a `reqwest` error
+    pub(crate) fn of(err: &reqwest::Error) -> Self {
+        // Connect timeouts are also connect errors; report them as timeouts
+        if err.is_timeout() {
+            NetworkErrorKind::Timeout
+        } else if err.is_connect() {
+            NetworkErrorKind::Connect
+        } else if err.is_redirect() {
+            NetworkErrorKind::Redirect
+        } else if err.is_body() || err.is_decode() {
+            NetworkErrorKind::Body
+        } else {
+            NetworkErrorKind::Other
+        }
+    }
+}
+
+impl fmt::Display for NetworkErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NetworkErrorKind::Timeout => "timed out",
+            NetworkErrorKind::Connect => "connection failed",
+            NetworkErrorKind::Redirect => "redirect failed",
+            NetworkErrorKind::Body => "body transfer failed",
+            NetworkErrorKind::Other => "request failed",
+        })
+    }
+}
+
 /// Represents different types of errors that can occur when interacting with the Todoist API
+///
+/// New variants may be added in minor releases, so matches outside this crate need a
+/// wildcard arm.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum TodoistError {
     /// Rate limiting error (HTTP 429)
-    RateLimited { retry_after: Option<u64>, message: String },
+    RateLimited {
+        retry_after: Option<u64>,
+        message: String,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
     /// Authentication error (HTTP 401)
-    AuthenticationError { message: String },
+    AuthenticationError {
+        message: String,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
     /// Authorization error (HTTP 403)
-    AuthorizationError { message: String },
+    AuthorizationError {
+        message: String,
+        /// Numeric error code from the API error body, when present
+        error_code: Option<i64>,
+        /// Machine-readable error tag from the API error body, when present
+        error_tag: Option<String>,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
     /// Resource not found (HTTP 404)
     NotFound {
         resource_type: String,
         resource_id: Option<String>,
         message: String,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
+    /// Validation error (HTTP 400, or rejected client-side before sending)
+    ValidationError {
+        field: Option<String>,
+        message: String,
+        /// Numeric error code from the API error body, when present
+        error_code: Option<i64>,
+        /// Machine-readable error tag from the API error body, when present
+        error_tag: Option<String>,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
     },
-    /// Validation error (HTTP 400)
-    ValidationError { field: Option<String>, message: String },
     /// Server error (HTTP 5xx)
-    ServerError { status_code: u16, message: String },
-    /// Network/connection error
-    NetworkError { message: String },
+    ServerError {
+        status_code: u16,
+        message: String,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
+    /// Network/connection error (no response was received)
+    NetworkError {
+        /// Whether the request timed out, could not connect, etc.
+        kind: NetworkErrorKind,
+        message: String,
+    },
     /// JSON parsing error
-    ParseError { message: String },
+    ParseError {
+        message: String,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
     /// Unexpected empty response (when API returns nothing)
-    EmptyResponse { endpoint: String, message: String },
+    EmptyResponse {
+        endpoint: String,
+        message: String,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
     /// Generic error for other cases
-    Generic { status_code: Option<u16>, message: String },
+    Generic {
+        status_code: Option<u16>,
+        message: String,
+        /// Numeric error code from the API error body, when present
+        error_code: Option<i64>,
+        /// Machine-readable error tag from the API error body, when present
+        error_tag: Option<String>,
+        /// Value of the `X-Request-Id` response header, to quote to Todoist support
+        request_id: Option<String>,
+    },
 }
 
 impl TodoistError {
@@ -526,11 +2422,40 @@ impl TodoistError {
         matches!(self, TodoistError::NetworkError { .. })
     }
 
+    /// Check if this is a network error caused by a timeout
+    pub fn is_timeout(&self) -> bool {
+        self.network_error_kind() == Some(NetworkErrorKind::Timeout)
+    }
+
+    /// Get what went wrong for network errors
+    pub fn network_error_kind(&self) -> Option<NetworkErrorKind> {
+        match self {
+            TodoistError::NetworkError { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
     /// Check if this is an empty response error
     pub fn is_empty_response(&self) -> bool {
         matches!(self, TodoistError::EmptyResponse { .. })
     }
 
+    /// Name of the error variant, e.g. `"NotFound"`, for use as a metrics label
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            TodoistError::RateLimited { .. } => "RateLimited",
+            TodoistError::AuthenticationError { .. } => "AuthenticationError",
+            TodoistError::AuthorizationError { .. } => "AuthorizationError",
+            TodoistError::NotFound { .. } => "NotFound",
+            TodoistError::ValidationError { .. } => "ValidationError",
+            TodoistError::ServerError { .. } => "ServerError",
+            TodoistError::NetworkError { .. } => "NetworkError",
+            TodoistError::ParseError { .. } => "ParseError",
+            TodoistError::EmptyResponse { .. } => "EmptyResponse",
+            TodoistError::Generic { .. } => "Generic",
+        }
+    }
+
     /// Get the retry after value for rate limiting errors
     pub fn retry_after(&self) -> Option<u64> {
         match self {
@@ -539,11 +2464,95 @@ impl TodoistError {
         }
     }
 
-    /// Get the HTTP status code if available
+    /// Get the HTTP status code the error stands for, if any
+    ///
+    /// Each HTTP-derived variant reports its status: 429, 401, 403, 404, 400, the
+    /// 5xx code of a `ServerError` and the code stored in `Generic`. Validation and
+    /// not-found errors raised client-side report 400 and 404 too, matching the
+    /// response the API would have given. Network, parse and empty-response errors
+    /// have no status.
     pub fn status_code(&self) -> Option<u16> {
         match self {
+            TodoistError::RateLimited { .. } => Some(429),
+            TodoistError::AuthenticationError { .. } => Some(401),
+            TodoistError::AuthorizationError { .. } => Some(403),
+            TodoistError::NotFound { .. } => Some(404),
+            TodoistError::ValidationError { .. } => Some(400),
             TodoistError::ServerError { status_code, .. } => Some(*status_code),
             TodoistError::Generic { status_code, .. } => *status_code,
+            TodoistError::NetworkError { .. }
+            | TodoistError::ParseError { .. }
+            | TodoistError::EmptyResponse { .. } => None,
+        }
+    }
+
+    /// Get the `X-Request-Id` of the failed response, if any
+    ///
+    /// Todoist support asks for this ID when diagnosing an issue.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            TodoistError::RateLimited { request_id, .. }
+            | TodoistError::AuthenticationError { request_id, .. }
+            | TodoistError::AuthorizationError { request_id, .. }
+            | TodoistError::NotFound { request_id, .. }
+            | TodoistError::ValidationError { request_id, .. }
+            | TodoistError::ServerError { request_id, .. }
+            | TodoistError::ParseError { request_id, .. }
+            | TodoistError::EmptyResponse { request_id, .. }
+            | TodoistError::Generic { request_id, .. } => request_id.as_deref(),
+            TodoistError::NetworkError { .. } => None,
+        }
+    }
+
+    /// Attach the `X-Request-Id` of the response this error was built from
+    pub(crate) fn with_request_id(mut self, id: Option<String>) -> Self {
+        match &mut self {
+            TodoistError::RateLimited { request_id, .. }
+            | TodoistError::AuthenticationError { request_id, .. }
+            | TodoistError::AuthorizationError { request_id, .. }
+            | TodoistError::NotFound { request_id, .. }
+            | TodoistError::ValidationError { request_id, .. }
+            | TodoistError::ServerError { request_id, .. }
+            | TodoistError::ParseError { request_id, .. }
+            | TodoistError::EmptyResponse { request_id, .. }
+            | TodoistError::Generic { request_id, .. } => *request_id = id,
+            TodoistError::NetworkError { .. } => {}
+        }
+        self
+    }
+
+    /// Record which resource a `NotFound` error refers to
+    ///
+    /// Other errors are returned unchanged.
+    pub(crate) fn for_resource(mut self, kind: &str, id: &str) -> Self {
+        if let TodoistError::NotFound {
+            resource_type,
+            resource_id,
+            ..
+        } = &mut self
+        {
+            *resource_type = kind.to_string();
+            *resource_id = Some(id.to_string());
+        }
+        self
+    }
+
+    /// Get the numeric error code reported by the API, if any
+    pub fn error_code(&self) -> Option<i64> {
+        match self {
+            TodoistError::AuthorizationError { error_code, .. }
+            | TodoistError::ValidationError { error_code, .. }
+            | TodoistError::Generic { error_code, .. } => *error_code,
+            _ => None,
+        }
+    }
+
+    /// Get the machine-readable error tag reported by the API (e.g. `MAX_PROJECTS_LIMIT_REACHED`), if any
+    pub fn error_tag(&self) -> Option<&str> {
+        match self {
+            TodoistError::AuthorizationError { error_tag, .. }
+            | TodoistError::ValidationError { error_tag, .. }
+            | TodoistError::Generic { error_tag, .. } => error_tag.as_deref(),
             _ => None,
         }
     }
@@ -552,57 +2561,63 @@ impl TodoistError {
 impl fmt::Display for TodoistError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            TodoistError::RateLimited { retry_after, message } => {
+            TodoistError::RateLimited {
+                retry_after, message, ..
+            } => {
                 if let Some(seconds) = retry_after {
-                    write!(f, "Rate limited: {} (retry after {} seconds)", message, seconds)
+                    write!(f, "Rate limited: {} (retry after {} seconds)", message, seconds)?
                 } else {
-                    write!(f, "Rate limited: {}", message)
+                    write!(f, "Rate limited: {}", message)?
                 }
             }
-            TodoistError::AuthenticationError { message } => {
-                write!(f, "Authentication error: {}", message)
-            }
-            TodoistError::AuthorizationError { message } => {
-                write!(f, "Authorization error: {}", message)
-            }
+            TodoistError::AuthenticationError { message, .. } => write!(f, "Authentication error: {}", message)?,
+            TodoistError::AuthorizationError { message, .. } => write!(f, "Authorization error: {}", message)?,
             TodoistError::NotFound {
                 resource_type,
                 resource_id,
                 message,
+                ..
             } => {
                 if let Some(id) = resource_id {
-                    write!(f, "{} not found (ID: {}): {}", resource_type, id, message)
+                    write!(f, "{} not found (ID: {}): {}", resource_type, id, message)?
                 } else {
-                    write!(f, "{} not found: {}", resource_type, message)
+                    write!(f, "{} not found: {}", resource_type, message)?
                 }
             }
-            TodoistError::ValidationError { field, message } => {
+            TodoistError::ValidationError { field, message, .. } => {
                 if let Some(field_name) = field {
-                    write!(f, "Validation error for field '{}': {}", field_name, message)
+                    write!(f, "Validation error for field '{}': {}", field_name, message)?
                 } else {
-                    write!(f, "Validation error: {}", message)
+                    write!(f, "Validation error: {}", message)?
                 }
             }
-            TodoistError::ServerError { status_code, message } => {
-                write!(f, "Server error ({}): {}", status_code, message)
-            }
-            TodoistError::NetworkError { message } => {
-                write!(f, "Network error: {}", message)
-            }
-            TodoistError::ParseError { message } => {
-                write!(f, "Parse error: {}", message)
-            }
-            TodoistError::EmptyResponse { endpoint, message } => {
-                write!(f, "Empty response from {}: {}", endpoint, message)
+            TodoistError::ServerError {
+                status_code, message, ..
+            } => write!(f, "Server error ({}): {}", status_code, message)?,
+            TodoistError::NetworkError {
+                kind: NetworkErrorKind::Other,
+                message,
+            } => write!(f, "Network error: {}", message)?,
+            TodoistError::NetworkError { kind, message } => write!(f, "Network error ({}): {}", kind, message)?,
+            TodoistError::ParseError { message, .. } => write!(f, "Parse error: {}", message)?,
+            TodoistError::EmptyResponse { endpoint, message, .. } => {
+                write!(f, "Empty response from {}: {}", endpoint, message)?
             }
-            TodoistError::Generic { status_code, message } => {
+            TodoistError::Generic {
+                status_code, message, ..
+            } => {
                 if let Some(code) = status_code {
-                    write!(f, "Error ({}): {}", code, message)
+                    write!(f, "Error ({}): {}", code, message)?
                 } else {
-                    write!(f, "Error: {}", message)
+                    write!(f, "Error: {}", message)?
                 }
             }
         }
+
+        if let Some(request_id) = self.request_id() {
+            write!(f, " (request ID: {})", request_id)?;
+        }
+        Ok(())
     }
 }
 
@@ -610,9 +2625,7 @@ impl std::error::Error for TodoistError {}
 
 impl From<reqwest::Error> for TodoistError {
     fn from(err: reqwest::Error) -> Self {
-        TodoistError::NetworkError {
-            message: format!("Request failed: {}", err),
-        }
+        network_error("Request failed", &err)
     }
 }
 
@@ -620,6 +2633,7 @@ impl From<serde_json::Error> for TodoistError {
     fn from(err: serde_json::Error) -> Self {
         TodoistError::ParseError {
             message: format!("JSON error: {}", err),
+            request_id: None,
         }
     }
 }
@@ -632,6 +2646,7 @@ pub fn rate_limited_error(message: impl Into<String>, retry_after: Option<u64>)
     TodoistError::RateLimited {
         retry_after,
         message: message.into(),
+        request_id: None,
     }
 }
 
@@ -640,6 +2655,37 @@ pub fn empty_response_error(endpoint: impl Into<String>, message: impl Into<Stri
     TodoistError::EmptyResponse {
         endpoint: endpoint.into(),
         message: message.into(),
+        request_id: None,
+    }
+}
+
+/// Build a `NetworkError` from a `reqwest` error, classifying its kind
+pub(crate) fn network_error(context: &str, err: &reqwest::Error) -> TodoistError {
+    TodoistError::NetworkError {
+        kind: NetworkErrorKind::of(err),
+        message: format!("{}: {}", context, err),
+    }
+}
+
+/// Helper function to create a validation error without API error details
+pub fn validation_error(field: Option<&str>, message: impl Into<String>) -> TodoistError {
+    TodoistError::ValidationError {
+        field: field.map(str::to_string),
+        message: message.into(),
+        error_code: None,
+        error_tag: None,
+        request_id: None,
+    }
+}
+
+/// Helper function to create a generic error without API error details
+pub fn generic_error(status_code: Option<u16>, message: impl Into<String>) -> TodoistError {
+    TodoistError::Generic {
+        status_code,
+        message: message.into(),
+        error_code: None,
+        error_tag: None,
+        request_id: None,
     }
 }
 
@@ -653,5 +2699,97 @@ pub fn not_found_error(
         resource_type: resource_type.into(),
         resource_id: resource_id.map(|id| id.into()),
         message: message.into(),
+        request_id: None,
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_types {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Format used by the API for floating due datetimes (no offset)
+    const FLOATING_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+    /// Date and time of a due date (`chrono` feature)
+    ///
+    /// Todoist due datetimes are either fixed to an instant (they carry a UTC offset,
+    /// usually `Z`) or floating (no offset, e.g. `2024-01-18T14:00:00`). A floating
+    /// datetime is a wall-clock time in the user's current timezone and cannot be
+    /// turned into an instant without knowing that timezone.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum DueDateTime {
+        /// A datetime carrying a UTC offset, normalized to UTC
+        Fixed(DateTime<Utc>),
+        /// A wall-clock datetime without timezone information
+        Floating(NaiveDateTime),
+    }
+
+    impl DueDateTime {
+        /// Whether this is a floating (timezone-less) datetime
+        pub fn is_floating(&self) -> bool {
+            matches!(self, DueDateTime::Floating(_))
+        }
+
+        /// The calendar date part, in UTC for fixed datetimes
+        pub fn date_naive(&self) -> NaiveDate {
+            match self {
+                DueDateTime::Fixed(instant) => instant.date_naive(),
+                DueDateTime::Floating(wall_clock) => wall_clock.date(),
+            }
+        }
+    }
+
+    impl FromStr for DueDateTime {
+        type Err = chrono::ParseError;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match DateTime::parse_from_rfc3339(value) {
+                Ok(instant) => Ok(DueDateTime::Fixed(instant.with_timezone(&Utc))),
+                Err(_) => value.parse::<NaiveDateTime>().map(DueDateTime::Floating),
+            }
+        }
+    }
+
+    impl fmt::Display for DueDateTime {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DueDateTime::Fixed(instant) => {
+                    write!(f, "{}", instant.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+                }
+                DueDateTime::Floating(wall_clock) => write!(f, "{}", wall_clock.format(FLOATING_FORMAT)),
+            }
+        }
+    }
+
+    impl Serialize for DueDateTime {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for DueDateTime {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let value = String::deserialize(deserializer)?;
+            value.parse().map_err(serde::de::Error::custom)
+        }
+    }
+
+    /// Deserialize a due date, keeping only the date part of a datetime value
+    pub(super) fn deserialize_due_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        let date = value.get(..10).unwrap_or(&value);
+        date.parse().map_err(serde::de::Error::custom)
     }
 }
@@ -0,0 +1,149 @@
+//! OAuth helpers for obtaining a token on behalf of another user
+//!
+//! Send the user to [`build_authorize_url`]. Todoist then redirects them to the
+//! app's configured redirect URL with `code` and `state` query parameters; check
+//! that `state` matches, then trade the code for a token with [`exchange_code`].
+//!
+//! ```rust,no_run
+//! use todoist_api::oauth::{self, Scope};
+//! use todoist_api::TodoistWrapper;
+//!
+//! # async fn example(code: &str) -> todoist_api::TodoistResult<()> {
+//! let url = oauth::build_authorize_url("client-id", &[Scope::DataReadWrite], "random-state");
+//! println!("Open {url}");
+//!
+//! let token = oauth::exchange_code("client-id", "client-secret", code).await?;
+//! let todoist = TodoistWrapper::new(token.access_token);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::*;
+use crate::wrapper::{error_from_status, parse_retry_after, ApiErrorBody, DEFAULT_USER_AGENT};
+
+/// Page users are sent to in order to authorize an app
+pub const AUTHORIZE_URL: &str = "https://todoist.com/oauth/authorize";
+
+/// Endpoint exchanging an authorization code for an access token
+pub const ACCESS_TOKEN_URL: &str = "https://todoist.com/oauth/access_token";
+
+/// A permission an app can request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Add new tasks, without reading or modifying existing data
+    TaskAdd,
+    /// Read tasks, projects, labels and filters
+    DataRead,
+    /// Read and write tasks, projects, labels and filters (includes `TaskAdd` and `DataRead`)
+    DataReadWrite,
+    /// Delete tasks, labels and filters
+    DataDelete,
+    /// Delete projects
+    ProjectDelete,
+    /// List backups without an MFA token
+    BackupsRead,
+}
+
+impl Scope {
+    /// The scope name used by the API, e.g. `data:read`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::TaskAdd => "task:add",
+            Scope::DataRead => "data:read",
+            Scope::DataReadWrite => "data:read_write",
+            Scope::DataDelete => "data:delete",
+            Scope::ProjectDelete => "project:delete",
+            Scope::BackupsRead => "backups:read",
+        }
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Token returned by a successful code exchange
+///
+/// The `Debug` output redacts the token so it does not end up in logs.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AccessToken {
+    /// Token to pass to [`crate::TodoistWrapper::new`]
+    pub access_token: String,
+    /// Token type, `Bearer`
+    pub token_type: String,
+}
+
+impl std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("access_token", &"<redacted>")
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
+/// Build the URL to send a user to when asking them to authorize the app
+///
+/// `state` should be unique and unguessable; Todoist sends it back with the
+/// authorization code so the redirect can be checked against cross-site request forgery.
+pub fn build_authorize_url(client_id: &str, scopes: &[Scope], state: &str) -> String {
+    let scope = scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(",");
+    reqwest::Url::parse_with_params(
+        AUTHORIZE_URL,
+        [("client_id", client_id), ("scope", scope.as_str()), ("state", state)],
+    )
+    .expect("AUTHORIZE_URL is a valid URL")
+    .into()
+}
+
+/// Exchange the authorization code from the redirect for an access token
+pub async fn exchange_code(client_id: &str, client_secret: &str, code: &str) -> TodoistResult<AccessToken> {
+    exchange_code_with_url(ACCESS_TOKEN_URL, client_id, client_secret, code).await
+}
+
+/// Exchange an authorization code against a custom token endpoint (useful for testing)
+pub async fn exchange_code_with_url(
+    token_url: &str,
+    client_id: &str,
+    client_secret: &str,
+    code: &str,
+) -> TodoistResult<AccessToken> {
+    let response = reqwest::Client::new()
+        .post(token_url)
+        .header(reqwest::header::USER_AGENT, DEFAULT_USER_AGENT)
+        .form(&[
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("code", code),
+        ])
+        .send()
+        .await
+        .map_err(|e| network_error("Failed to send request", &e))?;
+
+    let status = response.status();
+    let retry_after = response
+        .headers()
+        .get("Retry-After")
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_retry_after);
+    let text = response
+        .text()
+        .await
+        .map_err(|e| network_error("Failed to read response body", &e))?;
+
+    if !status.is_success() {
+        return Err(error_from_status(
+            status.as_u16(),
+            ApiErrorBody::parse(text),
+            retry_after,
+        ));
+    }
+    serde_json::from_str(&text).map_err(|e| TodoistError::ParseError {
+        message: format!("Failed to parse response: {}", e),
+        request_id: None,
+    })
+}
@@ -0,0 +1,203 @@
+//! Webhook payloads and signature verification (`webhooks` feature)
+//!
+//! Todoist sends each webhook as a JSON envelope with the event name, the ID of
+//! the user it concerns and the affected object in `event_data`. Verify the
+//! `X-Todoist-Hmac-SHA256` header with [`verify_signature`] before trusting a
+//! payload, then parse it with [`WebhookPayload::from_slice`].
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::*;
+
+/// Name of the header carrying the webhook signature
+pub const SIGNATURE_HEADER: &str = "X-Todoist-Hmac-SHA256";
+
+/// Check a webhook signature against the raw request body
+///
+/// The header value is the base64-encoded HMAC-SHA256 of the body, keyed with
+/// the app's client secret. The comparison runs in constant time. Pass the body
+/// exactly as received: re-serialized JSON will not match.
+pub fn verify_signature(client_secret: &str, raw_body: &[u8], header_value: &str) -> bool {
+    let Ok(signature) = base64::engine::general_purpose::STANDARD.decode(header_value.trim()) else {
+        return false;
+    };
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, client_secret.as_bytes());
+    ring::hmac::verify(&key, raw_body, &signature).is_ok()
+}
+
+/// User who triggered a webhook event
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct Initiator {
+    pub id: String,
+    pub email: Option<String>,
+    pub full_name: Option<String>,
+    pub image_id: Option<String>,
+    #[serde(default)]
+    pub is_premium: bool,
+}
+
+/// A webhook request body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookPayload {
+    /// The event and the object it concerns
+    pub event: Event,
+    /// ID of the user the webhook was delivered for
+    pub user_id: String,
+    /// User who triggered the event, which may differ from `user_id` in shared projects
+    pub initiator: Option<Initiator>,
+    /// When the event happened
+    pub triggered_at: Option<Timestamp>,
+    /// Webhook format version
+    pub version: Option<String>,
+    /// Additional event information, such as `old_item` and `update_intent` on `item:updated`
+    pub event_data_extra: Option<Value>,
+}
+
+impl WebhookPayload {
+    /// Parse a raw webhook request body
+    pub fn from_slice(raw_body: &[u8]) -> TodoistResult<Self> {
+        Ok(serde_json::from_slice(raw_body)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Envelope {
+            event_name: String,
+            user_id: String,
+            event_data: Value,
+            initiator: Option<Initiator>,
+            triggered_at: Option<Timestamp>,
+            version: Option<String>,
+            event_data_extra: Option<Value>,
+        }
+
+        let envelope = Envelope::deserialize(deserializer)?;
+        let event = Event::from_parts(envelope.event_name, envelope.event_data).map_err(serde::de::Error::custom)?;
+        Ok(WebhookPayload {
+            event,
+            user_id: envelope.user_id,
+            initiator: envelope.initiator,
+            triggered_at: envelope.triggered_at,
+            version: envelope.version,
+            event_data_extra: envelope.event_data_extra,
+        })
+    }
+}
+
+/// A webhook event with its typed `event_data`
+///
+/// Events this crate does not model (filters, reminders, or names added later)
+/// are kept as [`Event::Other`] with their raw data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// `item:added`
+    ItemAdded(Task),
+    /// `item:updated`
+    ItemUpdated(Task),
+    /// `item:deleted`
+    ItemDeleted(Task),
+    /// `item:completed`
+    ItemCompleted(Task),
+    /// `item:uncompleted`
+    ItemUncompleted(Task),
+    /// `note:added`
+    NoteAdded(Comment),
+    /// `note:updated`
+    NoteUpdated(Comment),
+    /// `note:deleted`
+    NoteDeleted(Comment),
+    /// `project:added`
+    ProjectAdded(Project),
+    /// `project:updated`
+    ProjectUpdated(Project),
+    /// `project:deleted`
+    ProjectDeleted(Project),
+    /// `project:archived`
+    ProjectArchived(Project),
+    /// `project:unarchived`
+    ProjectUnarchived(Project),
+    /// `section:added`
+    SectionAdded(Section),
+    /// `section:updated`
+    SectionUpdated(Section),
+    /// `section:deleted`
+    SectionDeleted(Section),
+    /// `section:archived`
+    SectionArchived(Section),
+    /// `section:unarchived`
+    SectionUnarchived(Section),
+    /// `label:added`
+    LabelAdded(Label),
+    /// `label:updated`
+    LabelUpdated(Label),
+    /// `label:deleted`
+    LabelDeleted(Label),
+    /// Any other event
+    Other { event_name: String, event_data: Value },
+}
+
+impl Event {
+    /// Build an event from its name and raw `event_data`
+    pub fn from_parts(event_name: String, event_data: Value) -> Result<Self, serde_json::Error> {
+        let event = match event_name.as_str() {
+            "item:added" => Event::ItemAdded(serde_json::from_value(event_data)?),
+            "item:updated" => Event::ItemUpdated(serde_json::from_value(event_data)?),
+            "item:deleted" => Event::ItemDeleted(serde_json::from_value(event_data)?),
+            "item:completed" => Event::ItemCompleted(serde_json::from_value(event_data)?),
+            "item:uncompleted" => Event::ItemUncompleted(serde_json::from_value(event_data)?),
+            "note:added" => Event::NoteAdded(serde_json::from_value(event_data)?),
+            "note:updated" => Event::NoteUpdated(serde_json::from_value(event_data)?),
+            "note:deleted" => Event::NoteDeleted(serde_json::from_value(event_data)?),
+            "project:added" => Event::ProjectAdded(serde_json::from_value(event_data)?),
+            "project:updated" => Event::ProjectUpdated(serde_json::from_value(event_data)?),
+            "project:deleted" => Event::ProjectDeleted(serde_json::from_value(event_data)?),
+            "project:archived" => Event::ProjectArchived(serde_json::from_value(event_data)?),
+            "project:unarchived" => Event::ProjectUnarchived(serde_json::from_value(event_data)?),
+            "section:added" => Event::SectionAdded(serde_json::from_value(event_data)?),
+            "section:updated" => Event::SectionUpdated(serde_json::from_value(event_data)?),
+            "section:deleted" => Event::SectionDeleted(serde_json::from_value(event_data)?),
+            "section:archived" => Event::SectionArchived(serde_json::from_value(event_data)?),
+            "section:unarchived" => Event::SectionUnarchived(serde_json::from_value(event_data)?),
+            "label:added" => Event::LabelAdded(serde_json::from_value(event_data)?),
+            "label:updated" => Event::LabelUpdated(serde_json::from_value(event_data)?),
+            "label:deleted" => Event::LabelDeleted(serde_json::from_value(event_data)?),
+            _ => Event::Other { event_name, event_data },
+        };
+        Ok(event)
+    }
+
+    /// The webhook event name, e.g. `item:added`
+    pub fn name(&self) -> &str {
+        match self {
+            Event::ItemAdded(_) => "item:added",
+            Event::ItemUpdated(_) => "item:updated",
+            Event::ItemDeleted(_) => "item:deleted",
+            Event::ItemCompleted(_) => "item:completed",
+            Event::ItemUncompleted(_) => "item:uncompleted",
+            Event::NoteAdded(_) => "note:added",
+            Event::NoteUpdated(_) => "note:updated",
+            Event::NoteDeleted(_) => "note:deleted",
+            Event::ProjectAdded(_) => "project:added",
+            Event::ProjectUpdated(_) => "project:updated",
+            Event::ProjectDeleted(_) => "project:deleted",
+            Event::ProjectArchived(_) => "project:archived",
+            Event::ProjectUnarchived(_) => "project:unarchived",
+            Event::SectionAdded(_) => "section:added",
+            Event::SectionUpdated(_) => "section:updated",
+            Event::SectionDeleted(_) => "section:deleted",
+            Event::SectionArchived(_) => "section:archived",
+            Event::SectionUnarchived(_) => "section:unarchived",
+            Event::LabelAdded(_) => "label:added",
+            Event::LabelUpdated(_) => "label:updated",
+            Event::LabelDeleted(_) => "label:deleted",
+            Event::Other { event_name, .. } => event_name,
+        }
+    }
+}
@@ -1,130 +1,911 @@
 use reqwest::Client;
 use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 use crate::models::*;
 
 const TODOIST_API_BASE: &str = "https://api.todoist.com/api/v1";
 
+/// Request timeout used unless another one is configured
+const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// `User-Agent` sent unless another one is configured
+pub(crate) const DEFAULT_USER_AGENT: &str = concat!("todoist-api-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Largest page size accepted by the API v1 list endpoints
+const MAX_PAGE_SIZE: i32 = 200;
+
+/// Number of IDs `get_tasks_by_ids` sends per request, keeping URLs well under server limits
+const DEFAULT_IDS_PER_REQUEST: usize = 100;
+
+/// Number of requests `create_tasks` keeps in flight at once
+const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// Largest number of commands the Sync API accepts in a single request
+const MAX_SYNC_COMMANDS: usize = 100;
+
+/// Upper bound on pages followed when draining a paginated endpoint
+/// Guards against looping forever on a misbehaving cursor
+const MAX_PAGES: usize = 500;
+
+/// A query string parameter, borrowing its value from the arguments where possible
+type QueryParam<'a> = (&'static str, Cow<'a, str>);
+
+/// Response header identifying a request in Todoist's logs
+const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Response headers describing the current rate-limit quota
+const RATE_LIMIT_LIMIT_HEADER: &str = "X-RateLimit-Limit";
+const RATE_LIMIT_REMAINING_HEADER: &str = "X-RateLimit-Remaining";
+const RATE_LIMIT_RESET_HEADER: &str = "X-RateLimit-Reset";
+
+/// Most responses kept by the ETag cache; the least recently used one is dropped beyond this
+const MAX_ETAG_CACHE_ENTRIES: usize = 128;
+
+/// ETag cache shared between clones of a client
+type SharedEtagCache = std::sync::Arc<std::sync::Mutex<EtagCache>>;
+
+/// Bodies of GET responses that carried an `ETag`, keyed by request URL
+#[derive(Debug, Default)]
+struct EtagCache {
+    entries: HashMap<String, CachedResponse>,
+    /// Incremented on every use, to find the least recently used entry
+    clock: u64,
+}
+
+/// A GET response body kept to answer `304 Not Modified`
+#[derive(Debug)]
+struct CachedResponse {
+    etag: String,
+    body: std::sync::Arc<[u8]>,
+    last_used: u64,
+}
+
+/// The cache entry of a request, held for the whole request so a `304` can always be decoded
+struct Revalidation {
+    key: String,
+    /// Body whose `ETag` was sent as `If-None-Match`, if any
+    cached_body: Option<std::sync::Arc<[u8]>>,
+}
+
+impl EtagCache {
+    /// Get the `ETag` and body cached for `key`, marking the entry as used
+    fn get(&mut self, key: &str) -> Option<(String, std::sync::Arc<[u8]>)> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = self.clock;
+        Some((entry.etag.clone(), entry.body.clone()))
+    }
+
+    /// Cache a body, dropping the least recently used entry when the cache is full
+    fn insert(&mut self, key: String, etag: String, body: std::sync::Arc<[u8]>) {
+        self.clock += 1;
+        if !self.entries.contains_key(&key) && self.entries.len() >= MAX_ETAG_CACHE_ENTRIES {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        let last_used = self.clock;
+        self.entries.insert(key, CachedResponse { etag, body, last_used });
+    }
+}
+
 /// A comprehensive wrapper around the Todoist Unified API v1
 #[derive(Clone)]
 pub struct TodoistWrapper {
     client: Client,
     api_token: String,
     base_url: String,
+    retry_config: Option<RetryConfig>,
+    /// Timeout applied to each request, for clients built around a caller-provided
+    /// `reqwest::Client` or derived with [`TodoistWrapper::with_timeout`]
+    request_timeout: Option<std::time::Duration>,
+    /// `User-Agent` applied to each request, for clients built around a caller-provided `reqwest::Client`
+    request_user_agent: Option<String>,
+    /// Rate-limit quota from the most recent response that reported one, shared between clones
+    rate_limit_status: std::sync::Arc<std::sync::Mutex<Option<RateLimitStatus>>>,
+    /// Hook called around each request attempt, see [`TodoistWrapper::with_observer`]
+    observer: Option<SharedObserver>,
+    /// Values filled in on created tasks, see [`TodoistWrapper::with_defaults`]
+    task_defaults: TaskDefaults,
+    /// Cached GET responses revalidated with `If-None-Match`, see [`TodoistWrapper::with_etag_cache`]
+    etag_cache: Option<SharedEtagCache>,
+    /// Extra headers sent with each request, see [`TodoistWrapper::with_default_headers`]
+    default_headers: reqwest::header::HeaderMap,
+    /// Backend answering requests in place of the HTTP client, see [`TodoistWrapper::with_mock`]
+    #[cfg(feature = "mock")]
+    pub(crate) mock: Option<crate::mock::MockBackend>,
+}
+
+/// Builder for [`TodoistWrapper`], created with [`TodoistWrapper::builder`]
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use todoist_api::TodoistWrapper;
+///
+/// let todoist = TodoistWrapper::builder("your-api-token")
+///     .timeout(Duration::from_secs(30))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct TodoistWrapperBuilder {
+    api_token: String,
+    base_url: Option<String>,
+    client: Option<Client>,
+    timeout: Option<std::time::Duration>,
+    user_agent: Option<String>,
+    retry_config: Option<RetryConfig>,
+    observer: Option<SharedObserver>,
+    task_defaults: TaskDefaults,
+    etag_cache: bool,
+    default_headers: reqwest::header::HeaderMap,
+}
+
+impl TodoistWrapperBuilder {
+    /// Set the timeout for each request (defaults to 10 seconds)
+    ///
+    /// When combined with [`Self::client`], the timeout is applied to every
+    /// request instead, overriding any timeout configured on that client.
+    #[must_use]
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the `User-Agent` identifying the application (defaults to `todoist-api-rs/{version}`)
+    ///
+    /// When combined with [`Self::client`], the header is set on every request instead,
+    /// overriding any user agent configured on that client.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Send requests to another base URL, as with [`TodoistWrapper::with_base_url`]
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Use a pre-configured `reqwest::Client`, as with [`TodoistWrapper::with_client`]
+    #[must_use]
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Enable automatic retries of failed requests, as with [`TodoistWrapper::with_retry`]
+    #[must_use]
+    pub fn retry(mut self, config: RetryConfig) -> Self {
+        self.retry_config = Some(config);
+        self
+    }
+
+    /// Report every request attempt to `observer`, as with [`TodoistWrapper::with_observer`]
+    #[must_use]
+    pub fn observer(mut self, observer: impl RequestObserver + 'static) -> Self {
+        self.observer = Some(SharedObserver(std::sync::Arc::new(observer)));
+        self
+    }
+
+    /// Fill in unset fields of created tasks, as with [`TodoistWrapper::with_defaults`]
+    #[must_use]
+    pub fn task_defaults(mut self, defaults: TaskDefaults) -> Self {
+        self.task_defaults = defaults;
+        self
+    }
+
+    /// Cache GET responses and revalidate them with their `ETag`, as with [`TodoistWrapper::with_etag_cache`]
+    #[must_use]
+    pub fn etag_cache(mut self) -> Self {
+        self.etag_cache = true;
+        self
+    }
+
+    /// Send extra headers with every request, as with [`TodoistWrapper::with_default_headers`]
+    #[must_use]
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers = without_reserved_headers(headers);
+        self
+    }
+
+    /// Build the client
+    ///
+    /// Never fails: if the HTTP client cannot be configured, a default `reqwest::Client`
+    /// is used instead. Use [`Self::try_build`] to surface that error and to reject an
+    /// empty token.
+    #[must_use]
+    pub fn build(self) -> TodoistWrapper {
+        let client = match &self.client {
+            Some(client) => client.clone(),
+            None => self.build_client().unwrap_or_else(|_| Client::new()),
+        };
+        self.finish(client)
+    }
+
+    /// Build the client, checking the token and the HTTP client configuration
+    ///
+    /// Returns a `ValidationError` for an empty or whitespace-only token, and a
+    /// `Generic` error if the HTTP client cannot be built (e.g. TLS initialization
+    /// fails). The token is not checked against the API; see
+    /// [`TodoistWrapper::validate_token`] for that.
+    pub fn try_build(self) -> TodoistResult<TodoistWrapper> {
+        if self.api_token.trim().is_empty() {
+            return Err(validation_error(Some("api_token"), "API token is empty"));
+        }
+        let client = match &self.client {
+            Some(client) => client.clone(),
+            None => self
+                .build_client()
+                .map_err(|e| generic_error(None, format!("Failed to build HTTP client: {}", e)))?,
+        };
+        Ok(self.finish(client))
+    }
+
+    /// Build the default HTTP client from the configured timeout and user agent
+    fn build_client(&self) -> reqwest::Result<Client> {
+        Client::builder()
+            .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
+            .user_agent(self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))
+            .build()
+    }
+
+    fn finish(self, client: Client) -> TodoistWrapper {
+        // A caller-provided client is used as-is, so the timeout and user agent
+        // are applied to each request instead
+        let (request_timeout, request_user_agent) = match self.client {
+            Some(_) => (self.timeout, self.user_agent),
+            None => (None, None),
+        };
+
+        TodoistWrapper {
+            client,
+            api_token: self.api_token,
+            base_url: self.base_url.unwrap_or_else(|| TODOIST_API_BASE.to_string()),
+            retry_config: self.retry_config,
+            request_timeout,
+            request_user_agent,
+            rate_limit_status: Default::default(),
+            observer: self.observer,
+            task_defaults: self.task_defaults,
+            etag_cache: self.etag_cache.then(SharedEtagCache::default),
+            default_headers: self.default_headers,
+            #[cfg(feature = "mock")]
+            mock: None,
+        }
+    }
+}
+
+/// Retry policy for rate-limited and transiently failing requests
+///
+/// Rate-limited (HTTP 429) requests are always retried. Network errors and 5xx
+/// responses are only retried when repeating the request is safe: GET and DELETE
+/// requests, and POSTs carrying an `X-Request-Id` idempotency key (every REST
+/// write does). Sync API POSTs have no such key and are never retried on these
+/// errors, so a command that may already have been applied is not sent twice.
+///
+/// When the response carries a `Retry-After` header the client waits exactly that
+/// long; otherwise it backs off exponentially from `base_delay` with random jitter.
+/// A `Retry-After` longer than `max_delay` is not waited out: the `RateLimited`
+/// error is returned straight away, as it is once `max_retries` is exhausted.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of retries after the initial attempt
+    pub max_retries: u32,
+    /// Delay before the first retry when no `Retry-After` header is present
+    pub base_delay: std::time::Duration,
+    /// Upper bound for a single wait between attempts
+    pub max_delay: std::time::Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Compute the wait before the next attempt, or `None` if the request should not be retried
+    fn delay_for(&self, attempt: u32, retry_after: Option<u64>) -> Option<std::time::Duration> {
+        if let Some(seconds) = retry_after {
+            let delay = std::time::Duration::from_secs(seconds);
+            return (delay <= self.max_delay).then_some(delay);
+        }
+
+        let exponential = self.base_delay.saturating_mul(2u32.saturating_pow(attempt));
+        let capped = exponential.min(self.max_delay);
+        // Equal jitter: wait between half and all of the backoff window
+        let jitter = capped.mul_f64(random_fraction() / 2.0);
+        Some(capped / 2 + jitter)
+    }
+}
+
+/// Hook called around every HTTP request, e.g. to feed metrics
+///
+/// Both methods do nothing by default, so implementations only override what
+/// they need. They are called once per attempt, so a retried request is reported
+/// several times, and must return quickly since they run on the request path.
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use todoist_api::{RequestObserver, ResponseInfo, TodoistWrapper};
+///
+/// #[derive(Default)]
+/// struct ErrorCounter(AtomicUsize);
+///
+/// impl RequestObserver for ErrorCounter {
+///     fn on_response(&self, response: &ResponseInfo<'_>) {
+///         if let Some(error) = response.error {
+///             println!("{} {} failed: {}", response.method, response.endpoint, error.variant_name());
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let todoist = TodoistWrapper::builder("your-api-token")
+///     .observer(ErrorCounter::default())
+///     .build();
+/// ```
+pub trait RequestObserver: Send + Sync {
+    /// Called before a request attempt is sent
+    fn on_request(&self, _request: &RequestInfo<'_>) {}
+
+    /// Called once a request attempt has completed, successfully or not
+    fn on_response(&self, _response: &ResponseInfo<'_>) {}
+}
+
+/// Lets callers keep a handle on an observer they hand to the client, e.g. to read its counters
+impl<T: RequestObserver + ?Sized> RequestObserver for std::sync::Arc<T> {
+    fn on_request(&self, request: &RequestInfo<'_>) {
+        (**self).on_request(request);
+    }
+
+    fn on_response(&self, response: &ResponseInfo<'_>) {
+        (**self).on_response(response);
+    }
+}
+
+/// A request attempt about to be sent, passed to [`RequestObserver::on_request`]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct RequestInfo<'a> {
+    /// HTTP method, e.g. `GET`
+    pub method: &'a str,
+    /// Endpoint path, e.g. `/tasks/123`; IDs are included, so group by route before
+    /// using it as a metrics label
+    pub endpoint: &'a str,
+    /// Zero for the first attempt, incremented on each retry
+    pub attempt: u32,
+}
+
+/// A completed request attempt, passed to [`RequestObserver::on_response`]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct ResponseInfo<'a> {
+    /// HTTP method, e.g. `GET`
+    pub method: &'a str,
+    /// Endpoint path, as in [`RequestInfo::endpoint`]
+    pub endpoint: &'a str,
+    /// Zero for the first attempt, incremented on each retry
+    pub attempt: u32,
+    /// HTTP status, or `None` when no response was received
+    pub status: Option<u16>,
+    /// Time from sending the request to decoding its response
+    pub elapsed: std::time::Duration,
+    /// The error the attempt produced, if any
+    pub error: Option<&'a TodoistError>,
+}
+
+/// A shared [`RequestObserver`], so that clients and builders stay `Clone` and `Debug`
+#[derive(Clone)]
+struct SharedObserver(std::sync::Arc<dyn RequestObserver>);
+
+impl std::fmt::Debug for SharedObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RequestObserver")
+    }
+}
+
+/// Hand-driven pagination over a list endpoint, one page per [`Self::next_page`] call
+///
+/// Unlike the `futures` streams, the caller decides when to fetch the next page,
+/// and [`Self::cursor`] can be stored to resume from the same place later by
+/// passing it back as the `cursor` of the filter arguments.
+pub struct PageIterator<'a, T> {
+    todoist: &'a TodoistWrapper,
+    endpoint: &'static str,
+    query_params: Vec<QueryParam<'static>>,
+    cursor: Option<String>,
+    done: bool,
+    _items: std::marker::PhantomData<fn() -> T>,
+}
+
+/// Pages of tasks, from [`TodoistWrapper::task_pages`]
+pub type TaskPageIterator<'a> = PageIterator<'a, Task>;
+/// Pages of projects, from [`TodoistWrapper::project_pages`]
+pub type ProjectPageIterator<'a> = PageIterator<'a, Project>;
+/// Pages of labels, from [`TodoistWrapper::label_pages`]
+pub type LabelPageIterator<'a> = PageIterator<'a, Label>;
+/// Pages of comments, from [`TodoistWrapper::comment_pages`]
+pub type CommentPageIterator<'a> = PageIterator<'a, Comment>;
+
+impl<'a, T> PageIterator<'a, T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn new(
+        todoist: &'a TodoistWrapper,
+        endpoint: &'static str,
+        mut query_params: Vec<QueryParam<'static>>,
+        limit: Option<i32>,
+        cursor: Option<String>,
+    ) -> Self {
+        query_params.push(("limit", limit.unwrap_or(MAX_PAGE_SIZE).to_string().into()));
+        PageIterator {
+            todoist,
+            endpoint,
+            query_params,
+            cursor,
+            done: false,
+            _items: std::marker::PhantomData,
+        }
+    }
+
+    /// Fetch the next page, or `None` once the last page has been returned
+    ///
+    /// On error the cursor is left unchanged, so calling again retries the same page.
+    pub async fn next_page(&mut self) -> TodoistResult<Option<Vec<T>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let mut params = self.query_params.clone();
+        if let Some(c) = &self.cursor {
+            params.push(("cursor", c.clone().into()));
+        }
+        let page: PaginatedResponse<T> = self.todoist.make_get_request_paginated(self.endpoint, &params).await?;
+
+        self.cursor = next_page_cursor(self.endpoint, self.cursor.as_ref(), page.next_cursor)?;
+        self.done = self.cursor.is_none();
+        Ok(Some(page.results))
+    }
+
+    /// Cursor of the next page to fetch
+    ///
+    /// `None` before the first page when no starting cursor was given, and once
+    /// the last page has been returned.
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    /// Whether the last page has been returned
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
 }
 
 impl TodoistWrapper {
     /// Create a new Todoist client
     #[must_use]
     pub fn new(api_token: String) -> Self {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()
-            .unwrap_or_else(|_| Client::new());
-        Self {
-            client,
-            api_token,
-            base_url: TODOIST_API_BASE.to_string(),
+        Self::builder(api_token).build()
+    }
+
+    /// Create a new Todoist client, rejecting an empty token up front
+    ///
+    /// Unlike [`Self::new`], returns a `ValidationError` for an empty or
+    /// whitespace-only token and a `Generic` error if the HTTP client cannot be
+    /// built; see [`TodoistWrapperBuilder::try_build`].
+    pub fn try_new(api_token: String) -> TodoistResult<Self> {
+        Self::builder(api_token).try_build()
+    }
+
+    /// Start configuring a client (timeout, base URL, HTTP client, retries)
+    #[must_use]
+    pub fn builder(api_token: impl Into<String>) -> TodoistWrapperBuilder {
+        TodoistWrapperBuilder {
+            api_token: api_token.into(),
+            base_url: None,
+            client: None,
+            timeout: None,
+            user_agent: None,
+            retry_config: None,
+            observer: None,
+            task_defaults: TaskDefaults::default(),
+            etag_cache: false,
+            default_headers: reqwest::header::HeaderMap::new(),
         }
     }
 
-    /// Create a new Todoist client with custom base URL (for testing)
-    #[doc(hidden)]
+    /// Create a new Todoist client with a custom base URL
+    ///
+    /// Every request is sent to `{base_url}/{endpoint}`, which makes it possible to
+    /// point the client at a local mock server (e.g. wiremock) or a proxy. The base
+    /// URL should include the API prefix, as in the default `https://api.todoist.com/api/v1`.
     #[must_use]
     pub fn with_base_url(api_token: String, base_url: String) -> Self {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()
-            .unwrap_or_else(|_| Client::new());
         Self {
-            client,
-            api_token,
             base_url,
+            ..Self::new(api_token)
+        }
+    }
+
+    /// Create a new Todoist client on top of a pre-configured `reqwest::Client`
+    ///
+    /// Use this to share a client across an application or to configure proxies,
+    /// connection pooling, TLS roots or timeouts. The client is used as-is, so the
+    /// default 10-second timeout only applies if the given client sets one.
+    #[must_use]
+    pub fn with_client(client: Client, api_token: String) -> Self {
+        Self::builder(api_token).client(client).build()
+    }
+
+    /// Enable automatic retries of rate-limited and transiently failing requests
+    ///
+    /// Applies to every request made by this client; see [`RetryConfig`] for which
+    /// errors are retried. A request that still fails after `max_retries` retries
+    /// returns the last error.
+    #[must_use]
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry_config = Some(config);
+        self
+    }
+
+    /// Report every request attempt to `observer`, e.g. to count requests and errors
+    /// per endpoint or record latencies; see [`RequestObserver`]
+    ///
+    /// Replaces any observer set before. Clones made afterwards share the observer.
+    #[must_use]
+    pub fn with_observer(mut self, observer: impl RequestObserver + 'static) -> Self {
+        self.observer = Some(SharedObserver(std::sync::Arc::new(observer)));
+        self
+    }
+
+    /// Fill in the priority, labels and project of created tasks when their arguments leave them unset
+    ///
+    /// Applies to [`Self::create_task`], [`Self::create_tasks`] and the helpers built on
+    /// them; explicit arguments always win. [`Self::quick_add_task`] cannot send these
+    /// fields, so it updates the new task afterwards instead. Replaces any defaults set before.
+    #[must_use]
+    pub fn with_defaults(mut self, defaults: TaskDefaults) -> Self {
+        self.task_defaults = defaults;
+        self
+    }
+
+    /// Cache GET responses that carry an `ETag` and revalidate them on the next identical request
+    ///
+    /// The cached `ETag` is sent as `If-None-Match`; when the API answers `304 Not Modified`,
+    /// the cached body is decoded instead, so pollers of e.g. [`Self::get_projects`] skip
+    /// re-downloading unchanged data. Responses without an `ETag` are not cached, so this
+    /// changes nothing for endpoints that do not send one. Pages requested with a cursor
+    /// and attachment downloads are never cached, and only the 128 most recently used
+    /// responses are kept. Clones made afterwards share the cache; see
+    /// [`Self::clear_etag_cache`] to empty it.
+    #[must_use]
+    pub fn with_etag_cache(mut self) -> Self {
+        self.etag_cache = Some(SharedEtagCache::default());
+        self
+    }
+
+    /// Send extra headers with every request, e.g. a gateway's authentication or tracing headers
+    ///
+    /// Headers the crate controls are dropped from `headers`: `Authorization`, `Content-Type`,
+    /// `User-Agent` (set it with [`TodoistWrapperBuilder::user_agent`] instead), `If-None-Match`
+    /// and `X-Request-Id`, whose presence decides whether a request is safe to retry.
+    /// Replaces any default headers set before.
+    ///
+    /// ```no_run
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    /// use todoist_api::TodoistWrapper;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("X-Internal-Auth", HeaderValue::from_static("gateway-secret"));
+    /// let todoist = TodoistWrapper::new("your-api-token".to_string()).with_default_headers(headers);
+    /// ```
+    #[must_use]
+    pub fn with_default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers = without_reserved_headers(headers);
+        self
+    }
+
+    /// Drop every response kept by [`Self::with_etag_cache`]
+    pub fn clear_etag_cache(&self) {
+        if let Some(cache) = &self.etag_cache {
+            cache.lock().unwrap_or_else(|e| e.into_inner()).entries.clear();
+        }
+    }
+
+    /// Get a copy of this client whose requests time out after `timeout`
+    ///
+    /// Useful to give a single slow call more time without building a second client:
+    /// the copy shares the connection pool and rate-limit status, and overrides the
+    /// timeout of the underlying `reqwest::Client` for each of its requests.
+    ///
+    /// ```no_run
+    /// # async fn example(todoist: todoist_api::TodoistWrapper) -> todoist_api::TodoistResult<()> {
+    /// use std::time::Duration;
+    /// use todoist_api::CompletedTasksFilterArgs;
+    ///
+    /// let args = CompletedTasksFilterArgs {
+    ///     since: Some("2024-01-01T00:00:00Z".to_string()),
+    ///     until: Some("2024-01-31T23:59:59Z".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let completed = todoist
+    ///     .with_timeout(Duration::from_secs(60))
+    ///     .get_completed_tasks(&args)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        Self {
+            request_timeout: Some(timeout),
+            ..self.clone()
         }
     }
 
+    /// Get the rate-limit quota reported by the most recent response that carried one
+    ///
+    /// Read from the `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `X-RateLimit-Reset`
+    /// headers, so callers can slow down before being rate limited. `None` until a
+    /// response includes any of them. Clones of this client share the status.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit_status.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Helper method for making GET requests
     async fn make_get_request<T>(&self, endpoint: &str) -> TodoistResult<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        self.make_get_request_with_params(endpoint, &[] as &[(&str, String)])
-            .await
+        self.make_get_request_with_params(endpoint, &[]).await
     }
 
     /// Helper method for making GET requests with query parameters
-    async fn make_get_request_with_params<T>(&self, endpoint: &str, query_params: &[(&str, String)]) -> TodoistResult<T>
+    async fn make_get_request_with_params<T>(&self, endpoint: &str, query_params: &[QueryParam<'_>]) -> TodoistResult<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!(
-            "{}/{}",
-            self.base_url.trim_end_matches('/'),
-            endpoint.trim_start_matches('/')
-        );
-
-        let response = self
-            .client
-            .get(&url)
-            .query(query_params)
-            .bearer_auth(&self.api_token)
-            .send()
+        let request = self.client.get(self.endpoint_url(endpoint)).query(query_params);
+        // Cursor pages are only walked once, so caching them would just hold memory
+        let cacheable = !query_params.iter().any(|(name, _)| *name == "cursor");
+        self.send_request_with_cache("GET", endpoint, request, cacheable, decode_json)
             .await
-            .map_err(|e| TodoistError::NetworkError {
-                message: format!("Failed to send request: {}", e),
-            })?;
-
-        self.handle_response("GET", endpoint, response).await
     }
 
     /// Helper method for making POST requests
+    /// Each call gets a fresh `X-Request-Id`, reused if the request is retried
     async fn make_post_request<T>(&self, endpoint: &str, body: Option<&Value>) -> TodoistResult<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!(
-            "{}/{}",
-            self.base_url.trim_end_matches('/'),
-            endpoint.trim_start_matches('/')
-        );
-        let mut request = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.api_token)
-            .header("Content-Type", "application/json");
+        let request_id = uuid::Uuid::new_v4().to_string();
+        self.make_post_request_with_id(endpoint, body, &request_id).await
+    }
 
-        if let Some(body_value) = body {
-            request = request.json(body_value);
-        }
+    /// Helper method for making POST requests with a given `X-Request-Id`
+    /// The API ignores a request whose ID it has already processed, which makes retries safe
+    async fn make_post_request_with_id<T>(
+        &self,
+        endpoint: &str,
+        body: Option<&Value>,
+        request_id: &str,
+    ) -> TodoistResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let request = self.post_request(endpoint, body, request_id);
+        self.send_request("POST", endpoint, request, decode_json).await
+    }
+
+    /// Helper method for making POST requests whose response body is not used
+    /// Succeeds on any 2xx status, including `204 No Content`
+    async fn make_post_request_no_content(&self, endpoint: &str, body: Option<&Value>) -> TodoistResult<()> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let request = self.post_request(endpoint, body, &request_id);
+        self.send_request("POST", endpoint, request, decode_no_content).await
+    }
 
-        let response = request.send().await.map_err(|e| TodoistError::NetworkError {
-            message: format!("Failed to send request: {}", e),
-        })?;
+    /// Build a JSON POST request carrying the given `X-Request-Id`
+    fn post_request(&self, endpoint: &str, body: Option<&Value>, request_id: &str) -> reqwest::RequestBuilder {
+        let request = self
+            .client
+            .post(self.endpoint_url(endpoint))
+            .header("Content-Type", "application/json")
+            .header(REQUEST_ID_HEADER, request_id);
 
-        self.handle_response("POST", endpoint, response).await
+        match body {
+            Some(body_value) => request.json(body_value),
+            None => request,
+        }
     }
 
     /// Helper method for making DELETE requests
-    async fn make_delete_request<T>(&self, endpoint: &str) -> TodoistResult<T>
+    /// The API answers deletions with `204 No Content`, so no body is read
+    async fn make_delete_request(&self, endpoint: &str) -> TodoistResult<()> {
+        let request = self.client.delete(self.endpoint_url(endpoint));
+        self.send_request("DELETE", endpoint, request, decode_no_content).await
+    }
+
+    /// Helper method for making form-encoded POST requests to the Sync API endpoint
+    async fn make_sync_request<T>(&self, form: &[(&str, String)]) -> TodoistResult<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!(
+        let request = self.client.post(self.endpoint_url("/sync")).form(form);
+        self.send_request("POST", "/sync", request, decode_json).await
+    }
+
+    /// Build the full URL for an API endpoint
+    fn endpoint_url(&self, endpoint: &str) -> String {
+        format!(
             "{}/{}",
             self.base_url.trim_end_matches('/'),
             endpoint.trim_start_matches('/')
-        );
-        let response = self
-            .client
-            .delete(&url)
-            .bearer_auth(&self.api_token)
-            .send()
+        )
+    }
+
+    /// Send a single request over HTTP, or to the mock backend when one is set
+    async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(mock.respond(&request));
+        }
+        self.client.execute(request).await
+    }
+
+    /// Helper method for sending a request and handling its response
+    /// Failed attempts are retried according to the configured retry policy, and
+    /// the body of a successful response is turned into the result by `decode`
+    async fn send_request<T>(
+        &self,
+        http_method: &str,
+        endpoint: &str,
+        request: reqwest::RequestBuilder,
+        decode: Decode<T>,
+    ) -> TodoistResult<T> {
+        self.send_request_with_cache(http_method, endpoint, request, false, decode)
             .await
-            .map_err(|e| TodoistError::NetworkError {
-                message: format!("Failed to send request: {}", e),
+    }
+
+    /// Helper method for sending a request, revalidating it against the ETag cache
+    /// when `cacheable` is set and the cache is enabled
+    async fn send_request_with_cache<T>(
+        &self,
+        http_method: &str,
+        endpoint: &str,
+        request: reqwest::RequestBuilder,
+        cacheable: bool,
+        decode: Decode<T>,
+    ) -> TodoistResult<T> {
+        let attempts = self.send_request_attempts(http_method, endpoint, request, cacheable, decode);
+        #[cfg(feature = "tracing")]
+        let attempts = tracing::Instrument::instrument(
+            attempts,
+            tracing::debug_span!("todoist_request", method = http_method, endpoint),
+        );
+        attempts.await
+    }
+
+    async fn send_request_attempts<T>(
+        &self,
+        http_method: &str,
+        endpoint: &str,
+        request: reqwest::RequestBuilder,
+        cacheable: bool,
+        decode: Decode<T>,
+    ) -> TodoistResult<T> {
+        let mut request = request.bearer_auth(&self.api_token);
+        if let Some(timeout) = self.request_timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.request_user_agent {
+            request = request.header(reqwest::header::USER_AGENT, user_agent);
+        }
+        let mut request = request
+            .build()
+            .map_err(|e| network_error("Failed to send request", &e))?;
+        let revalidation = if cacheable {
+            self.revalidation(&mut request)
+        } else {
+            None
+        };
+        let idempotent = is_idempotent(&request);
+        for name in self.default_headers.keys() {
+            if !request.headers().contains_key(name) {
+                for value in self.default_headers.get_all(name) {
+                    request.headers_mut().append(name, value.clone());
+                }
+            }
+        }
+        let mut attempt: u32 = 0;
+
+        loop {
+            let attempt_request = request.try_clone().ok_or_else(|| {
+                generic_error(
+                    None,
+                    format!("{} request to {} cannot be retried", http_method, endpoint),
+                )
             })?;
+            if let Some(SharedObserver(observer)) = &self.observer {
+                observer.on_request(&RequestInfo {
+                    method: http_method,
+                    endpoint,
+                    attempt,
+                });
+            }
+            let started = std::time::Instant::now();
+            let response = self.execute(attempt_request).await;
+            let status = response.as_ref().ok().map(|response| response.status().as_u16());
+            let result = match response {
+                Ok(response) => {
+                    self.handle_response(endpoint, response, revalidation.as_ref(), decode)
+                        .await
+                }
+                Err(e) => Err(network_error("Failed to send request", &e)),
+            };
+            let elapsed = started.elapsed();
+            #[cfg(feature = "tracing")]
+            trace_attempt(http_method, endpoint, status, elapsed, &result);
+            if let Some(SharedObserver(observer)) = &self.observer {
+                observer.on_response(&ResponseInfo {
+                    method: http_method,
+                    endpoint,
+                    attempt,
+                    status,
+                    elapsed,
+                    error: result.as_ref().err(),
+                });
+            }
+
+            let delay = match (&self.retry_config, &result) {
+                (Some(config), Err(TodoistError::RateLimited { retry_after, .. })) if attempt < config.max_retries => {
+                    config.delay_for(attempt, *retry_after)
+                }
+                (Some(config), Err(TodoistError::NetworkError { .. } | TodoistError::ServerError { .. }))
+                    if idempotent && attempt < config.max_retries =>
+                {
+                    config.delay_for(attempt, None)
+                }
+                _ => None,
+            };
+
+            match delay {
+                Some(delay) => {
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return result,
+            }
+        }
+    }
 
-        self.handle_response("DELETE", endpoint, response).await
+    /// Helper method for executing a single Sync API command
+    /// Used for operations that the REST endpoints do not expose
+    async fn execute_sync_command(&self, command_type: &str, args: Value) -> TodoistResult<()> {
+        let command = SyncCommand::new(command_type, args);
+        let mut result = self.sync_commands(std::slice::from_ref(&command)).await?;
+
+        result.sync_status.remove(&command.uuid).unwrap_or_else(|| {
+            Err(empty_response_error(
+                "/sync",
+                format!("No sync status returned for command {}", command_type),
+            ))
+        })
     }
 
     /// Helper method for making GET requests that return paginated responses
@@ -132,75 +913,188 @@ impl TodoistWrapper {
     async fn make_get_request_paginated<T>(
         &self,
         endpoint: &str,
-        query_params: &[(&str, String)],
+        query_params: &[QueryParam<'_>],
     ) -> TodoistResult<PaginatedResponse<T>>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!(
-            "{}/{}",
-            self.base_url.trim_end_matches('/'),
-            endpoint.trim_start_matches('/')
-        );
+        self.make_get_request_with_params(endpoint, query_params).await
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .query(query_params)
-            .bearer_auth(&self.api_token)
-            .send()
-            .await
-            .map_err(|e| TodoistError::NetworkError {
-                message: format!("Failed to send request: {}", e),
-            })?;
+    /// Helper method for draining every page of a paginated endpoint into a single Vec
+    async fn get_all_pages<T>(&self, endpoint: &str, query_params: &[QueryParam<'_>]) -> TodoistResult<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        for _ in 0..MAX_PAGES {
+            let mut params = query_params.to_vec();
+            params.push(("limit", MAX_PAGE_SIZE.to_string().into()));
+            if let Some(c) = &cursor {
+                params.push(("cursor", c.into()));
+            }
 
-        self.handle_response("GET", endpoint, response).await
+            let page: PaginatedResponse<T> = self.make_get_request_paginated(endpoint, &params).await?;
+            items.extend(page.results);
+
+            match next_page_cursor(endpoint, cursor.as_ref(), page.next_cursor)? {
+                Some(next) => cursor = Some(next),
+                None => return Ok(items),
+            }
+        }
+
+        Err(too_many_pages_error(endpoint))
     }
 
-    /// Helper method to handle HTTP responses and convert them to TodoistResult
-    async fn handle_response<T>(
+    /// Helper method returning the first item of a paginated endpoint matching `predicate`
+    ///
+    /// Pages are requested in order and pagination stops as soon as a match is found.
+    async fn find_in_pages<T>(
         &self,
-        http_method: &str,
         endpoint: &str,
-        response: reqwest::Response,
-    ) -> TodoistResult<T>
+        query_params: &[QueryParam<'_>],
+        predicate: impl Fn(&T) -> bool,
+    ) -> TodoistResult<Option<T>>
     where
         T: serde::de::DeserializeOwned,
     {
-        let status = response.status();
-        let headers = response.headers().clone();
-
-        if status.is_success() {
-            // Read response body
-            let text = response.text().await.map_err(|e| TodoistError::NetworkError {
-                message: format!("Failed to read response body: {}", e),
-            })?;
+        let mut cursor: Option<String> = None;
 
-            // For DELETE requests, empty responses are expected and valid
-            if http_method == "DELETE" && text.trim().is_empty() {
-                // Try to deserialize "null" for empty DELETE responses
-                return serde_json::from_str::<T>("null").map_err(|e| TodoistError::ParseError {
-                    message: format!("Failed to deserialize empty DELETE response: {}", e),
-                });
+        for _ in 0..MAX_PAGES {
+            let mut params = query_params.to_vec();
+            params.push(("limit", MAX_PAGE_SIZE.to_string().into()));
+            if let Some(c) = &cursor {
+                params.push(("cursor", c.into()));
             }
 
-            // For POST requests to close/reopen tasks, empty responses or 204 are expected and valid
-            if http_method == "POST" && (status.as_u16() == 204 || text.trim().is_empty()) {
-                // Try to deserialize "null" for empty POST responses
-                return serde_json::from_str::<T>("null").map_err(|e| TodoistError::ParseError {
-                    message: format!("Failed to deserialize empty POST response: {}", e),
-                });
+            let page: PaginatedResponse<T> = self.make_get_request_paginated(endpoint, &params).await?;
+            if let Some(item) = page.results.into_iter().find(|item| predicate(item)) {
+                return Ok(Some(item));
             }
 
-            // Handle empty responses for other methods
-            if text.trim().is_empty() {
-                return Err(empty_response_error(endpoint, "API returned empty response body"));
+            match next_page_cursor(endpoint, cursor.as_ref(), page.next_cursor)? {
+                Some(next) => cursor = Some(next),
+                None => return Ok(None),
             }
+        }
 
-            // Try to parse response
-            serde_json::from_str::<T>(&text).map_err(|e| TodoistError::ParseError {
-                message: format!("Failed to parse response: {}", e),
-            })
+        Err(too_many_pages_error(endpoint))
+    }
+
+    /// Helper method for lazily streaming every item of a paginated endpoint
+    ///
+    /// Pages are only requested as the stream is polled, so dropping the stream
+    /// stops pagination. The stream ends after yielding the first error.
+    #[cfg(feature = "futures")]
+    fn paginate<T>(
+        &self,
+        endpoint: &'static str,
+        query_params: Vec<QueryParam<'static>>,
+    ) -> impl futures_util::Stream<Item = TodoistResult<T>> + '_
+    where
+        T: serde::de::DeserializeOwned + 'static,
+    {
+        use futures_util::stream::{self, StreamExt};
+
+        // `None` once the last page was fetched or an error was yielded
+        let start: Option<(Option<String>, usize)> = Some((None, 0));
+
+        stream::unfold(start, move |state| {
+            let mut params = query_params.clone();
+            async move {
+                let (cursor, pages) = state?;
+                if pages >= MAX_PAGES {
+                    return Some((vec![Err(too_many_pages_error(endpoint))], None));
+                }
+
+                params.push(("limit", MAX_PAGE_SIZE.to_string().into()));
+                if let Some(c) = &cursor {
+                    params.push(("cursor", c.clone().into()));
+                }
+
+                let page: PaginatedResponse<T> = match self.make_get_request_paginated(endpoint, &params).await {
+                    Ok(page) => page,
+                    Err(e) => return Some((vec![Err(e)], None)),
+                };
+
+                let mut items: Vec<TodoistResult<T>> = page.results.into_iter().map(Ok).collect();
+                let next_state = match next_page_cursor(endpoint, cursor.as_ref(), page.next_cursor) {
+                    Ok(Some(next)) => Some((Some(next), pages + 1)),
+                    Ok(None) => None,
+                    Err(e) => {
+                        items.push(Err(e));
+                        None
+                    }
+                };
+                Some((items, next_state))
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Helper method to handle HTTP responses and convert them to TodoistResult
+    ///
+    /// Errors built from the response carry its `X-Request-Id` header, if any.
+    async fn handle_response<T>(
+        &self,
+        endpoint: &str,
+        response: reqwest::Response,
+        revalidation: Option<&Revalidation>,
+        decode: Decode<T>,
+    ) -> TodoistResult<T> {
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        if let Some(status) = parse_rate_limit_headers(response.headers()) {
+            *self.rate_limit_status.lock().unwrap_or_else(|e| e.into_inner()) = Some(status);
+        }
+
+        self.read_response(endpoint, response, revalidation, decode)
+            .await
+            .map_err(|e| e.with_request_id(request_id))
+    }
+
+    /// Read a response body and decode it, mapping HTTP errors to `TodoistError`
+    ///
+    /// With a `revalidation`, a `304 Not Modified` is answered with the cached body it
+    /// holds and a successful response replaces the cached one.
+    async fn read_response<T>(
+        &self,
+        endpoint: &str,
+        response: reqwest::Response,
+        revalidation: Option<&Revalidation>,
+        decode: Decode<T>,
+    ) -> TodoistResult<T> {
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(body) = revalidation.and_then(|r| r.cached_body.as_ref()) {
+                return decode(endpoint, body);
+            }
+        }
+
+        if status.is_success() {
+            let body = response
+                .bytes()
+                .await
+                .map_err(|e| network_error("Failed to read response body", &e))?;
+            let value = decode(endpoint, &body)?;
+            if let (Some(cache), Some(revalidation)) = (&self.etag_cache, revalidation) {
+                let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+                match headers.get(reqwest::header::ETAG).and_then(|v| v.to_str().ok()) {
+                    Some(etag) => cache.insert(revalidation.key.clone(), etag.to_string(), body.as_ref().into()),
+                    None => {
+                        cache.entries.remove(&revalidation.key);
+                    }
+                }
+            }
+            Ok(value)
         } else {
             // Handle different error status codes
             let error_text = response
@@ -208,40 +1102,168 @@ impl TodoistWrapper {
                 .await
                 .unwrap_or_else(|_| format!("Unknown error occurred (HTTP {})", status));
 
-            let error = match status.as_u16() {
-                401 => TodoistError::AuthenticationError { message: error_text },
-                403 => TodoistError::AuthorizationError { message: error_text },
-                404 => TodoistError::NotFound {
-                    resource_type: "Resource".to_string(),
-                    resource_id: None,
-                    message: error_text,
-                },
-                429 => {
-                    let retry_after = headers
-                        .get("Retry-After")
-                        .and_then(|v| v.to_str().ok())
-                        .and_then(|s| s.parse::<u64>().ok());
-                    TodoistError::RateLimited {
-                        retry_after,
-                        message: error_text,
-                    }
-                }
-                400 => TodoistError::ValidationError {
-                    field: None,
-                    message: error_text,
-                },
-                500..=599 => TodoistError::ServerError {
-                    status_code: status.as_u16(),
-                    message: error_text,
-                },
-                _ => TodoistError::Generic {
-                    status_code: Some(status.as_u16()),
-                    message: error_text,
-                },
-            };
+            let retry_after = headers
+                .get("Retry-After")
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_retry_after);
+
+            Err(error_from_status(
+                status.as_u16(),
+                ApiErrorBody::parse(error_text),
+                retry_after,
+            ))
+        }
+    }
+
+    /// Look up a GET request in the ETag cache, adding `If-None-Match` when a response is cached
+    ///
+    /// The cached body is returned with the key, so a `304` can be decoded even if the
+    /// entry is evicted or cleared while the request is in flight.
+    fn revalidation(&self, request: &mut reqwest::Request) -> Option<Revalidation> {
+        let cache = self.etag_cache.as_ref()?;
+        if request.method() != reqwest::Method::GET {
+            return None;
+        }
+        let key = request.url().to_string();
+        let cached = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key);
+        let cached_body = cached.and_then(|(etag, body)| {
+            let etag = reqwest::header::HeaderValue::from_str(&etag).ok()?;
+            request.headers_mut().insert(reqwest::header::IF_NONE_MATCH, etag);
+            Some(body)
+        });
+        Some(Revalidation { key, cached_body })
+    }
+
+    // ===== SYNC OPERATIONS =====
+
+    /// Send a batch of Sync API commands in a single request
+    ///
+    /// The commands are applied in order. An `Err` means the request itself failed;
+    /// otherwise each command's outcome is in `SyncResult::sync_status`, and the real
+    /// IDs of objects created with a `temp_id` are in `SyncResult::temp_id_mapping`.
+    /// An empty batch returns an empty result without sending a request.
+    pub async fn sync_commands(&self, commands: &[SyncCommand]) -> TodoistResult<SyncResult> {
+        if commands.is_empty() {
+            return Ok(SyncResult::default());
+        }
 
-            Err(error)
+        #[derive(serde::Deserialize)]
+        struct CommandsResponse {
+            #[serde(default)]
+            sync_status: HashMap<String, Value>,
+            #[serde(default)]
+            temp_id_mapping: HashMap<String, String>,
         }
+
+        let commands = serde_json::to_string(commands)?;
+        let response: CommandsResponse = self.make_sync_request(&[("commands", commands)]).await?;
+
+        Ok(SyncResult {
+            sync_status: response
+                .sync_status
+                .into_iter()
+                .map(|(uuid, status)| (uuid, sync_status_result(status)))
+                .collect(),
+            temp_id_mapping: response.temp_id_mapping,
+        })
+    }
+
+    /// Read resources from the Sync API, in full or as the changes since a previous sync
+    ///
+    /// Pass `"*"` as `sync_token` for a full sync, then the `sync_token` of the returned
+    /// [`SyncResponse`] to only receive what changed since, which keeps a local copy up
+    /// to date without re-fetching everything. Returns a `ValidationError` without
+    /// sending a request when `resource_types` is empty.
+    pub async fn sync(&self, sync_token: &str, resource_types: &[SyncResourceType]) -> TodoistResult<SyncResponse> {
+        if resource_types.is_empty() {
+            return Err(validation_error(
+                Some("resource_types"),
+                "No resource types specified for sync",
+            ));
+        }
+        let resource_types = serde_json::to_string(resource_types)?;
+        self.make_sync_request(&[
+            ("sync_token", sync_token.to_string()),
+            ("resource_types", resource_types),
+        ])
+        .await
+    }
+
+    // ===== USER OPERATIONS =====
+
+    /// Get the authenticated user, e.g. to check `is_premium` before using premium-only features
+    pub async fn get_user(&self) -> TodoistResult<User> {
+        self.make_get_request("/user").await
+    }
+
+    /// Check that the API is reachable and accepts this client's token
+    ///
+    /// Makes a single `GET /user` request without parsing the body. Returns
+    /// `Ok(false)` when the token is rejected (HTTP 401) and propagates any other
+    /// error, such as a network failure, so an invalid token is told apart from an
+    /// unreachable API.
+    pub async fn validate_token(&self) -> TodoistResult<bool> {
+        let request = self.client.get(self.endpoint_url("/user"));
+        match self.send_request("GET", "/user", request, decode_no_content).await {
+            Ok(()) => Ok(true),
+            Err(TodoistError::AuthenticationError { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    // ===== ID MAPPING OPERATIONS =====
+
+    /// Translate object IDs between the legacy numeric format and API v1
+    ///
+    /// This crate only speaks API v1, whose IDs differ from the numeric ones used by
+    /// REST v2 and Sync v9. Use this to migrate IDs stored by an older integration.
+    /// Either kind of ID can be passed; IDs with no match are left out of the result.
+    pub async fn get_id_mappings(&self, object: IdMappingObject, ids: &[&str]) -> TodoistResult<Vec<IdMapping>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.make_get_request(&format!("/id_mappings/{}/{}", object, ids.join(",")))
+            .await
+    }
+
+    // ===== WORKSPACE OPERATIONS =====
+
+    /// Fetch every project, section, label and active task concurrently
+    ///
+    /// All four collections are fetched in full (following pagination) at the same
+    /// time. If any request fails, the first error is returned.
+    pub async fn load_workspace(&self) -> TodoistResult<Workspace> {
+        let no_params: &[QueryParam] = &[];
+        let (projects, sections, labels, tasks) = tokio::try_join!(
+            self.get_all_pages::<Project>("/projects", no_params),
+            self.get_all_pages::<Section>("/sections", no_params),
+            self.get_all_pages::<Label>("/labels", no_params),
+            self.get_all_pages::<Task>("/tasks", no_params),
+        )?;
+
+        Ok(Workspace {
+            projects,
+            sections,
+            labels,
+            tasks_by_project: Workspace::group_tasks(tasks),
+        })
+    }
+
+    /// Fetch a project with its sections and active tasks concurrently
+    ///
+    /// Sections are sorted by `section_order` and tasks by `child_order`, each task
+    /// grouped under its section or, without one, in `unsectioned_tasks`. Sub-tasks
+    /// are listed alongside their parents; see [`build_task_tree`] to nest them.
+    pub async fn get_project_data(&self, project_id: impl AsRef<str>) -> TodoistResult<ProjectData> {
+        let project_id = project_id.as_ref();
+        let params = [("project_id", project_id.into())];
+        let (project, sections, tasks) = tokio::try_join!(
+            self.get_project(project_id),
+            self.get_all_pages::<Section>("/sections", &params),
+            self.get_all_pages::<Task>("/tasks", &params),
+        )?;
+
+        Ok(ProjectData::new(project, sections, tasks))
     }
 
     // ===== PROJECT OPERATIONS =====
@@ -254,10 +1276,10 @@ impl TodoistWrapper {
     ) -> TodoistResult<PaginatedResponse<Project>> {
         let mut query_params = Vec::new();
         if let Some(l) = limit {
-            query_params.push(("limit", l.to_string()));
+            query_params.push(("limit", l.to_string().into()));
         }
         if let Some(c) = cursor {
-            query_params.push(("cursor", c));
+            query_params.push(("cursor", c.into()));
         }
         self.make_get_request_paginated("/projects", &query_params).await
     }
@@ -267,133 +1289,930 @@ impl TodoistWrapper {
         let mut query_params = Vec::new();
 
         if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
+            query_params.push(("limit", limit.to_string().into()));
         }
         if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
+            query_params.push(("cursor", cursor.into()));
         }
 
         self.make_get_request_paginated("/projects", &query_params).await
     }
 
-    /// Get a specific project by ID
-    pub async fn get_project(&self, project_id: &str) -> TodoistResult<Project> {
-        self.make_get_request(&format!("/projects/{project_id}")).await
+    /// Get every project, following `next_cursor` across all pages
+    pub async fn get_all_projects(&self) -> TodoistResult<Vec<Project>> {
+        self.get_all_pages("/projects", &[]).await
+    }
+
+    /// Stream every project, following pagination cursors as the stream is polled
+    #[cfg(feature = "futures")]
+    pub fn get_all_projects_stream(&self) -> impl futures_util::Stream<Item = TodoistResult<Project>> + '_ {
+        self.paginate("/projects", Vec::new())
+    }
+
+    /// Get the user's Inbox project
+    ///
+    /// The API has no dedicated endpoint, so projects are listed page by page
+    /// until the one with `inbox_project` set turns up.
+    pub async fn get_inbox_project(&self) -> TodoistResult<Project> {
+        self.find_in_pages("/projects", &[], |project: &Project| project.inbox_project)
+            .await?
+            .ok_or_else(|| TodoistError::NotFound {
+                resource_type: "Project".to_string(),
+                resource_id: None,
+                message: "No Inbox project found".to_string(),
+                request_id: None,
+            })
+    }
+
+    /// Page through projects by hand, starting at `args.cursor`
+    pub fn project_pages(&self, args: &ProjectFilterArgs) -> ProjectPageIterator<'_> {
+        PageIterator::new(self, "/projects", Vec::new(), args.limit, args.cursor.clone())
+    }
+
+    /// Get a specific project by ID
+    pub async fn get_project(&self, project_id: impl AsRef<str>) -> TodoistResult<Project> {
+        let project_id = project_id.as_ref();
+        self.make_get_request(&format!("/projects/{project_id}"))
+            .await
+            .map_err(|e| e.for_resource("Project", project_id))
+    }
+
+    /// Create a new project
+    ///
+    /// When `args.child_order` is set the new project is then moved into place
+    /// with [`Self::reorder_projects`]. That move is best-effort: if it fails, the
+    /// created project is still returned, with the `child_order` the API assigned,
+    /// so compare it with `args.child_order` to detect a project left out of place.
+    pub async fn create_project(&self, args: &CreateProjectArgs) -> TodoistResult<Project> {
+        let body_value = serde_json::to_value(args)?;
+        let mut project: Project = self.make_post_request("/projects", Some(&body_value)).await?;
+        if let Some(child_order) = args.child_order {
+            if self
+                .reorder_projects(&[(project.id.clone(), child_order)])
+                .await
+                .is_ok()
+            {
+                project.child_order = child_order;
+            }
+        }
+        Ok(project)
+    }
+
+    /// Create a project with the sections and tasks described by `template`
+    ///
+    /// Everything is created one request at a time: the project, then its unsectioned
+    /// tasks, then each section followed by its tasks, parents before their sub-tasks
+    /// and siblings in template order. If one of these requests fails, the error is
+    /// returned and whatever was created so far is kept.
+    pub async fn apply_template(&self, template: &ProjectTemplate) -> TodoistResult<Project> {
+        let project = self.create_project(&template.project).await?;
+        self.create_template_tasks(&template.tasks, &project.id, None).await?;
+        for section_template in &template.sections {
+            let section = self
+                .create_section(&CreateSectionArgs {
+                    name: section_template.name.clone(),
+                    project_id: project.id.clone(),
+                    order: None,
+                })
+                .await?;
+            self.create_template_tasks(&section_template.tasks, &project.id, Some(&section.id))
+                .await?;
+        }
+        Ok(project)
+    }
+
+    /// Create template tasks and their sub-tasks at the top level of a project or section
+    async fn create_template_tasks(
+        &self,
+        tasks: &[TaskTemplate],
+        project_id: &str,
+        section_id: Option<&str>,
+    ) -> TodoistResult<()> {
+        // Templates still to create, each with the ID of its parent's copy; kept
+        // reversed so that siblings are popped, and thus appended, in order
+        let mut pending: Vec<(&TaskTemplate, Option<String>)> = tasks.iter().rev().map(|task| (task, None)).collect();
+        while let Some((template, parent_id)) = pending.pop() {
+            let mut args = template.task.clone();
+            match parent_id {
+                Some(parent_id) => {
+                    args.project_id = None;
+                    args.section_id = None;
+                    args.parent_id = Some(parent_id);
+                }
+                None => {
+                    args.project_id = Some(project_id.to_string());
+                    args.section_id = section_id.map(str::to_string);
+                    args.parent_id = None;
+                }
+            }
+            let task = self.create_task(&args).await?;
+            pending.extend(
+                template
+                    .subtasks
+                    .iter()
+                    .rev()
+                    .map(|subtask| (subtask, Some(task.id.clone()))),
+            );
+        }
+        Ok(())
+    }
+
+    /// Update an existing project
+    ///
+    /// Returns a `ValidationError` without sending a request when `args.has_updates()` is false.
+    pub async fn update_project(
+        &self,
+        project_id: impl AsRef<str>,
+        args: &UpdateProjectArgs,
+    ) -> TodoistResult<Project> {
+        let project_id = project_id.as_ref();
+        if !args.has_updates() {
+            return Err(validation_error(None, "No fields specified for update".to_string()));
+        }
+        let body_value = serde_json::to_value(args)?;
+        self.make_post_request(&format!("/projects/{project_id}"), Some(&body_value))
+            .await
+            .map_err(|e| e.for_resource("Project", project_id))
+    }
+
+    /// Collapse a project's sub-projects in the Todoist sidebar
+    ///
+    /// The REST endpoint has no collapse field, so this goes through the Sync API
+    /// `project_update` command. The state is read back as [`Project::is_collapsed`].
+    pub async fn collapse_project(&self, project_id: impl AsRef<str>) -> TodoistResult<()> {
+        let project_id = project_id.as_ref();
+        self.set_project_collapsed(project_id, true).await
+    }
+
+    /// Expand a previously collapsed project in the Todoist sidebar
+    pub async fn expand_project(&self, project_id: impl AsRef<str>) -> TodoistResult<()> {
+        let project_id = project_id.as_ref();
+        self.set_project_collapsed(project_id, false).await
+    }
+
+    /// Set the collapsed state of a project (via the Sync API `project_update` command)
+    pub async fn set_project_collapsed(&self, project_id: impl AsRef<str>, collapsed: bool) -> TodoistResult<()> {
+        let project_id = project_id.as_ref();
+        self.execute_sync_command(
+            "project_update",
+            serde_json::json!({ "id": project_id, "collapsed": collapsed }),
+        )
+        .await
+    }
+
+    /// Persist a new order for sibling projects in a single request
+    ///
+    /// Each pair is a project ID and its new `child_order`. Uses the Sync API
+    /// `project_reorder` command, since the REST API has no reorder endpoint.
+    pub async fn reorder_projects(&self, orders: &[(String, i32)]) -> TodoistResult<()> {
+        if orders.is_empty() {
+            return Ok(());
+        }
+        let projects: Vec<Value> = orders
+            .iter()
+            .map(|(id, child_order)| serde_json::json!({ "id": id, "child_order": child_order }))
+            .collect();
+        self.execute_sync_command("project_reorder", serde_json::json!({ "projects": projects }))
+            .await
+    }
+
+    /// Delete a project
+    ///
+    /// The API deletes the project's sections, tasks and sub-projects with it. Use
+    /// [`Self::delete_project_with_report`] to check that nothing was left behind.
+    pub async fn delete_project(&self, project_id: impl AsRef<str>) -> TodoistResult<()> {
+        let project_id = project_id.as_ref();
+        self.make_delete_request(&format!("/projects/{project_id}"))
+            .await
+            .map_err(|e| e.for_resource("Project", project_id))
+    }
+
+    /// Delete a project, then check that its sections and tasks went with it
+    ///
+    /// Lists the project's sections and active tasks, deletes the project, and lists
+    /// them again. Anything the server-side cascade left behind is deleted explicitly
+    /// (tasks first, then sections), and whatever still could not be removed is
+    /// reported with its error. A child that cannot be listed after the delete (e.g.
+    /// on a network error) is reported as remaining, since it is not known to be gone.
+    ///
+    /// Returns an error, with nothing deleted, if the project's contents cannot be
+    /// listed beforehand or the project delete itself fails.
+    pub async fn delete_project_with_report(&self, project_id: impl AsRef<str>) -> TodoistResult<DeletionReport> {
+        let project_id = project_id.as_ref();
+        let (sections, tasks) = self.project_children(project_id).await?;
+        self.delete_project(project_id).await?;
+
+        let mut report = DeletionReport {
+            project_id: project_id.to_string(),
+            ..Default::default()
+        };
+        let (left_sections, left_tasks) = match self.project_children(project_id).await {
+            Ok(children) => children,
+            Err(e) if e.is_not_found() => Default::default(),
+            Err(e) => {
+                report.remaining_sections = sections.into_iter().map(|id| (id, e.clone())).collect();
+                report.remaining_tasks = tasks.into_iter().map(|id| (id, e.clone())).collect();
+                return Ok(report);
+            }
+        };
+
+        let task_ids: Vec<&str> = left_tasks.iter().map(String::as_str).collect();
+        let task_results = self.delete_tasks(&task_ids).await;
+        let mut failed_tasks: HashMap<String, TodoistError> = HashMap::new();
+        for (id, result) in left_tasks.iter().zip(task_results) {
+            // A sub-task is already gone once its parent is deleted
+            if let Err(e) = ignore_not_found(result) {
+                failed_tasks.insert(id.clone(), e);
+            }
+        }
+        let mut failed_sections: HashMap<String, TodoistError> = HashMap::new();
+        for id in &left_sections {
+            if let Err(e) = ignore_not_found(self.delete_section(id).await) {
+                failed_sections.insert(id.clone(), e);
+            }
+        }
+
+        for id in sections {
+            match failed_sections.remove(&id) {
+                Some(e) => report.remaining_sections.push((id, e)),
+                None => report.deleted_sections.push(id),
+            }
+        }
+        for id in tasks {
+            match failed_tasks.remove(&id) {
+                Some(e) => report.remaining_tasks.push((id, e)),
+                None => report.deleted_tasks.push(id),
+            }
+        }
+        // Children added while the project was being deleted
+        report.remaining_sections.extend(failed_sections);
+        report.remaining_tasks.extend(failed_tasks);
+        Ok(report)
+    }
+
+    /// IDs of a project's sections and active tasks
+    async fn project_children(&self, project_id: &str) -> TodoistResult<(Vec<String>, Vec<String>)> {
+        let sections: Vec<Section> = self
+            .get_all_pages("/sections", &[("project_id", project_id.into())])
+            .await?;
+        let tasks = self.get_all_tasks_for_project(project_id).await?;
+        Ok((
+            sections.into_iter().map(|section| section.id).collect(),
+            tasks.into_iter().map(|task| task.id).collect(),
+        ))
+    }
+
+    /// Get everyone with access to a shared project, following pagination
+    ///
+    /// Use [`Comment::author`] to match comments in the project to their authors.
+    pub async fn get_collaborators(&self, project_id: impl AsRef<str>) -> TodoistResult<Vec<Collaborator>> {
+        let project_id = project_id.as_ref();
+        self.get_all_pages(&format!("/projects/{project_id}/collaborators"), &[])
+            .await
+            .map_err(|e| e.for_resource("Project", project_id))
+    }
+
+    /// Share a project with someone by email (via the Sync API `share_project` command)
+    ///
+    /// Sharing is a paid feature; refusals from the API (e.g. a plan without
+    /// sharing) come back with their `error_tag`.
+    pub async fn invite_collaborator(&self, project_id: impl AsRef<str>, email: &str) -> TodoistResult<()> {
+        let project_id = project_id.as_ref();
+        if !email.contains('@') {
+            return Err(validation_error(Some("email"), "Expected an email address"));
+        }
+        self.execute_sync_command(
+            "share_project",
+            serde_json::json!({ "project_id": project_id, "email": email }),
+        )
+        .await
+        .map_err(|e| e.for_resource("Project", project_id))
+    }
+
+    /// Remove someone from a shared project (via the Sync API `delete_collaborator` command)
+    ///
+    /// The command only accepts an email address, so a collaborator ID is first
+    /// resolved with [`Self::get_collaborators`].
+    pub async fn remove_collaborator(&self, project_id: impl AsRef<str>, email_or_id: &str) -> TodoistResult<()> {
+        let project_id = project_id.as_ref();
+        let email = if email_or_id.contains('@') {
+            email_or_id.to_string()
+        } else {
+            self.get_collaborators(project_id)
+                .await?
+                .into_iter()
+                .find(|collaborator| collaborator.id == email_or_id)
+                .map(|collaborator| collaborator.email)
+                .ok_or_else(|| {
+                    not_found_error(
+                        "Collaborator",
+                        Some(email_or_id),
+                        format!("No collaborator with this ID in project {}", project_id),
+                    )
+                })?
+        };
+        self.execute_sync_command(
+            "delete_collaborator",
+            serde_json::json!({ "project_id": project_id, "email": email }),
+        )
+        .await
+        .map_err(|e| e.for_resource("Project", project_id))
+    }
+
+    // ===== TASK OPERATIONS =====
+
+    /// Get all tasks (paginated)
+    pub async fn get_tasks(
+        &self,
+        limit: Option<i32>,
+        cursor: Option<String>,
+    ) -> TodoistResult<PaginatedResponse<Task>> {
+        let mut query_params = Vec::new();
+        if let Some(l) = limit {
+            query_params.push(("limit", l.to_string().into()));
+        }
+        if let Some(c) = cursor {
+            query_params.push(("cursor", c.into()));
+        }
+        self.make_get_request_paginated("/tasks", &query_params).await
+    }
+
+    /// Get tasks for a specific project (paginated)
+    pub async fn get_tasks_for_project(
+        &self,
+        project_id: impl AsRef<str>,
+        limit: Option<i32>,
+        cursor: Option<String>,
+    ) -> TodoistResult<PaginatedResponse<Task>> {
+        let project_id = project_id.as_ref();
+        let mut query_params = vec![("project_id", project_id.into())];
+        if let Some(l) = limit {
+            query_params.push(("limit", l.to_string().into()));
+        }
+        if let Some(c) = cursor {
+            query_params.push(("cursor", c.into()));
+        }
+        self.make_get_request_paginated("/tasks", &query_params).await
+    }
+
+    /// Get every task in a project, following `next_cursor` across all pages
+    pub async fn get_all_tasks_for_project(&self, project_id: impl AsRef<str>) -> TodoistResult<Vec<Task>> {
+        let project_id = project_id.as_ref();
+        self.get_all_pages("/tasks", &[("project_id", project_id.into())]).await
+    }
+
+    /// Stream every active task, following pagination cursors as the stream is polled
+    ///
+    /// Pages are fetched lazily, and dropping the stream early stops fetching.
+    #[cfg(feature = "futures")]
+    pub fn get_all_tasks_stream(&self) -> impl futures_util::Stream<Item = TodoistResult<Task>> + '_ {
+        self.paginate("/tasks", Vec::new())
+    }
+
+    /// Get a specific task by ID
+    pub async fn get_task(&self, task_id: impl AsRef<str>) -> TodoistResult<Task> {
+        let task_id = task_id.as_ref();
+        self.make_get_request(&format!("/tasks/{task_id}"))
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))
+    }
+
+    /// Get the active direct sub-tasks of a task, following pagination
+    ///
+    /// Use [`crate::build_task_tree`] to nest a whole task list instead.
+    pub async fn get_subtasks(&self, parent_task_id: impl AsRef<str>) -> TodoistResult<Vec<Task>> {
+        let parent_task_id = parent_task_id.as_ref();
+        self.get_all_pages("/tasks", &[("parent_id", parent_task_id.into())])
+            .await
+    }
+
+    /// Get several active tasks by ID, following pagination
+    ///
+    /// Tasks are returned in the order of `ids`, once each even if an ID is repeated.
+    /// IDs that don't match an active task (completed, deleted or unknown) are left
+    /// out of the result rather than failing the call. Long lists are split into
+    /// requests of 100 IDs to keep URLs short; an empty `ids` slice returns an empty
+    /// Vec without making a request.
+    pub async fn get_tasks_by_ids(&self, ids: &[&str]) -> TodoistResult<Vec<Task>> {
+        self.get_tasks_by_ids_with_chunk_size(ids, DEFAULT_IDS_PER_REQUEST)
+            .await
+    }
+
+    /// Get several active tasks by ID, sending at most `chunk_size` IDs per request
+    ///
+    /// See [`Self::get_tasks_by_ids`]. The chunks are requested one after another.
+    pub async fn get_tasks_by_ids_with_chunk_size(&self, ids: &[&str], chunk_size: usize) -> TodoistResult<Vec<Task>> {
+        // Position of each distinct ID in `ids`, used to put the results in order
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        let mut unique_ids = Vec::with_capacity(ids.len());
+        for &id in ids {
+            if !positions.contains_key(id) {
+                positions.insert(id, unique_ids.len());
+                unique_ids.push(id);
+            }
+        }
+
+        let mut tasks: Vec<Task> = Vec::new();
+        for chunk in unique_ids.chunks(chunk_size.max(1)) {
+            let chunk_tasks: Vec<Task> = self.get_all_pages("/tasks", &[("ids", chunk.join(",").into())]).await?;
+            tasks.extend(chunk_tasks);
+        }
+        tasks.sort_by_key(|task| positions.get(task.id.as_str()).copied().unwrap_or(usize::MAX));
+        tasks.dedup_by(|a, b| a.id == b.id);
+        Ok(tasks)
+    }
+
+    /// Get tasks by filter query (paginated)
+    ///
+    /// A non-empty `args.query` is sent to the filter endpoint. Otherwise active tasks
+    /// are listed, narrowed by the structured filters (`label`, `project_id`,
+    /// `section_id`, `ids`). Setting both returns a `ValidationError` without sending
+    /// a request, since the API cannot combine them.
+    pub async fn get_tasks_by_filter(&self, args: &TaskFilterArgs) -> TodoistResult<PaginatedResponse<Task>> {
+        let (endpoint, mut query_params) = task_filter_params(args)?;
+        if let Some(limit) = args.limit {
+            query_params.push(("limit", limit.to_string().into()));
+        }
+        if let Some(cursor) = &args.cursor {
+            query_params.push(("cursor", cursor.into()));
+        }
+
+        self.make_get_request_paginated(endpoint, &query_params).await
+    }
+
+    /// Page through tasks by hand, starting at `args.cursor`
+    ///
+    /// Uses the same filters as [`Self::get_tasks_by_filter`] and returns its
+    /// `ValidationError` up front when they conflict.
+    pub fn task_pages(&self, args: &TaskFilterArgs) -> TodoistResult<TaskPageIterator<'_>> {
+        let (endpoint, query_params) = task_filter_params(args)?;
+        Ok(PageIterator::new(
+            self,
+            endpoint,
+            owned_params(query_params),
+            args.limit,
+            args.cursor.clone(),
+        ))
+    }
+
+    /// Get every active task due today, following pagination
+    ///
+    /// Uses the `today` filter query, so "today" is the current day in the
+    /// timezone of the user's Todoist account. Overdue tasks are not included.
+    pub async fn get_tasks_due_today(&self) -> TodoistResult<Vec<Task>> {
+        self.get_all_pages("/tasks/filter", &[("query", "today".into())]).await
+    }
+
+    /// Get every active overdue task, following pagination
+    ///
+    /// Uses the `overdue` filter query: tasks due on an earlier day, or earlier
+    /// today for tasks with a due time, in the timezone of the user's account.
+    pub async fn get_overdue_tasks(&self) -> TodoistResult<Vec<Task>> {
+        self.get_all_pages("/tasks/filter", &[("query", "overdue".into())])
+            .await
+    }
+
+    /// Create a new task
+    ///
+    /// The request carries a random `X-Request-Id`, kept when the client retries it, so
+    /// a retried creation does not produce a duplicate task.
+    pub async fn create_task(&self, args: &CreateTaskArgs) -> TodoistResult<Task> {
+        let args = self.task_defaults.apply(args);
+        args.validate()?;
+        let body_value = serde_json::to_value(args)?;
+        self.make_post_request("/tasks", Some(&body_value)).await
+    }
+
+    /// Create a new task with a caller-chosen idempotency key
+    ///
+    /// The key is sent as `X-Request-Id`. Reusing it when retrying a failed call (e.g.
+    /// after a network error) keeps the API from creating the task twice.
+    pub async fn create_task_with_request_id(
+        &self,
+        args: &CreateTaskArgs,
+        request_id: impl AsRef<str>,
+    ) -> TodoistResult<Task> {
+        let args = self.task_defaults.apply(args);
+        args.validate()?;
+        let body_value = serde_json::to_value(args)?;
+        self.make_post_request_with_id("/tasks", Some(&body_value), request_id.as_ref())
+            .await
+    }
+
+    /// Create several tasks concurrently, at most 8 requests at a time
+    ///
+    /// Results are returned in the same order as `args`. See
+    /// [`Self::create_tasks_with_concurrency`] for how rate limiting is handled.
+    pub async fn create_tasks(&self, args: &[CreateTaskArgs]) -> Vec<TodoistResult<Task>> {
+        self.create_tasks_with_concurrency(args, DEFAULT_BATCH_CONCURRENCY)
+            .await
+    }
+
+    /// Create several tasks concurrently, with at most `concurrency` requests in flight
+    ///
+    /// Results are returned in the same order as `args`. When a request is rate
+    /// limited, the whole batch pauses before sending anything else, and the
+    /// rate-limited task is retried following the client's [`RetryConfig`] (or the
+    /// default policy when none is set). A task that is still rate limited after
+    /// the last retry gets a `RateLimited` error. Network errors and 5xx responses
+    /// are retried as for [`Self::create_task`]: only when the client has a
+    /// [`RetryConfig`], and with the same `X-Request-Id`, so no task is created twice.
+    pub async fn create_tasks_with_concurrency(
+        &self,
+        args: &[CreateTaskArgs],
+        concurrency: usize,
+    ) -> Vec<TodoistResult<Task>> {
+        // Rate limits are retried here, across the batch, rather than per request
+        let client = std::sync::Arc::new(Self {
+            retry_config: None,
+            ..self.clone()
+        });
+        let retry_transient = self.retry_config.is_some();
+        let retry_config = self.retry_config.clone().unwrap_or_default();
+        let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+        let paused_until = std::sync::Arc::new(std::sync::Mutex::new(None));
+
+        let mut results: Vec<Option<TodoistResult<Task>>> = Vec::with_capacity(args.len());
+        let mut tasks = tokio::task::JoinSet::new();
+        for (index, task_args) in args.iter().enumerate() {
+            let task_args = self.task_defaults.apply(task_args);
+            if let Err(e) = task_args.validate() {
+                results.push(Some(Err(e)));
+                continue;
+            }
+            let body = match serde_json::to_value(task_args) {
+                Ok(body) => body,
+                Err(e) => {
+                    results.push(Some(Err(e.into())));
+                    continue;
+                }
+            };
+            results.push(None);
+
+            let client = client.clone();
+            let retry_config = retry_config.clone();
+            let permits = permits.clone();
+            let paused_until = paused_until.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let result = client
+                    .create_task_in_batch(&body, &retry_config, retry_transient, &paused_until)
+                    .await;
+                (index, result)
+            });
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, result)) => results[index] = Some(result),
+                Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+                // Cancelled, e.g. while the runtime shuts down: its slot is filled below
+                Err(_) => {}
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| Err(generic_error(None, "Task creation was cancelled before completing")))
+            })
+            .collect()
+    }
+
+    /// Create one task of a batch, pausing the whole batch whenever a request is rate limited
+    ///
+    /// Network errors and 5xx responses are retried when `retry_transient` is set; every
+    /// attempt carries the same `X-Request-Id`, which makes repeating the POST safe.
+    async fn create_task_in_batch(
+        &self,
+        body: &Value,
+        retry_config: &RetryConfig,
+        retry_transient: bool,
+        paused_until: &std::sync::Mutex<Option<tokio::time::Instant>>,
+    ) -> TodoistResult<Task> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let mut attempt: u32 = 0;
+
+        loop {
+            let resume_at = *paused_until.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(resume_at) = resume_at {
+                tokio::time::sleep_until(resume_at).await;
+            }
+
+            let result = self.make_post_request_with_id("/tasks", Some(body), &request_id).await;
+            let (delay, pause_batch) = match &result {
+                Err(TodoistError::RateLimited { retry_after, .. }) if attempt < retry_config.max_retries => {
+                    (retry_config.delay_for(attempt, *retry_after), true)
+                }
+                // Only this task backs off; the rest of the batch keeps going
+                Err(TodoistError::NetworkError { .. } | TodoistError::ServerError { .. })
+                    if retry_transient && attempt < retry_config.max_retries =>
+                {
+                    (retry_config.delay_for(attempt, None), false)
+                }
+                _ => (None, false),
+            };
+
+            let Some(delay) = delay else {
+                return result;
+            };
+            if !pause_batch {
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            let resume_at = tokio::time::Instant::now() + delay;
+            let mut pause = paused_until.lock().unwrap_or_else(|e| e.into_inner());
+            if pause.is_none_or(|current| current < resume_at) {
+                *pause = Some(resume_at);
+            }
+            drop(pause);
+            attempt += 1;
+        }
+    }
+
+    /// Create a task from natural language, as in the Todoist apps' Quick Add
+    ///
+    /// The text is parsed by Todoist, e.g. `"Buy milk tomorrow at 5pm #Groceries p1 @errand"`
+    /// sets the due date, project, priority and labels of the created task.
+    ///
+    /// Defaults set with [`Self::with_defaults`] are applied with follow-up requests,
+    /// only where the text left them out: the task is moved to the default project
+    /// when the text names no `#project`, given the default priority when it has no
+    /// `p1`-`p4` token (so an explicit `p4` is kept), and given the default labels
+    /// when it ends up with no labels. These follow-ups are best-effort: if one fails,
+    /// the created task is still returned as it stands, so a retry does not add a
+    /// duplicate.
+    pub async fn quick_add_task(&self, text: &str) -> TodoistResult<Task> {
+        let body_value = serde_json::json!({ "text": text });
+        let mut task: Task = self.make_post_request("/tasks/quick", Some(&body_value)).await?;
+        let defaults = &self.task_defaults;
+
+        let names_project = text.split_whitespace().any(|word| word.starts_with('#'));
+        if let (Some(project_id), false) = (&defaults.project_id, names_project) {
+            if task.project_id != *project_id {
+                let args = MoveTaskArgs {
+                    project_id: Some(project_id.clone()),
+                    ..Default::default()
+                };
+                if let Ok(moved) = self.move_task(&task.id, &args).await {
+                    task = moved;
+                }
+            }
+        }
+
+        let names_priority = text
+            .split_whitespace()
+            .any(|word| matches!(word.to_ascii_lowercase().as_str(), "p1" | "p2" | "p3" | "p4"));
+        let update = UpdateTaskArgs {
+            priority: defaults.priority.filter(|_| !names_priority),
+            labels: defaults.labels.clone().filter(|_| task.labels.is_empty()),
+            ..Default::default()
+        };
+        if update.has_updates() {
+            if let Ok(updated) = self.update_task(&task.id, &update).await {
+                task = updated;
+            }
+        }
+        Ok(task)
+    }
+
+    /// Create a copy of a task, in the same place or at the top of another project
+    ///
+    /// Content, description, labels, priority, due date, deadline and duration are
+    /// copied. Without `into_project` the copy is created next to the original, in
+    /// the same section and under the same parent. Sub-tasks are not copied; see
+    /// [`Self::duplicate_task_with_options`].
+    pub async fn duplicate_task(&self, task_id: impl AsRef<str>, into_project: Option<&str>) -> TodoistResult<Task> {
+        self.duplicate_task_with_options(task_id, into_project, &DuplicateOptions::default())
+            .await
+    }
+
+    /// Create a copy of a task, optionally with all of its active sub-tasks
+    ///
+    /// See [`Self::duplicate_task`]. Sub-tasks are created one at a time, parents
+    /// before their children and siblings in `child_order`. If one of these requests
+    /// fails, the error is returned and the copies made so far are kept.
+    pub async fn duplicate_task_with_options(
+        &self,
+        task_id: impl AsRef<str>,
+        into_project: Option<&str>,
+        options: &DuplicateOptions,
+    ) -> TodoistResult<Task> {
+        let source = self.get_task(task_id).await?;
+
+        let mut args = CreateTaskArgs::duplicate_of(&source);
+        match into_project {
+            Some(project_id) if project_id != source.project_id => args.project_id = Some(project_id.to_string()),
+            _ => {
+                args.project_id = Some(source.project_id.clone());
+                args.section_id = source.section_id.clone();
+                args.parent_id = source.parent_id.clone();
+            }
+        }
+        let copy = self.create_task(&args).await?;
+
+        if options.include_subtasks {
+            let project_tasks = self.get_all_tasks_for_project(&source.project_id).await?;
+            let mut children: HashMap<&str, Vec<&Task>> = HashMap::new();
+            for task in &project_tasks {
+                if let Some(parent_id) = task.parent_id.as_deref() {
+                    children.entry(parent_id).or_default().push(task);
+                }
+            }
+            for siblings in children.values_mut() {
+                siblings.sort_by_key(|task| task.child_order);
+            }
+
+            // Pairs of (original, copy) whose children are still to be copied
+            let mut pending = vec![(source.id.clone(), copy.id.clone())];
+            while let Some((original_id, copy_id)) = pending.pop() {
+                for child in children.get(original_id.as_str()).into_iter().flatten() {
+                    let mut child_args = CreateTaskArgs::duplicate_of(child);
+                    child_args.parent_id = Some(copy_id.clone());
+                    let child_copy = self.create_task(&child_args).await?;
+                    pending.push((child.id.clone(), child_copy.id));
+                }
+            }
+        }
+
+        Ok(copy)
     }
 
-    /// Create a new project
-    pub async fn create_project(&self, args: &CreateProjectArgs) -> TodoistResult<Project> {
+    /// Update an existing task
+    ///
+    /// Returns a `ValidationError` without sending a request when `args.has_updates()` is false.
+    pub async fn update_task(&self, task_id: impl AsRef<str>, args: &UpdateTaskArgs) -> TodoistResult<Task> {
+        let task_id = task_id.as_ref();
+        if !args.has_updates() {
+            return Err(validation_error(None, "No fields specified for update".to_string()));
+        }
+        args.validate()?;
         let body_value = serde_json::to_value(args)?;
-        self.make_post_request("/projects", Some(&body_value)).await
+        self.make_post_request(&format!("/tasks/{task_id}"), Some(&body_value))
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))
     }
 
-    /// Update an existing project
-    pub async fn update_project(&self, project_id: &str, args: &UpdateProjectArgs) -> TodoistResult<Project> {
-        if !args.has_updates() {
-            return Err(TodoistError::ValidationError {
-                field: None,
-                message: "No fields specified for update".to_string(),
-            });
+    /// Move a task to another project, section or parent task
+    ///
+    /// `update_task` cannot change a task's location; this uses the dedicated move
+    /// endpoint instead. Exactly one destination must be set in `args`.
+    pub async fn move_task(&self, task_id: impl AsRef<str>, args: &MoveTaskArgs) -> TodoistResult<Task> {
+        let task_id = task_id.as_ref();
+        match args.destination_count() {
+            0 => return Err(validation_error(None, "No destination specified for move".to_string())),
+            1 => {}
+            _ => {
+                return Err(validation_error(
+                    None,
+                    "Only one of project_id, section_id or parent_id can be set for a move".to_string(),
+                ))
+            }
         }
         let body_value = serde_json::to_value(args)?;
-        self.make_post_request(&format!("/projects/{project_id}"), Some(&body_value))
+        self.make_post_request(&format!("/tasks/{task_id}/move"), Some(&body_value))
             .await
+            .map_err(|e| e.for_resource("Task", task_id))
     }
 
-    /// Delete a project
-    pub async fn delete_project(&self, project_id: &str) -> TodoistResult<()> {
-        self.make_delete_request(&format!("/projects/{project_id}")).await
+    /// Complete a task
+    ///
+    /// A recurring task (see [`Task::is_recurring`]) is not closed: it stays open,
+    /// with `checked` false, and its due date moves to the next occurrence. Use
+    /// [`Self::complete_recurring_task`] to get the advanced task back.
+    pub async fn complete_task(&self, task_id: impl AsRef<str>) -> TodoistResult<()> {
+        let task_id = task_id.as_ref();
+        self.make_post_request_no_content(&format!("/tasks/{task_id}/close"), None)
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))
     }
 
-    // ===== TASK OPERATIONS =====
+    /// Complete a task and return its new state
+    ///
+    /// Recurring tasks are not closed but moved to their next occurrence, so the
+    /// returned task carries the advanced `due`. The response body is used when
+    /// the API sends one; otherwise the task is fetched again.
+    pub async fn complete_task_returning(&self, task_id: impl AsRef<str>) -> TodoistResult<Task> {
+        let task_id = task_id.as_ref();
+        self.post_returning_task(task_id, "close").await
+    }
 
-    /// Get all tasks (paginated)
-    pub async fn get_tasks(
-        &self,
-        limit: Option<i32>,
-        cursor: Option<String>,
-    ) -> TodoistResult<PaginatedResponse<Task>> {
-        let mut query_params = Vec::new();
-        if let Some(l) = limit {
-            query_params.push(("limit", l.to_string()));
-        }
-        if let Some(c) = cursor {
-            query_params.push(("cursor", c));
+    /// Complete one occurrence of a recurring task and return the task moved to its next occurrence
+    ///
+    /// The task is fetched first; a task that does not recur returns a `ValidationError`
+    /// without being completed, since completing it would close it rather than advance it.
+    pub async fn complete_recurring_task(&self, task_id: impl AsRef<str>) -> TodoistResult<Task> {
+        let task_id = task_id.as_ref();
+        let task = self.get_task(task_id).await?;
+        if !task.is_recurring() {
+            return Err(validation_error(
+                Some("due"),
+                format!("Task {} is not recurring", task_id),
+            ));
         }
-        self.make_get_request_paginated("/tasks", &query_params).await
+        self.post_returning_task(task_id, "close").await
     }
 
-    /// Get tasks for a specific project (paginated)
-    pub async fn get_tasks_for_project(
-        &self,
-        project_id: &str,
-        limit: Option<i32>,
-        cursor: Option<String>,
-    ) -> TodoistResult<PaginatedResponse<Task>> {
-        let mut query_params = vec![("project_id", project_id.to_string())];
-        if let Some(l) = limit {
-            query_params.push(("limit", l.to_string()));
-        }
-        if let Some(c) = cursor {
-            query_params.push(("cursor", c));
-        }
-        self.make_get_request_paginated("/tasks", &query_params).await
+    /// Reopen a completed task
+    pub async fn reopen_task(&self, task_id: impl AsRef<str>) -> TodoistResult<()> {
+        let task_id = task_id.as_ref();
+        self.make_post_request_no_content(&format!("/tasks/{task_id}/reopen"), None)
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))
     }
 
-    /// Get a specific task by ID
-    pub async fn get_task(&self, task_id: &str) -> TodoistResult<Task> {
-        self.make_get_request(&format!("/tasks/{task_id}")).await
+    /// Reopen a completed task and return its new state
+    pub async fn reopen_task_returning(&self, task_id: impl AsRef<str>) -> TodoistResult<Task> {
+        let task_id = task_id.as_ref();
+        self.post_returning_task(task_id, "reopen").await
     }
 
-    /// Get tasks by filter query (paginated)
-    pub async fn get_tasks_by_filter(&self, args: &TaskFilterArgs) -> TodoistResult<PaginatedResponse<Task>> {
-        let mut query_params = vec![("query", args.query.clone())];
+    /// POST a task action, returning the task from the response body or a follow-up GET
+    async fn post_returning_task(&self, task_id: &str, action: &str) -> TodoistResult<Task> {
+        let endpoint = format!("/tasks/{task_id}/{action}");
+        let request = self.post_request(&endpoint, None, &uuid::Uuid::new_v4().to_string());
+        let task: Option<Task> = self
+            .send_request("POST", &endpoint, request, decode_optional_json)
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))?;
 
-        if let Some(lang) = &args.lang {
-            query_params.push(("lang", lang.clone()));
+        match task {
+            Some(task) => Ok(task),
+            None => self.get_task(task_id).await,
         }
-        if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
-        }
-        if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
-        }
-
-        self.make_get_request_paginated("/tasks/filter", &query_params).await
     }
 
-    /// Create a new task
-    pub async fn create_task(&self, args: &CreateTaskArgs) -> TodoistResult<Task> {
-        let body_value = serde_json::to_value(args)?;
-        self.make_post_request("/tasks", Some(&body_value)).await
+    /// Delete a task
+    pub async fn delete_task(&self, task_id: impl AsRef<str>) -> TodoistResult<()> {
+        let task_id = task_id.as_ref();
+        self.make_delete_request(&format!("/tasks/{task_id}"))
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))
     }
 
-    /// Update an existing task
-    pub async fn update_task(&self, task_id: &str, args: &UpdateTaskArgs) -> TodoistResult<Task> {
-        if !args.has_updates() {
-            return Err(TodoistError::ValidationError {
-                field: None,
-                message: "No fields specified for update".to_string(),
-            });
-        }
-        let body_value = serde_json::to_value(args)?;
-        self.make_post_request(&format!("/tasks/{task_id}"), Some(&body_value))
-            .await
+    /// Complete several tasks with batched Sync API `item_close` commands
+    ///
+    /// Returns one result per ID, in the same order. Tasks are completed as with
+    /// [`Self::complete_task`], so recurring tasks move to their next occurrence.
+    /// Up to 100 commands are sent per request; each command succeeds or fails on its
+    /// own, and a request that fails as a whole gives its error to each of its tasks.
+    pub async fn complete_tasks(&self, ids: &[&str]) -> Vec<TodoistResult<()>> {
+        self.run_task_commands("item_close", ids).await
     }
 
-    /// Complete a task
-    pub async fn complete_task(&self, task_id: &str) -> TodoistResult<()> {
-        self.make_post_request(&format!("/tasks/{task_id}/close"), None).await
+    /// Delete several tasks with batched Sync API `item_delete` commands
+    ///
+    /// Returns one result per ID, in the same order, as in [`Self::complete_tasks`].
+    /// Deleting a task also deletes its sub-tasks.
+    pub async fn delete_tasks(&self, ids: &[&str]) -> Vec<TodoistResult<()>> {
+        self.run_task_commands("item_delete", ids).await
     }
 
-    /// Reopen a completed task
-    pub async fn reopen_task(&self, task_id: &str) -> TodoistResult<()> {
-        self.make_post_request(&format!("/tasks/{task_id}/reopen"), None).await
+    /// Apply a Sync API command taking only a task `id` to each of `ids`, in batches
+    async fn run_task_commands(&self, command_type: &str, ids: &[&str]) -> Vec<TodoistResult<()>> {
+        let mut results = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_SYNC_COMMANDS) {
+            let commands: Vec<SyncCommand> = chunk
+                .iter()
+                .map(|id| SyncCommand::new(command_type, serde_json::json!({ "id": id })))
+                .collect();
+            match self.sync_commands(&commands).await {
+                Ok(mut result) => results.extend(commands.iter().zip(chunk).map(|(command, id)| {
+                    result
+                        .sync_status
+                        .remove(&command.uuid)
+                        .unwrap_or_else(|| {
+                            Err(empty_response_error(
+                                "/sync",
+                                format!("No sync status returned for command {}", command_type),
+                            ))
+                        })
+                        .map_err(|e| e.for_resource("Task", id))
+                })),
+                Err(e) => results.extend(chunk.iter().map(|_| Err(e.clone()))),
+            }
+        }
+        results
     }
 
-    /// Delete a task
-    pub async fn delete_task(&self, task_id: &str) -> TodoistResult<()> {
-        self.make_delete_request(&format!("/tasks/{task_id}")).await
+    /// Get completed tasks within a completion date range
+    ///
+    /// `since` and `until` are required by the API. Returned tasks carry their
+    /// `completed_at` timestamp. Use [`Self::get_completed_tasks_by_due_date`] to
+    /// filter on due date instead.
+    pub async fn get_completed_tasks(&self, args: &CompletedTasksFilterArgs) -> TodoistResult<PaginatedResponse<Task>> {
+        for (field, value) in [("since", &args.since), ("until", &args.until)] {
+            if value.is_none() {
+                return Err(validation_error(
+                    Some(field),
+                    format!("`{field}` is required when fetching completed tasks"),
+                ));
+            }
+        }
+
+        self.get_completed_tasks_by_completion_date(args).await
     }
 
     /// Get completed tasks by completion date (up to 3 months range)
@@ -405,22 +2224,22 @@ impl TodoistWrapper {
         let mut query_params = Vec::new();
 
         if let Some(since) = &args.since {
-            query_params.push(("since", since.clone()));
+            query_params.push(("since", since.into()));
         }
         if let Some(until) = &args.until {
-            query_params.push(("until", until.clone()));
+            query_params.push(("until", until.into()));
         }
         if let Some(project_id) = &args.project_id {
-            query_params.push(("project_id", project_id.clone()));
+            query_params.push(("project_id", project_id.into()));
         }
         if let Some(section_id) = &args.section_id {
-            query_params.push(("section_id", section_id.clone()));
+            query_params.push(("section_id", section_id.into()));
         }
         if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
+            query_params.push(("limit", limit.to_string().into()));
         }
         if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
+            query_params.push(("cursor", cursor.into()));
         }
 
         self.make_get_request_paginated("/tasks/completed/by_completion_date", &query_params)
@@ -436,22 +2255,22 @@ impl TodoistWrapper {
         let mut query_params = Vec::new();
 
         if let Some(since) = &args.since {
-            query_params.push(("since", since.clone()));
+            query_params.push(("since", since.into()));
         }
         if let Some(until) = &args.until {
-            query_params.push(("until", until.clone()));
+            query_params.push(("until", until.into()));
         }
         if let Some(project_id) = &args.project_id {
-            query_params.push(("project_id", project_id.clone()));
+            query_params.push(("project_id", project_id.into()));
         }
         if let Some(section_id) = &args.section_id {
-            query_params.push(("section_id", section_id.clone()));
+            query_params.push(("section_id", section_id.into()));
         }
         if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
+            query_params.push(("limit", limit.to_string().into()));
         }
         if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
+            query_params.push(("cursor", cursor.into()));
         }
 
         self.make_get_request_paginated("/tasks/completed/by_due_date", &query_params)
@@ -468,31 +2287,55 @@ impl TodoistWrapper {
     ) -> TodoistResult<PaginatedResponse<Label>> {
         let mut query_params = Vec::new();
         if let Some(l) = limit {
-            query_params.push(("limit", l.to_string()));
+            query_params.push(("limit", l.to_string().into()));
         }
         if let Some(c) = cursor {
-            query_params.push(("cursor", c));
+            query_params.push(("cursor", c.into()));
         }
         self.make_get_request_paginated("/labels", &query_params).await
     }
 
+    /// Get every personal label, following `next_cursor` across all pages
+    pub async fn get_all_labels(&self) -> TodoistResult<Vec<Label>> {
+        self.get_all_pages("/labels", &[]).await
+    }
+
+    /// Fetch every personal label into a [`LabelIndex`] mapping names to IDs and back
+    pub async fn get_label_index(&self) -> TodoistResult<LabelIndex> {
+        Ok(LabelIndex::new(self.get_all_labels().await?))
+    }
+
+    /// Stream every personal label, following pagination cursors as the stream is polled
+    #[cfg(feature = "futures")]
+    pub fn get_all_labels_stream(&self) -> impl futures_util::Stream<Item = TodoistResult<Label>> + '_ {
+        self.paginate("/labels", Vec::new())
+    }
+
     /// Get labels with filtering and pagination
     pub async fn get_labels_filtered(&self, args: &LabelFilterArgs) -> TodoistResult<PaginatedResponse<Label>> {
         let mut query_params = Vec::new();
 
         if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
+            query_params.push(("limit", limit.to_string().into()));
         }
         if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
+            query_params.push(("cursor", cursor.into()));
         }
 
         self.make_get_request_paginated("/labels", &query_params).await
     }
 
+    /// Page through personal labels by hand, starting at `args.cursor`
+    pub fn label_pages(&self, args: &LabelFilterArgs) -> LabelPageIterator<'_> {
+        PageIterator::new(self, "/labels", Vec::new(), args.limit, args.cursor.clone())
+    }
+
     /// Get a specific label by ID
-    pub async fn get_label(&self, label_id: &str) -> TodoistResult<Label> {
-        self.make_get_request(&format!("/labels/{label_id}")).await
+    pub async fn get_label(&self, label_id: impl AsRef<str>) -> TodoistResult<Label> {
+        let label_id = label_id.as_ref();
+        self.make_get_request(&format!("/labels/{label_id}"))
+            .await
+            .map_err(|e| e.for_resource("Label", label_id))
     }
 
     /// Create a new label
@@ -502,21 +2345,69 @@ impl TodoistWrapper {
     }
 
     /// Update an existing label
-    pub async fn update_label(&self, label_id: &str, args: &UpdateLabelArgs) -> TodoistResult<Label> {
+    ///
+    /// Returns a `ValidationError` without sending a request when `args.has_updates()` is false.
+    pub async fn update_label(&self, label_id: impl AsRef<str>, args: &UpdateLabelArgs) -> TodoistResult<Label> {
+        let label_id = label_id.as_ref();
         if !args.has_updates() {
-            return Err(TodoistError::ValidationError {
-                field: None,
-                message: "No fields specified for update".to_string(),
-            });
+            return Err(validation_error(None, "No fields specified for update".to_string()));
         }
         let body_value = serde_json::to_value(args)?;
         self.make_post_request(&format!("/labels/{label_id}"), Some(&body_value))
             .await
+            .map_err(|e| e.for_resource("Label", label_id))
     }
 
     /// Delete a label
-    pub async fn delete_label(&self, label_id: &str) -> TodoistResult<()> {
-        self.make_delete_request(&format!("/labels/{label_id}")).await
+    pub async fn delete_label(&self, label_id: impl AsRef<str>) -> TodoistResult<()> {
+        let label_id = label_id.as_ref();
+        self.make_delete_request(&format!("/labels/{label_id}"))
+            .await
+            .map_err(|e| e.for_resource("Label", label_id))
+    }
+
+    /// Get the names of all labels used on active tasks, across all pages
+    ///
+    /// This includes shared labels that collaborators added in shared projects, which
+    /// never appear in `get_labels`. Set `omit_personal` to leave out the names of
+    /// the user's personal labels.
+    pub async fn get_shared_labels(&self, omit_personal: bool) -> TodoistResult<Vec<String>> {
+        self.get_all_pages("/labels/shared", &[("omit_personal", omit_personal.to_string().into())])
+            .await
+    }
+
+    /// Rename a shared label on all active tasks
+    pub async fn rename_shared_label(&self, name: &str, new_name: &str) -> TodoistResult<()> {
+        let body_value = serde_json::json!({ "name": name, "new_name": new_name });
+        self.make_post_request_no_content("/labels/shared/rename", Some(&body_value))
+            .await
+    }
+
+    /// Remove a shared label from all active tasks
+    pub async fn remove_shared_label(&self, name: &str) -> TodoistResult<()> {
+        let body_value = serde_json::json!({ "name": name });
+        self.make_post_request_no_content("/labels/shared/remove", Some(&body_value))
+            .await
+    }
+
+    /// Count how many active tasks use each label, keyed by label name
+    ///
+    /// Fetches every task and every personal label once. Personal labels that no
+    /// task uses are included with a count of zero, which makes unused labels easy
+    /// to find. Shared labels that only appear on tasks are counted as well.
+    pub async fn get_label_usage(&self) -> TodoistResult<HashMap<String, usize>> {
+        let no_params: &[QueryParam] = &[];
+        let (tasks, labels) = tokio::try_join!(
+            self.get_all_pages::<Task>("/tasks", no_params),
+            self.get_all_pages::<Label>("/labels", no_params),
+        )?;
+
+        let mut usage: HashMap<String, usize> = labels.into_iter().map(|label| (label.name, 0)).collect();
+        for name in tasks.into_iter().flat_map(|task| task.labels) {
+            *usage.entry(name).or_insert(0) += 1;
+        }
+
+        Ok(usage)
     }
 
     // ===== SECTION OPERATIONS =====
@@ -529,10 +2420,10 @@ impl TodoistWrapper {
     ) -> TodoistResult<PaginatedResponse<Section>> {
         let mut query_params = Vec::new();
         if let Some(l) = limit {
-            query_params.push(("limit", l.to_string()));
+            query_params.push(("limit", l.to_string().into()));
         }
         if let Some(c) = cursor {
-            query_params.push(("cursor", c));
+            query_params.push(("cursor", c.into()));
         }
         self.make_get_request_paginated("/sections", &query_params).await
     }
@@ -542,21 +2433,76 @@ impl TodoistWrapper {
         let mut query_params = Vec::new();
 
         if let Some(project_id) = &args.project_id {
-            query_params.push(("project_id", project_id.clone()));
+            query_params.push(("project_id", project_id.into()));
         }
         if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
+            query_params.push(("limit", limit.to_string().into()));
         }
         if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
+            query_params.push(("cursor", cursor.into()));
         }
 
         self.make_get_request_paginated("/sections", &query_params).await
     }
 
+    /// Get every section of every project, following `next_cursor` across all pages
+    pub async fn get_all_sections(&self) -> TodoistResult<Vec<Section>> {
+        self.get_all_pages("/sections", &[]).await
+    }
+
+    /// Get every section of every project, grouped by `project_id`
+    ///
+    /// All pages are fetched, and each project's sections are sorted by `section_order`.
+    /// Projects without sections have no entry.
+    pub async fn get_sections_grouped(&self) -> TodoistResult<HashMap<String, Vec<Section>>> {
+        let mut grouped: HashMap<String, Vec<Section>> = HashMap::new();
+        for section in self.get_all_sections().await? {
+            grouped.entry(section.project_id.clone()).or_default().push(section);
+        }
+        for sections in grouped.values_mut() {
+            sort_sections(sections);
+        }
+        Ok(grouped)
+    }
+
     /// Get a specific section by ID
-    pub async fn get_section(&self, section_id: &str) -> TodoistResult<Section> {
-        self.make_get_request(&format!("/sections/{section_id}")).await
+    pub async fn get_section(&self, section_id: impl AsRef<str>) -> TodoistResult<Section> {
+        let section_id = section_id.as_ref();
+        self.make_get_request(&format!("/sections/{section_id}"))
+            .await
+            .map_err(|e| e.for_resource("Section", section_id))
+    }
+
+    /// Count the active tasks in a section, following pagination
+    pub async fn count_tasks_in_section(&self, section_id: impl AsRef<str>) -> TodoistResult<usize> {
+        let params = [("section_id", section_id.as_ref().into())];
+        let tasks: Vec<Task> = self.get_all_pages("/tasks", &params).await?;
+        Ok(tasks.len())
+    }
+
+    /// Get a project's sections, each with the number of active tasks it contains
+    ///
+    /// Sections and tasks are fetched concurrently (two paginated listings rather than
+    /// one request per section). Tasks outside any section are not counted.
+    pub async fn get_sections_with_counts(&self, project_id: impl AsRef<str>) -> TodoistResult<Vec<(Section, usize)>> {
+        let params = [("project_id", project_id.as_ref().into())];
+        let (sections, tasks) = tokio::try_join!(
+            self.get_all_pages::<Section>("/sections", &params),
+            self.get_all_pages::<Task>("/tasks", &params),
+        )?;
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for section_id in tasks.into_iter().filter_map(|task| task.section_id) {
+            *counts.entry(section_id).or_insert(0) += 1;
+        }
+
+        Ok(sections
+            .into_iter()
+            .map(|section| {
+                let count = counts.get(&section.id).copied().unwrap_or(0);
+                (section, count)
+            })
+            .collect())
     }
 
     /// Create a new section
@@ -566,23 +2512,104 @@ impl TodoistWrapper {
     }
 
     /// Update an existing section
-    pub async fn update_section(&self, section_id: &str, args: &UpdateSectionArgs) -> TodoistResult<Section> {
+    ///
+    /// A new `order` is applied first (via the Sync API `section_reorder` command), then
+    /// the name. When only the order changes, the updated section is fetched afterwards.
+    /// Returns a `ValidationError` without sending a request when `args.has_updates()` is false.
+    pub async fn update_section(
+        &self,
+        section_id: impl AsRef<str>,
+        args: &UpdateSectionArgs,
+    ) -> TodoistResult<Section> {
+        let section_id = section_id.as_ref();
+        if !args.has_updates() {
+            return Err(validation_error(None, "No fields specified for update".to_string()));
+        }
+        if let Some(order) = args.order {
+            self.reorder_sections(&[(section_id.to_string(), order)]).await?;
+        }
+        if args.name.is_none() {
+            return self.get_section(section_id).await;
+        }
         let body_value = serde_json::to_value(args)?;
         self.make_post_request(&format!("/sections/{section_id}"), Some(&body_value))
             .await
+            .map_err(|e| e.for_resource("Section", section_id))
+    }
+
+    /// Collapse a section in the Todoist UI
+    ///
+    /// The REST endpoint only accepts a section name, so this goes through the
+    /// Sync API `section_update` command.
+    pub async fn collapse_section(&self, section_id: impl AsRef<str>) -> TodoistResult<()> {
+        let section_id = section_id.as_ref();
+        self.set_section_collapsed(section_id, true).await
+    }
+
+    /// Expand a previously collapsed section in the Todoist UI
+    pub async fn expand_section(&self, section_id: impl AsRef<str>) -> TodoistResult<()> {
+        let section_id = section_id.as_ref();
+        self.set_section_collapsed(section_id, false).await
+    }
+
+    /// Set the collapsed state of a section (via the Sync API `section_update` command)
+    pub async fn set_section_collapsed(&self, section_id: impl AsRef<str>, collapsed: bool) -> TodoistResult<()> {
+        let section_id = section_id.as_ref();
+        self.execute_sync_command(
+            "section_update",
+            serde_json::json!({ "id": section_id, "collapsed": collapsed }),
+        )
+        .await
+    }
+
+    /// Persist a new order for the sections of a project in a single request
+    ///
+    /// Each pair is a section ID and its new `section_order`. Uses the Sync API
+    /// `section_reorder` command, since the REST API has no reorder endpoint.
+    pub async fn reorder_sections(&self, orders: &[(String, i32)]) -> TodoistResult<()> {
+        if orders.is_empty() {
+            return Ok(());
+        }
+        let sections: Vec<Value> = orders
+            .iter()
+            .map(|(id, section_order)| serde_json::json!({ "id": id, "section_order": section_order }))
+            .collect();
+        self.execute_sync_command("section_reorder", serde_json::json!({ "sections": sections }))
+            .await
+    }
+
+    /// Move a section, with all its tasks, to another project
+    ///
+    /// The REST API cannot change a section's project, so this sends the Sync API
+    /// `section_move` command and then fetches the moved section.
+    pub async fn move_section(
+        &self,
+        section_id: impl AsRef<str>,
+        target_project_id: impl AsRef<str>,
+    ) -> TodoistResult<Section> {
+        let section_id = section_id.as_ref();
+        self.execute_sync_command(
+            "section_move",
+            serde_json::json!({ "id": section_id, "project_id": target_project_id.as_ref() }),
+        )
+        .await
+        .map_err(|e| e.for_resource("Section", section_id))?;
+        self.get_section(section_id).await
     }
 
     /// Delete a section
-    pub async fn delete_section(&self, section_id: &str) -> TodoistResult<()> {
-        self.make_delete_request(&format!("/sections/{section_id}")).await
+    pub async fn delete_section(&self, section_id: impl AsRef<str>) -> TodoistResult<()> {
+        let section_id = section_id.as_ref();
+        self.make_delete_request(&format!("/sections/{section_id}"))
+            .await
+            .map_err(|e| e.for_resource("Section", section_id))
     }
 
     // ===== COMMENT OPERATIONS =====
 
     /// Get all comments (paginated)
     pub async fn get_comments(&self) -> TodoistResult<PaginatedResponse<Comment>> {
-        self.make_get_request_paginated("/comments", &[] as &[(&str, String)])
-            .await
+        self.make_get_request_paginated("/comments", &[]).await
     }
 
     /// Get comments with filtering and pagination
@@ -590,24 +2617,70 @@ impl TodoistWrapper {
         let mut query_params = Vec::new();
 
         if let Some(task_id) = &args.task_id {
-            query_params.push(("task_id", task_id.clone()));
+            query_params.push(("task_id", task_id.into()));
         }
         if let Some(project_id) = &args.project_id {
-            query_params.push(("project_id", project_id.clone()));
+            query_params.push(("project_id", project_id.into()));
         }
         if let Some(limit) = args.limit {
-            query_params.push(("limit", limit.to_string()));
+            query_params.push(("limit", limit.to_string().into()));
         }
         if let Some(cursor) = &args.cursor {
-            query_params.push(("cursor", cursor.clone()));
+            query_params.push(("cursor", cursor.into()));
         }
 
         self.make_get_request_paginated("/comments", &query_params).await
     }
 
+    /// Get every comment on a task, oldest first
+    ///
+    /// Follows `next_cursor` across all pages, then sorts by `posted_at`.
+    pub async fn get_comments_for_task(&self, task_id: impl AsRef<str>) -> TodoistResult<Vec<Comment>> {
+        let task_id = task_id.as_ref();
+        let mut comments: Vec<Comment> = self
+            .get_all_pages("/comments", &[("task_id", task_id.into())])
+            .await
+            .map_err(|e| e.for_resource("Task", task_id))?;
+        comments.sort_by(|a, b| a.posted_at.as_ref().cmp(&b.posted_at.as_ref()));
+        Ok(comments)
+    }
+
+    /// Stream every comment on a task or project, following pagination cursors as the stream is polled
+    ///
+    /// Only `task_id` and `project_id` are used from `args`; the stream manages `limit` and `cursor`.
+    #[cfg(feature = "futures")]
+    pub fn get_all_comments_stream(
+        &self,
+        args: &CommentFilterArgs,
+    ) -> impl futures_util::Stream<Item = TodoistResult<Comment>> + '_ {
+        let mut query_params = Vec::new();
+        if let Some(task_id) = &args.task_id {
+            query_params.push(("task_id", task_id.clone().into()));
+        }
+        if let Some(project_id) = &args.project_id {
+            query_params.push(("project_id", project_id.clone().into()));
+        }
+        self.paginate("/comments", query_params)
+    }
+
+    /// Page through the comments on a task or project by hand, starting at `args.cursor`
+    pub fn comment_pages(&self, args: &CommentFilterArgs) -> CommentPageIterator<'_> {
+        let mut query_params = Vec::new();
+        if let Some(task_id) = &args.task_id {
+            query_params.push(("task_id", task_id.clone().into()));
+        }
+        if let Some(project_id) = &args.project_id {
+            query_params.push(("project_id", project_id.clone().into()));
+        }
+        PageIterator::new(self, "/comments", query_params, args.limit, args.cursor.clone())
+    }
+
     /// Get a specific comment by ID
-    pub async fn get_comment(&self, comment_id: &str) -> TodoistResult<Comment> {
-        self.make_get_request(&format!("/comments/{comment_id}")).await
+    pub async fn get_comment(&self, comment_id: impl AsRef<str>) -> TodoistResult<Comment> {
+        let comment_id = comment_id.as_ref();
+        self.make_get_request(&format!("/comments/{comment_id}"))
+            .await
+            .map_err(|e| e.for_resource("Comment", comment_id))
     }
 
     /// Create a new comment
@@ -617,20 +2690,472 @@ impl TodoistWrapper {
     }
 
     /// Update an existing comment
-    pub async fn update_comment(&self, comment_id: &str, args: &UpdateCommentArgs) -> TodoistResult<Comment> {
+    ///
+    /// Returns a `ValidationError` without sending a request when `args.has_updates()` is false.
+    pub async fn update_comment(
+        &self,
+        comment_id: impl AsRef<str>,
+        args: &UpdateCommentArgs,
+    ) -> TodoistResult<Comment> {
+        let comment_id = comment_id.as_ref();
         if !args.has_updates() {
-            return Err(TodoistError::ValidationError {
-                field: None,
-                message: "No fields specified for update".to_string(),
-            });
+            return Err(validation_error(None, "No fields specified for update".to_string()));
         }
         let body_value = serde_json::to_value(args)?;
         self.make_post_request(&format!("/comments/{comment_id}"), Some(&body_value))
             .await
+            .map_err(|e| e.for_resource("Comment", comment_id))
     }
 
     /// Delete a comment
-    pub async fn delete_comment(&self, comment_id: &str) -> TodoistResult<()> {
-        self.make_delete_request(&format!("/comments/{comment_id}")).await
+    pub async fn delete_comment(&self, comment_id: impl AsRef<str>) -> TodoistResult<()> {
+        let comment_id = comment_id.as_ref();
+        self.make_delete_request(&format!("/comments/{comment_id}"))
+            .await
+            .map_err(|e| e.for_resource("Comment", comment_id))
+    }
+
+    /// Download the file attached to a comment
+    ///
+    /// Todoist file URLs require the API token, so this sends it the same way as
+    /// other requests. To keep the token from leaking to other sites, only HTTPS
+    /// URLs on `todoist.com` (or on the client's base URL host) are fetched; any
+    /// other `file_url`, such as a website attachment, returns a `ValidationError`
+    /// without sending a request. HTTP errors map to the usual error variants.
+    pub async fn download_attachment(&self, attachment: &Attachment) -> TodoistResult<Vec<u8>> {
+        let url = reqwest::Url::parse(&attachment.file_url)
+            .map_err(|e| validation_error(Some("file_url"), format!("Invalid attachment URL: {}", e)))?;
+        if !self.is_trusted_file_url(&url) {
+            return Err(validation_error(
+                Some("file_url"),
+                format!(
+                    "Refusing to send the API token to {}",
+                    url.host_str().unwrap_or_default()
+                ),
+            ));
+        }
+        let request = self.client.get(url.clone());
+        self.send_request("GET", url.path(), request, decode_bytes).await
+    }
+
+    /// Whether `url` is an HTTPS URL on Todoist, or shares the base URL's origin
+    fn is_trusted_file_url(&self, url: &reqwest::Url) -> bool {
+        let is_todoist_host = url
+            .host_str()
+            .is_some_and(|host| host == "todoist.com" || host.ends_with(".todoist.com"));
+        let is_base_origin = reqwest::Url::parse(&self.base_url).is_ok_and(|base| base.origin() == url.origin());
+        (url.scheme() == "https" && is_todoist_host) || is_base_origin
+    }
+
+    // ===== REMINDER OPERATIONS =====
+
+    /// Get all reminders, including location reminders
+    ///
+    /// Reminders are only available through the Sync API, so this performs a full
+    /// sync of the `reminders` and `reminders_location` resources.
+    pub async fn get_reminders(&self) -> TodoistResult<Vec<Reminder>> {
+        #[derive(serde::Deserialize)]
+        struct RemindersResponse {
+            #[serde(default)]
+            reminders: Vec<Reminder>,
+            #[serde(default)]
+            reminders_location: Vec<Reminder>,
+        }
+
+        let response: RemindersResponse = self
+            .make_sync_request(&[
+                ("sync_token", "*".to_string()),
+                ("resource_types", r#"["reminders","reminders_location"]"#.to_string()),
+            ])
+            .await?;
+
+        Ok(response
+            .reminders
+            .into_iter()
+            .chain(response.reminders_location)
+            .filter(|reminder| !reminder.is_deleted)
+            .collect())
+    }
+
+    /// Add a reminder to a task (via the Sync API `reminder_add` command)
+    ///
+    /// Returns the ID of the new reminder. Reminders require a premium plan.
+    pub async fn add_reminder(&self, task_id: impl AsRef<str>, reminder: &CreateReminderArgs) -> TodoistResult<String> {
+        let task_id = task_id.as_ref();
+        let mut args = serde_json::to_value(reminder)?;
+        args["item_id"] = Value::from(task_id);
+        let temp_id = uuid::Uuid::new_v4().to_string();
+        let command = SyncCommand::new("reminder_add", args).with_temp_id(&temp_id);
+
+        let mut result = self.sync_commands(std::slice::from_ref(&command)).await?;
+        if let Some(Err(e)) = result.sync_status.remove(&command.uuid) {
+            return Err(e.for_resource("Task", task_id));
+        }
+        result
+            .temp_id_mapping
+            .remove(&temp_id)
+            .ok_or_else(|| empty_response_error("/sync", "No ID returned for the new reminder"))
+    }
+
+    /// Delete a reminder (via the Sync API `reminder_delete` command)
+    pub async fn delete_reminder(&self, reminder_id: impl AsRef<str>) -> TodoistResult<()> {
+        let reminder_id = reminder_id.as_ref();
+        self.execute_sync_command("reminder_delete", serde_json::json!({ "id": reminder_id }))
+            .await
+            .map_err(|e| e.for_resource("Reminder", reminder_id))
+    }
+
+    // ===== ACTIVITY OPERATIONS =====
+
+    /// Get activity log events (paginated)
+    ///
+    /// The activity log is only available on paid plans. Setting `object_id`
+    /// without `object_type` returns a `ValidationError` without sending a request.
+    pub async fn get_activity_log(&self, args: &ActivityFilterArgs) -> TodoistResult<PaginatedResponse<ActivityEvent>> {
+        let mut query_params = Vec::new();
+
+        if let Some(object_type) = &args.object_type {
+            query_params.push(("object_type", object_type.as_str().into()));
+        }
+        if let Some(object_id) = &args.object_id {
+            if args.object_type.is_none() {
+                return Err(validation_error(
+                    Some("object_type"),
+                    "`object_type` is required when filtering by `object_id`",
+                ));
+            }
+            query_params.push(("object_id", object_id.into()));
+        }
+        if let Some(event_type) = &args.event_type {
+            query_params.push(("event_type", event_type.as_str().into()));
+        }
+        if let Some(parent_project_id) = &args.parent_project_id {
+            query_params.push(("parent_project_id", parent_project_id.into()));
+        }
+        if let Some(parent_task_id) = &args.parent_task_id {
+            query_params.push(("parent_item_id", parent_task_id.into()));
+        }
+        if let Some(initiator_id) = &args.initiator_id {
+            query_params.push(("initiator_id", initiator_id.into()));
+        }
+        if let Some(limit) = args.limit {
+            query_params.push(("limit", limit.to_string().into()));
+        }
+        if let Some(cursor) = &args.cursor {
+            query_params.push(("cursor", cursor.into()));
+        }
+
+        self.make_get_request_paginated("/activities", &query_params).await
+    }
+}
+
+/// Turns the body of a successful response into a result, given the endpoint it came from
+type Decode<T> = fn(&str, &[u8]) -> TodoistResult<T>;
+
+/// Deserialize a JSON response body, which must not be empty
+fn decode_json<T>(endpoint: &str, body: &[u8]) -> TodoistResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Err(empty_response_error(endpoint, "API returned empty response body"));
+    }
+    serde_json::from_slice::<T>(body).map_err(|e| TodoistError::ParseError {
+        message: format!("Failed to parse response from {}: {}", endpoint, e),
+        request_id: None,
+    })
+}
+
+/// Deserialize a JSON response body, or return `None` when the body is empty
+fn decode_optional_json<T>(endpoint: &str, body: &[u8]) -> TodoistResult<Option<T>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+    decode_json(endpoint, body).map(Some)
+}
+
+/// Ignore the body of a response that carries no content
+fn decode_no_content(_endpoint: &str, _body: &[u8]) -> TodoistResult<()> {
+    Ok(())
+}
+
+/// Return the raw body of a response, e.g. a downloaded file
+fn decode_bytes(_endpoint: &str, body: &[u8]) -> TodoistResult<Vec<u8>> {
+    Ok(body.to_vec())
+}
+
+/// Emit a tracing event for one request attempt (never includes the API token)
+#[cfg(feature = "tracing")]
+fn trace_attempt<T>(
+    http_method: &str,
+    endpoint: &str,
+    status: Option<u16>,
+    elapsed: std::time::Duration,
+    result: &TodoistResult<T>,
+) {
+    let elapsed_ms = elapsed.as_millis() as u64;
+    let error = match result {
+        Ok(_) => {
+            tracing::debug!(
+                method = http_method,
+                endpoint,
+                status,
+                elapsed_ms,
+                "Todoist request succeeded"
+            );
+            return;
+        }
+        Err(error) => error,
+    };
+
+    let variant = error.variant_name();
+    match error {
+        TodoistError::ServerError { .. } | TodoistError::NetworkError { .. } | TodoistError::ParseError { .. } => {
+            tracing::error!(method = http_method, endpoint, status, elapsed_ms, error = variant, %error, "Todoist request failed")
+        }
+        _ => {
+            tracing::warn!(method = http_method, endpoint, status, elapsed_ms, error = variant, %error, "Todoist request failed")
+        }
+    }
+}
+
+/// Decide the cursor for the next page, guarding against a cursor that doesn't advance
+fn next_page_cursor(endpoint: &str, current: Option<&String>, next: Option<String>) -> TodoistResult<Option<String>> {
+    match next {
+        Some(next) if current == Some(&next) => Err(generic_error(
+            None,
+            format!("Pagination cursor for {} did not advance", endpoint),
+        )),
+        next => Ok(next),
+    }
+}
+
+/// Endpoint and query parameters selecting the tasks `args` asks for, without `limit` and `cursor`
+///
+/// A non-empty `query` goes to the filter endpoint; otherwise the structured
+/// filters narrow the active tasks. The API cannot combine the two.
+fn task_filter_params(args: &TaskFilterArgs) -> TodoistResult<(&'static str, Vec<QueryParam<'_>>)> {
+    let mut query_params = Vec::new();
+    if args.query.is_empty() {
+        if let Some(label) = &args.label {
+            query_params.push(("label", label.into()));
+        }
+        if let Some(project_id) = &args.project_id {
+            query_params.push(("project_id", project_id.into()));
+        }
+        if let Some(section_id) = &args.section_id {
+            query_params.push(("section_id", section_id.into()));
+        }
+        if let Some(ids) = &args.ids {
+            query_params.push(("ids", ids.join(",").into()));
+        }
+        return Ok(("/tasks", query_params));
+    }
+
+    if args.has_structured_filters() {
+        return Err(validation_error(
+            Some("query"),
+            "A filter query cannot be combined with label, project_id, section_id or ids".to_string(),
+        ));
+    }
+    query_params.push(("query", (&args.query).into()));
+    if let Some(lang) = &args.lang {
+        query_params.push(("lang", lang.as_str().into()));
+    }
+    Ok(("/tasks/filter", query_params))
+}
+
+/// Copy borrowed query parameter values so they can outlive the arguments
+fn owned_params(query_params: Vec<QueryParam<'_>>) -> Vec<QueryParam<'static>> {
+    query_params
+        .into_iter()
+        .map(|(name, value)| (name, Cow::Owned(value.into_owned())))
+        .collect()
+}
+
+/// Treat a `NotFound` error as success, for deletes of objects that may already be gone
+fn ignore_not_found(result: TodoistResult<()>) -> TodoistResult<()> {
+    match result {
+        Err(e) if e.is_not_found() => Ok(()),
+        result => result,
     }
 }
+
+fn too_many_pages_error(endpoint: &str) -> TodoistError {
+    generic_error(
+        None,
+        format!("Pagination for {} exceeded {} pages", endpoint, MAX_PAGES),
+    )
+}
+
+/// Read the rate-limit quota headers of a response, if it has any of them
+fn parse_rate_limit_headers(headers: &reqwest::header::HeaderMap) -> Option<RateLimitStatus> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+    };
+    let status = RateLimitStatus {
+        limit: header(RATE_LIMIT_LIMIT_HEADER),
+        remaining: header(RATE_LIMIT_REMAINING_HEADER),
+        reset: header(RATE_LIMIT_RESET_HEADER),
+    };
+    (status != RateLimitStatus::default()).then_some(status)
+}
+
+/// Convert one entry of a Sync API `sync_status` map into a result
+/// A command succeeded when its status is `"ok"`; otherwise the status is an error body
+fn sync_status_result(status: Value) -> TodoistResult<()> {
+    if status == "ok" {
+        return Ok(());
+    }
+    let mut body: ApiErrorBody = serde_json::from_value(status).unwrap_or_default();
+    if body.error.is_empty() {
+        body.error = "Sync command failed".to_string();
+    }
+    let http_code = body.http_code.unwrap_or(400);
+    Err(error_from_status(http_code, body, None))
+}
+
+/// Map an HTTP error status code to the matching `TodoistError` variant
+pub(crate) fn error_from_status(status_code: u16, body: ApiErrorBody, retry_after: Option<u64>) -> TodoistError {
+    let field = body.field().map(str::to_string);
+    let ApiErrorBody {
+        error: message,
+        error_code,
+        error_tag,
+        ..
+    } = body;
+    match status_code {
+        401 => TodoistError::AuthenticationError {
+            message,
+            request_id: None,
+        },
+        403 => TodoistError::AuthorizationError {
+            message,
+            error_code,
+            error_tag,
+            request_id: None,
+        },
+        404 => TodoistError::NotFound {
+            resource_type: "Resource".to_string(),
+            resource_id: None,
+            message,
+            request_id: None,
+        },
+        429 => TodoistError::RateLimited {
+            retry_after,
+            message,
+            request_id: None,
+        },
+        400 => TodoistError::ValidationError {
+            field,
+            message,
+            error_code,
+            error_tag,
+            request_id: None,
+        },
+        500..=599 => TodoistError::ServerError {
+            status_code,
+            message,
+            request_id: None,
+        },
+        _ => TodoistError::Generic {
+            status_code: Some(status_code),
+            message,
+            error_code,
+            error_tag,
+            request_id: None,
+        },
+    }
+}
+
+/// Structured error body returned by the API, e.g.
+/// `{"error": "...", "error_code": 22, "error_tag": "...", "error_extra": {"argument": "due_string"}, "http_code": 400}`
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct ApiErrorBody {
+    #[serde(default)]
+    error: String,
+    error_code: Option<i64>,
+    error_tag: Option<String>,
+    http_code: Option<u16>,
+    /// Details such as the offending argument; their shape varies between errors
+    error_extra: Option<Value>,
+}
+
+impl ApiErrorBody {
+    /// Parse an error response body, keeping the raw text as the message when it isn't structured
+    pub(crate) fn parse(text: String) -> Self {
+        match serde_json::from_str::<ApiErrorBody>(&text) {
+            Ok(body) if !body.error.is_empty() => body,
+            _ => ApiErrorBody {
+                error: text,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Name of the request field the error is about, from `error_extra.argument`
+    /// (or `error_extra.field`), when the API gives one
+    fn field(&self) -> Option<&str> {
+        let extra = self.error_extra.as_ref()?;
+        extra
+            .get("argument")
+            .or_else(|| extra.get("field"))
+            .and_then(Value::as_str)
+    }
+}
+
+/// Parse a `Retry-After` header given either as delay seconds or as an HTTP-date
+pub(crate) fn parse_retry_after(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    let retry_at = httpdate::parse_http_date(value).ok()?;
+    let wait = retry_at
+        .duration_since(std::time::SystemTime::now())
+        .unwrap_or_default();
+    // Round up so that waiting the returned number of seconds is always long enough
+    Some(wait.as_secs() + u64::from(wait.subsec_nanos() > 0))
+}
+
+/// Drop the headers the crate sets itself from user-provided default headers
+fn without_reserved_headers(mut headers: reqwest::header::HeaderMap) -> reqwest::header::HeaderMap {
+    use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, IF_NONE_MATCH, USER_AGENT};
+
+    for name in [AUTHORIZATION, CONTENT_TYPE, USER_AGENT, IF_NONE_MATCH] {
+        headers.remove(name);
+    }
+    headers.remove(REQUEST_ID_HEADER);
+    headers
+}
+
+/// Whether a request can be sent again after a network error or 5xx response
+/// without risking a duplicate write
+fn is_idempotent(request: &reqwest::Request) -> bool {
+    match *request.method() {
+        reqwest::Method::GET | reqwest::Method::DELETE => true,
+        reqwest::Method::POST => request.headers().contains_key(REQUEST_ID_HEADER),
+        _ => false,
+    }
+}
+
+/// Random value in `[0, 1)` used to jitter retry delays
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
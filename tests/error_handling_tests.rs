@@ -1,4 +1,6 @@
-use todoist_api::{empty_response_error, not_found_error, rate_limited_error, TodoistError};
+use todoist_api::{
+    empty_response_error, not_found_error, rate_limited_error, validation_error, NetworkErrorKind, TodoistError,
+};
 
 #[test]
 fn test_rate_limited_error() {
@@ -28,6 +30,7 @@ fn test_not_found_error() {
     let error = not_found_error("Project", Some("123"), "Project not found");
 
     assert!(error.is_not_found());
+    assert_eq!(error.status_code(), Some(404));
     assert_eq!(error.to_string(), "Project not found (ID: 123): Project not found");
 }
 
@@ -35,9 +38,11 @@ fn test_not_found_error() {
 fn test_authentication_error() {
     let error = TodoistError::AuthenticationError {
         message: "Invalid token".to_string(),
+        request_id: None,
     };
 
     assert!(error.is_authentication_error());
+    assert_eq!(error.status_code(), Some(401));
     assert_eq!(error.to_string(), "Authentication error: Invalid token");
 }
 
@@ -45,9 +50,13 @@ fn test_authentication_error() {
 fn test_authorization_error() {
     let error = TodoistError::AuthorizationError {
         message: "Insufficient permissions".to_string(),
+        error_code: None,
+        error_tag: None,
+        request_id: None,
     };
 
     assert!(error.is_authorization_error());
+    assert_eq!(error.status_code(), Some(403));
     assert_eq!(error.to_string(), "Authorization error: Insufficient permissions");
 }
 
@@ -56,17 +65,66 @@ fn test_validation_error() {
     let error = TodoistError::ValidationError {
         field: Some("name".to_string()),
         message: "Name is required".to_string(),
+        error_code: None,
+        error_tag: None,
+        request_id: None,
     };
 
     assert!(error.is_validation_error());
+    assert_eq!(error.status_code(), Some(400));
     assert_eq!(error.to_string(), "Validation error for field 'name': Name is required");
 }
 
+#[test]
+fn test_error_code_and_tag_accessors() {
+    let error = TodoistError::Generic {
+        status_code: Some(409),
+        message: "Conflict".to_string(),
+        error_code: Some(42),
+        error_tag: Some("CONFLICT".to_string()),
+        request_id: None,
+    };
+    assert_eq!(error.error_code(), Some(42));
+    assert_eq!(error.error_tag(), Some("CONFLICT"));
+
+    let error = validation_error(Some("content"), "Content is required");
+    assert_eq!(error.error_code(), None);
+    assert_eq!(error.error_tag(), None);
+    assert_eq!(
+        error.to_string(),
+        "Validation error for field 'content': Content is required"
+    );
+
+    assert_eq!(rate_limited_error("Slow down", None).error_code(), None);
+}
+
+#[test]
+fn test_request_id_in_display() {
+    let error = TodoistError::ServerError {
+        status_code: 503,
+        message: "Service unavailable".to_string(),
+        request_id: Some("req-42".to_string()),
+    };
+
+    assert_eq!(error.request_id(), Some("req-42"));
+    assert_eq!(
+        error.to_string(),
+        "Server error (503): Service unavailable (request ID: req-42)"
+    );
+
+    let error = TodoistError::NetworkError {
+        kind: NetworkErrorKind::Connect,
+        message: "Connection refused".to_string(),
+    };
+    assert_eq!(error.request_id(), None);
+}
+
 #[test]
 fn test_server_error() {
     let error = TodoistError::ServerError {
         status_code: 500,
         message: "Internal server error".to_string(),
+        request_id: None,
     };
 
     assert!(error.is_server_error());
@@ -77,17 +135,29 @@ fn test_server_error() {
 #[test]
 fn test_network_error() {
     let error = TodoistError::NetworkError {
+        kind: NetworkErrorKind::Timeout,
         message: "Connection timeout".to_string(),
     };
 
     assert!(error.is_network_error());
-    assert_eq!(error.to_string(), "Network error: Connection timeout");
+    assert!(error.is_timeout());
+    assert_eq!(error.network_error_kind(), Some(NetworkErrorKind::Timeout));
+    assert_eq!(error.status_code(), None);
+    assert_eq!(error.to_string(), "Network error (timed out): Connection timeout");
+
+    let error = TodoistError::NetworkError {
+        kind: NetworkErrorKind::Other,
+        message: "Connection reset".to_string(),
+    };
+    assert!(!error.is_timeout());
+    assert_eq!(error.to_string(), "Network error: Connection reset");
 }
 
 #[test]
 fn test_parse_error() {
     let error = TodoistError::ParseError {
         message: "Invalid JSON".to_string(),
+        request_id: None,
     };
 
     assert_eq!(error.to_string(), "Parse error: Invalid JSON");
@@ -98,6 +168,9 @@ fn test_generic_error() {
     let error = TodoistError::Generic {
         status_code: Some(418),
         message: "I'm a teapot".to_string(),
+        error_code: None,
+        error_tag: None,
+        request_id: None,
     };
 
     assert_eq!(error.status_code(), Some(418));
@@ -109,6 +182,9 @@ fn test_generic_error_no_status() {
     let error = TodoistError::Generic {
         status_code: None,
         message: "Unknown error".to_string(),
+        error_code: None,
+        error_tag: None,
+        request_id: None,
     };
 
     assert_eq!(error.status_code(), None);
@@ -127,6 +203,7 @@ fn test_error_display_formatting() {
                 resource_type: "Task".to_string(),
                 resource_id: None,
                 message: "Task not found".to_string(),
+                request_id: None,
             },
             "Task not found: Task not found",
         ),
@@ -134,6 +211,9 @@ fn test_error_display_formatting() {
             TodoistError::ValidationError {
                 field: None,
                 message: "Invalid input".to_string(),
+                error_code: None,
+                error_tag: None,
+                request_id: None,
             },
             "Validation error: Invalid input",
         ),
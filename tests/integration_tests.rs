@@ -1,15 +1,311 @@
-use serde_json::json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
 use todoist_api::*;
 use wiremock::{
-    matchers::{method, path, query_param},
-    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, body_string_contains, header, method, path, query_param},
+    Mock, MockServer, Request, ResponseTemplate,
 };
 
+/// Build a task payload as returned by the API
+fn task_json(id: &str) -> Value {
+    json!({
+        "id": id,
+        "user_id": "user_1",
+        "content": "Task",
+        "description": "",
+        "project_id": "proj_1",
+        "section_id": null,
+        "parent_id": null,
+        "added_by_uid": null,
+        "assigned_by_uid": null,
+        "responsible_uid": null,
+        "labels": [],
+        "deadline": null,
+        "duration": null,
+        "added_at": "2024-01-01T00:00:00Z",
+        "completed_at": null,
+        "completed_by_uid": null,
+        "updated_at": null,
+        "due": null,
+        "priority": 1,
+        "child_order": 0,
+        "day_order": 0,
+        "is_collapsed": false
+    })
+}
+
+/// Decode the `commands` form field of a Sync API request
+fn sync_commands(request: &Request) -> Vec<Value> {
+    let form: HashMap<String, String> = serde_urlencoded::from_bytes(&request.body).unwrap();
+    serde_json::from_str(&form["commands"]).unwrap()
+}
+
+/// Build a Sync API response acknowledging every command in the request
+fn sync_ok_response(request: &Request) -> ResponseTemplate {
+    let sync_status: serde_json::Map<String, Value> = sync_commands(request)
+        .iter()
+        .map(|command| (command["uuid"].as_str().unwrap().to_string(), json!("ok")))
+        .collect();
+    ResponseTemplate::new(200).set_body_json(json!({
+        "sync_status": sync_status,
+        "temp_id_mapping": {}
+    }))
+}
+
 #[tokio::test]
 async fn test_wrapper_creation() {
     let _todoist = TodoistWrapper::new("test-token".to_string());
 }
 
+#[tokio::test]
+async fn test_custom_base_url_with_prefix_and_trailing_slash() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/labels/label_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "label_123",
+            "name": "work",
+            "color": "red",
+            "order": 1,
+            "is_favorite": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let base_url = format!("{}/api/v1/", mock_server.uri());
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), base_url);
+
+    let label = todoist.get_label("label_123").await.unwrap();
+    assert_eq!(label.name, "work");
+}
+
+#[tokio::test]
+async fn test_with_custom_client_is_used() {
+    // Route everything through a proxy that refuses connections: if the injected
+    // client is used, the request fails at the network level without reaching Todoist
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all("http://127.0.0.1:9").unwrap())
+        .build()
+        .unwrap();
+
+    let todoist = TodoistWrapper::with_client(client, "test-token".to_string());
+
+    let error = todoist.get_labels(None, None).await.unwrap_err();
+    assert_eq!(error.network_error_kind(), Some(NetworkErrorKind::Connect));
+}
+
+#[tokio::test]
+async fn test_builder_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"results": [], "next_cursor": null}))
+                .set_delay(std::time::Duration::from_millis(500)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .timeout(std::time::Duration::from_millis(50))
+        .build();
+    let error = todoist.get_labels(None, None).await.unwrap_err();
+    assert!(error.is_timeout());
+    assert!(error.to_string().contains("timed out"));
+
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .timeout(std::time::Duration::from_secs(5))
+        .build();
+    assert!(todoist.get_labels(None, None).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_builder_timeout_applies_to_custom_client() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"results": [], "next_cursor": null}))
+                .set_delay(std::time::Duration::from_millis(500)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::builder("test-token")
+        .client(reqwest::Client::new())
+        .base_url(mock_server.uri())
+        .timeout(std::time::Duration::from_millis(50))
+        .build();
+
+    let result = todoist.get_labels(None, None).await;
+    assert!(result.unwrap_err().is_network_error());
+}
+
+#[tokio::test]
+async fn test_with_timeout_overrides_client_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"results": [], "next_cursor": null}))
+                .set_delay(std::time::Duration::from_millis(500)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .timeout(std::time::Duration::from_millis(50))
+        .build();
+    assert!(todoist
+        .with_timeout(std::time::Duration::from_secs(5))
+        .get_labels(None, None)
+        .await
+        .is_ok());
+
+    // The original client keeps its own timeout
+    let result = todoist.get_labels(None, None).await;
+    assert!(result.unwrap_err().is_network_error());
+}
+
+#[tokio::test]
+async fn test_builder_user_agent() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(header(
+            "user-agent",
+            concat!("todoist-api-rs/", env!("CARGO_PKG_VERSION")),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(header("user-agent", "my-app/2.1 (me@example.com)"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    todoist.get_labels(None, None).await.unwrap();
+
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .user_agent("my-app/2.1 (me@example.com)")
+        .build();
+    todoist.get_labels(None, None).await.unwrap();
+
+    // Also applied on top of a caller-provided client
+    let todoist = TodoistWrapper::builder("test-token")
+        .client(reqwest::Client::new())
+        .base_url(mock_server.uri())
+        .user_agent("my-app/2.1 (me@example.com)")
+        .build();
+    todoist.get_labels(None, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_default_headers_sent_with_every_request() {
+    use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(header("x-internal-auth", "gateway-secret"))
+        .and(header("authorization", "Bearer test-token"))
+        .and(header(
+            "user-agent",
+            concat!("todoist-api-rs/", env!("CARGO_PKG_VERSION")),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The crate's own headers win over colliding defaults
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(header("x-internal-auth", "gateway-secret"))
+        .and(header("authorization", "Bearer test-token"))
+        .and(header("content-type", "application/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_1")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // A default X-Request-Id must not make Sync API batches look idempotent
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(header("x-internal-auth", "gateway-secret"))
+        .respond_with(|request: &Request| {
+            assert!(!request.headers.contains_key("x-request-id"));
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Internal-Auth", HeaderValue::from_static("gateway-secret"));
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer gateway-token"));
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+    headers.insert(USER_AGENT, HeaderValue::from_static("gateway-agent"));
+    headers.insert("X-Request-Id", HeaderValue::from_static("shared-id"));
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .default_headers(headers)
+        .build();
+
+    todoist.get_labels(None, None).await.unwrap();
+    let args = CreateTaskArgs {
+        content: "Task".to_string(),
+        ..Default::default()
+    };
+    todoist.create_task(&args).await.unwrap();
+    todoist.collapse_project("proj_1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_try_new_rejects_empty_token() {
+    for token in ["", "   "] {
+        let error = TodoistWrapper::try_new(token.to_string()).err().unwrap();
+        assert!(error.is_validation_error());
+        assert!(matches!(error, TodoistError::ValidationError { field: Some(ref field), .. } if field == "api_token"));
+    }
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .try_build()
+        .unwrap();
+    todoist.get_labels(None, None).await.unwrap();
+}
+
 // ===== PROJECT OPERATIONS =====
 
 #[tokio::test]
@@ -66,168 +362,171 @@ async fn test_get_projects_with_limit() {
 }
 
 #[tokio::test]
-async fn test_get_project() {
+async fn test_get_projects_filtered_with_limit_returns_page() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/projects/proj_123"))
+        .and(path("/projects"))
+        .and(query_param("limit", "1"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "proj_123",
-            "name": "Test Project",
-            "color": "blue",
-            "shared": false,
-            "is_favorite": false,
-            "is_inbox_project": false,
-            "view_style": "list"
+            "results": [
+                {
+                    "id": "proj_123",
+                    "name": "Test Project",
+                    "color": "blue",
+                    "shared": false,
+                    "is_favorite": false,
+                    "is_inbox_project": false,
+                    "view_style": "list"
+                }
+            ],
+            "next_cursor": "next_page"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_project("proj_123").await;
-    assert!(result.is_ok());
-    let project = result.unwrap();
-    assert_eq!(project.id, "proj_123");
-    assert_eq!(project.name, "Test Project");
+    let args = ProjectFilterArgs {
+        limit: Some(1),
+        cursor: None,
+    };
+    let page: Page<Project> = todoist.get_projects_filtered(&args).await.unwrap();
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.next_cursor, Some("next_page".to_string()));
 }
 
 #[tokio::test]
-async fn test_get_project_not_found() {
+async fn test_get_all_projects_follows_cursor() {
     let mock_server = MockServer::start().await;
 
+    let project = |id: &str| json!({"id": id, "name": format!("Project {}", id)});
+
     Mock::given(method("GET"))
-        .and(path("/projects/notexist"))
-        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
-            "error": "Project not found"
+        .and(path("/projects"))
+        .and(query_param("cursor", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [project("proj_2")],
+            "next_cursor": null
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
-    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
-
-    let result = todoist.get_project("notexist").await;
-    assert!(result.is_err());
-    match result {
-        Err(TodoistError::NotFound { .. }) => (),
-        _ => panic!("Expected NotFound error"),
-    }
-}
-
-#[tokio::test]
-async fn test_create_project() {
-    let mock_server = MockServer::start().await;
-
-    Mock::given(method("POST"))
+    Mock::given(method("GET"))
         .and(path("/projects"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "new_proj",
-            "name": "New Project",
-            "color": "red",
-            "shared": false,
-            "is_favorite": false,
-            "is_inbox_project": false,
-            "view_style": "list"
+            "results": [project("proj_1")],
+            "next_cursor": "page_2"
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = CreateProjectArgs {
-        name: "New Project".to_string(),
-        color: Some("red".to_string()),
-        ..Default::default()
-    };
-
-    let result = todoist.create_project(&args).await;
-    assert!(result.is_ok());
-    let project = result.unwrap();
-    assert_eq!(project.id, "new_proj");
-    assert_eq!(project.name, "New Project");
+    let projects = todoist.get_all_projects().await.unwrap();
+    let ids: Vec<&str> = projects.iter().map(|project| project.id.as_str()).collect();
+    assert_eq!(ids, ["proj_1", "proj_2"]);
 }
 
 #[tokio::test]
-async fn test_update_project() {
+async fn test_task_pages_resume_from_stored_cursor() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("POST"))
-        .and(path("/projects/proj_123"))
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .and(query_param("limit", "2"))
+        .and(query_param("cursor", "page_2"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "proj_123",
-            "name": "Updated Name",
-            "color": "green",
-            "shared": false,
-            "is_favorite": true,
-            "is_inbox_project": false,
-            "view_style": "list"
+            "results": [task_json("task_3")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("task_1"), task_json("task_2")],
+            "next_cursor": "page_2"
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = UpdateProjectArgs {
-        name: Some("Updated Name".to_string()),
-        color: Some("green".to_string()),
+    let mut args = TaskFilterArgs {
+        project_id: Some("proj_1".to_string()),
+        limit: Some(2),
         ..Default::default()
     };
-
-    let result = todoist.update_project("proj_123", &args).await;
-    assert!(result.is_ok());
-    let project = result.unwrap();
-    assert_eq!(project.name, "Updated Name");
-    assert_eq!(project.color, "green");
+    let mut pages = todoist.task_pages(&args).unwrap();
+    let first = pages.next_page().await.unwrap().unwrap();
+    assert_eq!(first.len(), 2);
+    assert!(!pages.is_done());
+
+    // Stop here and resume later from the stored cursor
+    args.cursor = pages.cursor().map(str::to_string);
+    assert_eq!(args.cursor.as_deref(), Some("page_2"));
+
+    let mut pages = todoist.task_pages(&args).unwrap();
+    let second = pages.next_page().await.unwrap().unwrap();
+    assert_eq!(second[0].id, "task_3");
+    assert!(pages.is_done());
+    assert_eq!(pages.cursor(), None);
+    assert!(pages.next_page().await.unwrap().is_none());
+
+    args.query = "today".to_string();
+    assert!(todoist.task_pages(&args).is_err());
 }
 
 #[tokio::test]
-async fn test_update_project_no_fields() {
-    let todoist = TodoistWrapper::new("test-token".to_string());
-    let args = UpdateProjectArgs::default();
+async fn test_get_inbox_project_stops_at_the_inbox() {
+    let mock_server = MockServer::start().await;
 
-    let result = todoist.update_project("proj_123", &args).await;
-    assert!(result.is_err());
-    match result {
-        Err(TodoistError::ValidationError { .. }) => (),
-        _ => panic!("Expected ValidationError"),
-    }
-}
+    let project = |id: &str, inbox: bool| json!({"id": id, "name": id, "is_inbox_project": inbox});
 
-#[tokio::test]
-async fn test_delete_project() {
-    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/projects"))
+        .and(query_param("cursor", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [project("proj_2", true), project("proj_3", false)],
+            "next_cursor": "page_3"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
-    Mock::given(method("DELETE"))
-        .and(path("/projects/proj_123"))
-        .respond_with(ResponseTemplate::new(204))
+    Mock::given(method("GET"))
+        .and(path("/projects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [project("proj_1", false)],
+            "next_cursor": "page_2"
+        })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.delete_project("proj_123").await;
-    assert!(result.is_ok());
+    let inbox = todoist.get_inbox_project().await.unwrap();
+    assert_eq!(inbox.id, "proj_2");
 }
 
 #[tokio::test]
-async fn test_get_projects_filtered() {
+async fn test_get_inbox_project_not_found() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
         .and(path("/projects"))
-        .and(query_param("limit", "5"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "proj_1",
-                    "name": "Project 1",
-                    "color": "blue",
-                    "shared": false,
-                    "is_favorite": false,
-                    "is_inbox_project": false,
-                    "view_style": "list"
-                }
-            ],
+            "results": [{"id": "proj_1", "name": "Work"}],
             "next_cursor": null
         })))
         .mount(&mock_server)
@@ -235,286 +534,3367 @@ async fn test_get_projects_filtered() {
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = ProjectFilterArgs {
-        limit: Some(5),
-        cursor: None,
-    };
+    let error = todoist.get_inbox_project().await.unwrap_err();
+    assert!(error.is_not_found());
+}
 
-    let result = todoist.get_projects_filtered(&args).await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].id, "proj_1");
-    assert!(response.next_cursor.is_none());
+#[tokio::test]
+async fn test_get_all_labels_stops_on_stuck_cursor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{"id": "label_1", "name": "work"}],
+            "next_cursor": "same"
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let error = todoist.get_all_labels().await.unwrap_err();
+    assert!(error.to_string().contains("did not advance"));
 }
 
-// ===== TASK OPERATIONS =====
+#[tokio::test]
+async fn test_get_labels_bare_array_response() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"id": "label_1", "name": "work", "color": "red", "order": 1, "is_favorite": false}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let page = todoist.get_labels(None, None).await.unwrap();
+    assert_eq!(page.results.len(), 1);
+    assert!(page.next_cursor.is_none());
+}
 
 #[tokio::test]
-async fn test_get_tasks() {
+async fn test_get_project() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/tasks"))
+        .and(path("/projects/proj_123"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "task_123",
-                    "creator_id": "user_1",
-                    "content": "Test Task",
-                    "description": "Test description",
-                    "project_id": "proj_1",
-                    "section_id": null,
-                    "parent_id": null,
-                    "added_by_uid": null,
-                    "assigned_by_uid": null,
-                    "responsible_uid": null,
-                    "labels": [],
-                    "deadline": null,
-                    "duration": null,
-                    "added_at": "2024-01-01T00:00:00Z",
-                    "updated_at": null,
-                    "due": null,
-                    "priority":1,
-                    "child_order": 0,
-                    "note_count": 0,
-                    "day_order": 0,
-                    "is_collapsed": false
-                }
-            ],
-            "next_cursor": "cursor123"
+            "id": "proj_123",
+            "name": "Test Project",
+            "color": "blue",
+            "shared": false,
+            "is_favorite": false,
+            "is_inbox_project": false,
+            "view_style": "list"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_tasks(None, None).await;
+    let result = todoist.get_project("proj_123").await;
     assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].id, "task_123");
-    assert_eq!(response.next_cursor, Some("cursor123".to_string()));
+    let project = result.unwrap();
+    assert_eq!(project.id, "proj_123");
+    assert_eq!(project.name, "Test Project");
 }
 
 #[tokio::test]
-async fn test_get_task() {
+async fn test_get_project_not_found() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/tasks/task_123"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "task_123",
-            "creator_id": "user_1",
-            "content": "Single Task",
-            "description": "Description",
-            "project_id": "proj_1",
-            "section_id": null,
-            "parent_id": null,
-            "added_by_uid": null,
-            "assigned_by_uid": null,
-            "responsible_uid": null,
-            "labels": [],
-            "deadline": null,
-            "duration": null,
-            "added_at": "2024-01-01T00:00:00Z",
-            "updated_at": null,
-            "due": null,
-            "priority": 2,
-            "child_order": 0,
-            "note_count": 0,
-            "day_order": 0,
-            "is_collapsed": false
+        .and(path("/projects/notexist"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Project not found"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_task("task_123").await;
-    assert!(result.is_ok());
-    let task = result.unwrap();
-    assert_eq!(task.id, "task_123");
-    assert_eq!(task.content, "Single Task");
+    let result = todoist.get_project("notexist").await;
+    assert!(result.is_err());
+    match result {
+        Err(TodoistError::NotFound {
+            resource_type,
+            resource_id,
+            ..
+        }) => {
+            assert_eq!(resource_type, "Project");
+            assert_eq!(resource_id.as_deref(), Some("notexist"));
+        }
+        _ => panic!("Expected NotFound error"),
+    }
 }
 
 #[tokio::test]
-async fn test_create_task() {
+async fn test_task_action_not_found_names_task() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/tasks"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "new_task",
-            "creator_id": "user_1",
-            "content": "New Task",
-            "description": "New Description",
-            "project_id": "proj_1",
-            "section_id": null,
-            "parent_id": null,
-            "added_by_uid": null,
-            "assigned_by_uid": null,
-            "responsible_uid": null,
-            "labels": ["important"],
-            "deadline": null,
-            "duration": null,
-            "added_at": "2024-01-01T00:00:00Z",
-            "updated_at": null,
-            "due": null,
-            "priority": 3,
-            "child_order": 0,
-            "note_count": 0,
-            "day_order": 0,
-            "is_collapsed": false
+        .and(path("/tasks/missing/close"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Task not found"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = CreateTaskArgs {
-        content: "New Task".to_string(),
-        description: Some("New Description".to_string()),
-        project_id: Some("proj_1".to_string()),
-        priority: Some(3),
-        labels: Some(vec!["important".to_string()]),
-        ..Default::default()
-    };
-
-    let result = todoist.create_task(&args).await;
-    assert!(result.is_ok());
-    let task = result.unwrap();
-    assert_eq!(task.id, "new_task");
-    assert_eq!(task.content, "New Task");
-    assert_eq!(task.priority, 3);
+    let error = todoist.complete_task("missing").await.unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.to_string(), "Task not found (ID: missing): Task not found");
 }
 
 #[tokio::test]
-async fn test_update_task() {
+async fn test_create_project() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/tasks/task_123"))
+        .and(path("/projects"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "task_123",
-            "creator_id": "user_1",
-            "content": "Updated Task",
-            "description": "Updated Description",
-            "project_id": "proj_1",
-            "section_id": null,
-            "parent_id": null,
-            "added_by_uid": null,
-            "assigned_by_uid": null,
-            "responsible_uid": null,
-            "labels": [],
-            "deadline": null,
-            "duration": null,
-            "added_at": "2024-01-01T00:00:00Z",
-            "updated_at": "2024-01-02T00:00:00Z",
-            "due": null,
-            "priority": 4,
-            "child_order": 0,
-            "note_count": 0,
-            "day_order": 0,
-            "is_collapsed": false
+            "id": "new_proj",
+            "name": "New Project",
+            "color": "red",
+            "shared": false,
+            "is_favorite": false,
+            "is_inbox_project": false,
+            "view_style": "list"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = UpdateTaskArgs {
-        content: Some("Updated Task".to_string()),
-        description: Some("Updated Description".to_string()),
-        priority: Some(4),
+    let args = CreateProjectArgs {
+        name: "New Project".to_string(),
+        color: Some(Color::Red),
         ..Default::default()
     };
 
-    let result = todoist.update_task("task_123", &args).await;
+    let result = todoist.create_project(&args).await;
     assert!(result.is_ok());
-    let task = result.unwrap();
-    assert_eq!(task.content, "Updated Task");
-    assert_eq!(task.priority, 4);
+    let project = result.unwrap();
+    assert_eq!(project.id, "new_proj");
+    assert_eq!(project.name, "New Project");
 }
 
 #[tokio::test]
-async fn test_complete_task() {
+async fn test_create_project_with_child_order() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/tasks/task_123/close"))
-        .respond_with(ResponseTemplate::new(204))
+        .and(path("/projects"))
+        .respond_with(|request: &Request| {
+            let body: Value = serde_json::from_slice(&request.body).unwrap();
+            assert_eq!(body, json!({"name": "Template B"}));
+            ResponseTemplate::new(200).set_body_json(json!({
+                "id": "proj_b",
+                "name": "Template B",
+                "child_order": 7
+            }))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands[0]["type"], "project_reorder");
+            assert_eq!(
+                commands[0]["args"],
+                json!({"projects": [{"id": "proj_b", "child_order": 2}]})
+            );
+            sync_ok_response(request)
+        })
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.complete_task("task_123").await;
-    assert!(result.is_ok());
+    let args = CreateProjectArgs {
+        name: "Template B".to_string(),
+        child_order: Some(2),
+        ..Default::default()
+    };
+    let project = todoist.create_project(&args).await.unwrap();
+    assert_eq!(project.child_order, 2);
 }
 
 #[tokio::test]
-async fn test_reopen_task() {
+async fn test_create_project_returns_project_when_reorder_fails() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/tasks/task_123/reopen"))
-        .respond_with(ResponseTemplate::new(204))
+        .and(path("/projects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "proj_b",
+            "name": "Template B",
+            "child_order": 7
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.reopen_task("task_123").await;
-    assert!(result.is_ok());
+    let args = CreateProjectArgs {
+        name: "Template B".to_string(),
+        child_order: Some(2),
+        ..Default::default()
+    };
+    let project = todoist.create_project(&args).await.unwrap();
+    assert_eq!(project.id, "proj_b");
+    // The order the API assigned is kept, showing the move did not happen
+    assert_eq!(project.child_order, 7);
 }
 
 #[tokio::test]
-async fn test_delete_task() {
+async fn test_set_project_collapsed() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("DELETE"))
-        .and(path("/tasks/task_123"))
-        .respond_with(ResponseTemplate::new(204))
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("project_update"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands[0]["args"], json!({"id": "proj_1", "collapsed": false}));
+            sync_ok_response(request)
+        })
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.delete_task("task_123").await;
-    assert!(result.is_ok());
+    todoist.expand_project("proj_1").await.unwrap();
 }
 
 #[tokio::test]
-async fn test_get_tasks_for_project() {
+async fn test_apply_template() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("GET"))
-        .and(path("/tasks"))
-        .and(query_param("project_id", "proj_123"))
+    Mock::given(method("POST"))
+        .and(path("/projects"))
+        .and(body_json(json!({"name": "Weekly Review"})))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "task_1",
-                    "creator_id": "user_1",
-                    "content": "Project Task",
-                    "description": "",
-                    "project_id": "proj_123",
-                    "section_id": null,
-                    "parent_id": null,
-                    "added_by_uid": null,
-                    "assigned_by_uid": null,
-                    "responsible_uid": null,
-                    "labels": [],
-                    "deadline": null,
-                    "duration": null,
-                    "added_at": "2024-01-01T00:00:00Z",
+            "id": "proj_2",
+            "name": "Weekly Review",
+            "color": "charcoal",
+            "shared": false,
+            "is_favorite": false,
+            "is_inbox_project": false,
+            "view_style": "list"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sections"))
+        .and(body_json(json!({"name": "Plan", "project_id": "proj_2"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sec_2",
+            "user_id": "user_1",
+            "project_id": "proj_2",
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": null,
+            "archived_at": null,
+            "name": "Plan",
+            "section_order": 1,
+            "is_archived": false,
+            "is_collapsed": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(|request: &Request| {
+            let body: Value = serde_json::from_slice(&request.body).unwrap();
+            let content = body["content"].as_str().unwrap();
+            ResponseTemplate::new(200).set_body_json(task_json(&format!("id_{content}")))
+        })
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let template = ProjectTemplate {
+        project: CreateProjectArgs {
+            name: "Weekly Review".to_string(),
+            ..Default::default()
+        },
+        tasks: vec![CreateTaskArgs::builder("inbox").build().into()],
+        sections: vec![SectionTemplate {
+            name: "Plan".to_string(),
+            tasks: vec![
+                TaskTemplate {
+                    task: CreateTaskArgs::builder("pick").project_id("ignored").build(),
+                    subtasks: vec![CreateTaskArgs::builder("calendar").build().into()],
+                },
+                CreateTaskArgs::builder("share").build().into(),
+            ],
+        }],
+    };
+    let project = todoist.apply_template(&template).await.unwrap();
+    assert_eq!(project.id, "proj_2");
+
+    let bodies: Vec<Value> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.url.path() == "/tasks")
+        .map(|request| serde_json::from_slice(&request.body).unwrap())
+        .collect();
+    assert_eq!(
+        bodies,
+        [
+            json!({"content": "inbox", "project_id": "proj_2"}),
+            json!({"content": "pick", "project_id": "proj_2", "section_id": "sec_2"}),
+            json!({"content": "calendar", "parent_id": "id_pick"}),
+            json!({"content": "share", "project_id": "proj_2", "section_id": "sec_2"}),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_update_project() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/proj_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "proj_123",
+            "name": "Updated Name",
+            "color": "green",
+            "shared": false,
+            "is_favorite": true,
+            "is_inbox_project": false,
+            "view_style": "list"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = UpdateProjectArgs {
+        name: Some("Updated Name".to_string()),
+        color: Some(Color::Green),
+        ..Default::default()
+    };
+
+    let result = todoist.update_project("proj_123", &args).await;
+    assert!(result.is_ok());
+    let project = result.unwrap();
+    assert_eq!(project.name, "Updated Name");
+    assert_eq!(project.color, Color::Green);
+}
+
+#[tokio::test]
+async fn test_create_and_update_project_board_view_style() {
+    let mock_server = MockServer::start().await;
+
+    let project = |name: &str, view_style: &str| {
+        json!({
+            "id": "proj_1",
+            "name": name,
+            "color": "teal",
+            "shared": false,
+            "is_favorite": false,
+            "is_inbox_project": false,
+            "view_style": view_style
+        })
+    };
+    Mock::given(method("POST"))
+        .and(path("/projects"))
+        .and(body_json(
+            json!({"name": "Sprint", "color": "teal", "view_style": "board"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(project("Sprint", "board")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // Renaming sends the name alone, leaving the color and view style untouched
+    Mock::given(method("POST"))
+        .and(path("/projects/proj_1"))
+        .and(body_json(json!({"name": "Sprint 2"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(project("Sprint 2", "board")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateProjectArgs {
+        name: "Sprint".to_string(),
+        color: Some(Color::Teal),
+        view_style: Some(ViewStyle::Board),
+        ..Default::default()
+    };
+    let created = todoist.create_project(&args).await.unwrap();
+    assert_eq!(created.view_style, ViewStyle::Board);
+
+    let args = UpdateProjectArgs {
+        name: Some("Sprint 2".to_string()),
+        ..Default::default()
+    };
+    let updated = todoist.update_project("proj_1", &args).await.unwrap();
+    assert_eq!(updated.view_style, ViewStyle::Board);
+    assert_eq!(updated.color, Color::Teal);
+}
+
+#[tokio::test]
+async fn test_update_project_no_fields() {
+    let todoist = TodoistWrapper::new("test-token".to_string());
+    let args = UpdateProjectArgs::default();
+
+    let result = todoist.update_project("proj_123", &args).await;
+    assert!(result.is_err());
+    match result {
+        Err(TodoistError::ValidationError { .. }) => (),
+        _ => panic!("Expected ValidationError"),
+    }
+}
+
+#[tokio::test]
+async fn test_update_without_fields_sends_no_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let errors = [
+        todoist
+            .update_task("task_123", &UpdateTaskArgs::default())
+            .await
+            .unwrap_err(),
+        todoist
+            .update_project("proj_123", &UpdateProjectArgs::default())
+            .await
+            .unwrap_err(),
+        todoist
+            .update_label("label_123", &UpdateLabelArgs::default())
+            .await
+            .unwrap_err(),
+    ];
+
+    for error in errors {
+        match error {
+            TodoistError::ValidationError { field, message, .. } => {
+                assert_eq!(field, None);
+                assert_eq!(message, "No fields specified for update");
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_delete_project() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/projects/proj_123"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.delete_project("proj_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_project_with_report_cleans_up_leftovers() {
+    let mock_server = MockServer::start().await;
+
+    let section = |id: &str| {
+        json!({"id": id, "user_id": "user_1", "project_id": "proj_1", "name": id,
+               "added_at": "2024-01-01T00:00:00Z", "updated_at": null, "archived_at": null})
+    };
+
+    // Before the delete: two sections and a task; afterwards the cascade missed two sections
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [section("sec_1"), section("sec_2"), section("sec_3")],
+            "next_cursor": null
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [section("sec_2"), section("sec_3")],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("task_1")],
+            "next_cursor": null
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/projects/proj_1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/sections/sec_2"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/sections/sec_3"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({"error": "Forbidden"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let report = todoist.delete_project_with_report("proj_1").await.unwrap();
+    assert!(!report.is_complete());
+    assert_eq!(report.deleted_sections, ["sec_1", "sec_2"]);
+    assert_eq!(report.deleted_tasks, ["task_1"]);
+    assert_eq!(report.remaining_sections.len(), 1);
+    assert_eq!(report.remaining_sections[0].0, "sec_3");
+    assert!(report.remaining_sections[0].1.is_authorization_error());
+    assert!(report.remaining_tasks.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_projects_filtered() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects"))
+        .and(query_param("limit", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "proj_1",
+                    "name": "Project 1",
+                    "color": "blue",
+                    "shared": false,
+                    "is_favorite": false,
+                    "is_inbox_project": false,
+                    "view_style": "list"
+                }
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = ProjectFilterArgs {
+        limit: Some(5),
+        cursor: None,
+    };
+
+    let result = todoist.get_projects_filtered(&args).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].id, "proj_1");
+    assert!(response.next_cursor.is_none());
+}
+
+// ===== TASK OPERATIONS =====
+
+#[tokio::test]
+async fn test_get_tasks() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "task_123",
+                    "creator_id": "user_1",
+                    "content": "Test Task",
+                    "description": "Test description",
+                    "project_id": "proj_1",
+                    "section_id": null,
+                    "parent_id": null,
+                    "added_by_uid": null,
+                    "assigned_by_uid": null,
+                    "responsible_uid": null,
+                    "labels": [],
+                    "deadline": null,
+                    "duration": null,
+                    "added_at": "2024-01-01T00:00:00Z",
+                    "updated_at": null,
+                    "due": null,
+                    "priority":1,
+                    "child_order": 0,
+                    "note_count": 0,
+                    "day_order": 0,
+                    "is_collapsed": false
+                }
+            ],
+            "next_cursor": "cursor123"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_tasks(None, None).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].id, "task_123");
+    assert_eq!(response.next_cursor, Some("cursor123".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "task_123",
+            "creator_id": "user_1",
+            "content": "Single Task",
+            "description": "Description",
+            "project_id": "proj_1",
+            "section_id": null,
+            "parent_id": null,
+            "added_by_uid": null,
+            "assigned_by_uid": null,
+            "responsible_uid": null,
+            "labels": [],
+            "deadline": null,
+            "duration": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": null,
+            "due": null,
+            "priority": 2,
+            "child_order": 0,
+            "note_count": 0,
+            "day_order": 0,
+            "is_collapsed": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_task("task_123").await;
+    assert!(result.is_ok());
+    let task = result.unwrap();
+    assert_eq!(task.id, "task_123");
+    assert_eq!(task.content, "Single Task");
+
+    // IDs can also be passed as `&String` or `String`
+    assert!(todoist.get_task(&task.id).await.is_ok());
+    assert!(todoist.get_task(task.id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "new_task",
+            "creator_id": "user_1",
+            "content": "New Task",
+            "description": "New Description",
+            "project_id": "proj_1",
+            "section_id": null,
+            "parent_id": null,
+            "added_by_uid": null,
+            "assigned_by_uid": null,
+            "responsible_uid": null,
+            "labels": ["important"],
+            "deadline": null,
+            "duration": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": null,
+            "due": null,
+            "priority": 3,
+            "child_order": 0,
+            "note_count": 0,
+            "day_order": 0,
+            "is_collapsed": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateTaskArgs {
+        content: "New Task".to_string(),
+        description: Some("New Description".to_string()),
+        project_id: Some("proj_1".to_string()),
+        priority: Some(Priority::High),
+        labels: Some(vec!["important".to_string()]),
+        ..Default::default()
+    };
+
+    let result = todoist.create_task(&args).await;
+    assert!(result.is_ok());
+    let task = result.unwrap();
+    assert_eq!(task.id, "new_task");
+    assert_eq!(task.content, "New Task");
+    assert_eq!(task.priority, Priority::High);
+}
+
+#[tokio::test]
+async fn test_task_deadline_round_trip() {
+    let mock_server = MockServer::start().await;
+
+    // Echo the requested deadline back the way the API reports it
+    let respond = |request: &Request| {
+        let body: Value = serde_json::from_slice(&request.body).unwrap();
+        ResponseTemplate::new(200).set_body_json(json!({
+            "id": "task_1",
+            "user_id": "user_1",
+            "content": "Ship release",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "priority": 1,
+            "deadline": {"date": body["deadline_date"], "lang": "en"}
+        }))
+    };
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(body_string_contains(r#""deadline_date":"2024-03-31""#))
+        .respond_with(respond)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_1"))
+        .and(body_json(json!({"deadline_date": "2024-04-15"})))
+        .respond_with(respond)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateTaskArgs::builder("Ship release")
+        .deadline_date("2024-03-31")
+        .build();
+    let task = todoist.create_task(&args).await.unwrap();
+    assert_eq!(task.deadline.unwrap().date.to_string(), "2024-03-31");
+
+    let args = UpdateTaskArgs {
+        deadline_date: Some(Some("2024-04-15".to_string())),
+        ..Default::default()
+    };
+    let task = todoist.update_task("task_1", &args).await.unwrap();
+    let deadline = task.deadline.unwrap();
+    assert_eq!(deadline.date.to_string(), "2024-04-15");
+    assert_eq!(deadline.lang, Some(Lang::English));
+}
+
+#[tokio::test]
+async fn test_duplicate_task_with_subtasks() {
+    let mock_server = MockServer::start().await;
+
+    let task = |id: &str, parent_id: Option<&str>, child_order: i32| {
+        let mut task = task_json(id);
+        task["content"] = json!(id);
+        task["parent_id"] = json!(parent_id);
+        task["child_order"] = json!(child_order);
+        task
+    };
+    let mut source = task("parent", None, 0);
+    source["section_id"] = json!("sec_1");
+    source["labels"] = json!(["errand"]);
+    source["priority"] = json!(4);
+    source["due"] = json!({
+        "date": "2024-01-22",
+        "string": "every monday",
+        "lang": "en",
+        "is_recurring": true,
+        "datetime": null,
+        "timezone": null
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/parent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(source.clone()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                source,
+                task("child_b", Some("parent"), 2),
+                task("child_a", Some("parent"), 1),
+                task("grandchild", Some("child_a"), 0),
+                task("other", None, 1)
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(|request: &Request| {
+            let body: Value = serde_json::from_slice(&request.body).unwrap();
+            let content = body["content"].as_str().unwrap();
+            ResponseTemplate::new(200).set_body_json(task_json(&format!("copy_of_{content}")))
+        })
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let options = DuplicateOptions { include_subtasks: true };
+    let copy = todoist
+        .duplicate_task_with_options("parent", Some("proj_2"), &options)
+        .await
+        .unwrap();
+    assert_eq!(copy.id, "copy_of_parent");
+
+    let bodies: Vec<Value> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.method.as_str() == "POST")
+        .map(|request| serde_json::from_slice(&request.body).unwrap())
+        .collect();
+    // Moved to another project: the section is dropped and the recurrence kept
+    assert_eq!(
+        bodies[0],
+        json!({
+            "content": "parent",
+            "project_id": "proj_2",
+            "priority": 4,
+            "labels": ["errand"],
+            "due_string": "every monday",
+            "due_lang": "en"
+        })
+    );
+    let placed: Vec<(&str, &str)> = bodies[1..]
+        .iter()
+        .map(|body| (body["content"].as_str().unwrap(), body["parent_id"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        placed,
+        [
+            ("child_a", "copy_of_parent"),
+            ("child_b", "copy_of_parent"),
+            ("grandchild", "copy_of_child_a")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_duplicate_task_in_place() {
+    let mock_server = MockServer::start().await;
+
+    let mut source = task_json("task_1");
+    source["section_id"] = json!("sec_1");
+    source["description"] = json!("Details");
+    source["due"] = json!({
+        "date": "2024-01-18",
+        "string": "Jan 18 at 2pm",
+        "is_recurring": false,
+        "datetime": "2024-01-18T13:00:00Z",
+        "timezone": "Europe/Paris"
+    });
+    source["duration"] = json!({"amount": 30, "unit": "minute"});
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(source))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(body_json(json!({
+            "content": "Task",
+            "description": "Details",
+            "project_id": "proj_1",
+            "section_id": "sec_1",
+            "priority": 1,
+            "labels": [],
+            "due_datetime": "2024-01-18T13:00:00Z",
+            "duration": 30,
+            "duration_unit": "minute"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_2")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let copy = todoist.duplicate_task("task_1", None).await.unwrap();
+    assert_eq!(copy.id, "task_2");
+}
+
+#[tokio::test]
+async fn test_update_task_clear_labels() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_1"))
+        .and(body_json(json!({"labels": []})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_1")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let task = todoist
+        .update_task("task_1", &UpdateTaskArgs::clear_labels())
+        .await
+        .unwrap();
+    assert!(task.labels.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "task_123",
+            "creator_id": "user_1",
+            "content": "Updated Task",
+            "description": "Updated Description",
+            "project_id": "proj_1",
+            "section_id": null,
+            "parent_id": null,
+            "added_by_uid": null,
+            "assigned_by_uid": null,
+            "responsible_uid": null,
+            "labels": [],
+            "deadline": null,
+            "duration": null,
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "due": null,
+            "priority": 4,
+            "child_order": 0,
+            "note_count": 0,
+            "day_order": 0,
+            "is_collapsed": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = UpdateTaskArgs {
+        content: Some("Updated Task".to_string()),
+        description: Some("Updated Description".to_string()),
+        priority: Some(Priority::Urgent),
+        ..Default::default()
+    };
+
+    let result = todoist.update_task("task_123", &args).await;
+    assert!(result.is_ok());
+    let task = result.unwrap();
+    assert_eq!(task.content, "Updated Task");
+    assert_eq!(task.priority, Priority::Urgent);
+}
+
+#[tokio::test]
+async fn test_move_task() {
+    let mock_server = MockServer::start().await;
+
+    let mut moved = task_json("task_123");
+    moved["section_id"] = json!("section_456");
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/move"))
+        .and(body_json(json!({"section_id": "section_456"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(moved))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = MoveTaskArgs {
+        section_id: Some("section_456".to_string()),
+        ..Default::default()
+    };
+    let task = todoist.move_task("task_123", &args).await.unwrap();
+    assert_eq!(task.section_id, Some("section_456".to_string()));
+}
+
+#[tokio::test]
+async fn test_move_task_requires_single_destination() {
+    let todoist = TodoistWrapper::new("test-token".to_string());
+
+    let result = todoist.move_task("task_123", &MoveTaskArgs::default()).await;
+    assert!(matches!(result, Err(TodoistError::ValidationError { .. })));
+
+    let args = MoveTaskArgs {
+        project_id: Some("proj_1".to_string()),
+        parent_id: Some("task_456".to_string()),
+        ..Default::default()
+    };
+    let result = todoist.move_task("task_123", &args).await;
+    assert!(matches!(result, Err(TodoistError::ValidationError { .. })));
+}
+
+#[tokio::test]
+async fn test_validate_token() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .and(header("Authorization", "Bearer good-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "user_1"})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("good-token".to_string(), mock_server.uri());
+    assert!(todoist.validate_token().await.unwrap());
+
+    let todoist = TodoistWrapper::with_base_url("bad-token".to_string(), mock_server.uri());
+    assert!(!todoist.validate_token().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_validate_token_propagates_other_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    assert!(todoist.validate_token().await.unwrap_err().is_server_error());
+}
+
+#[tokio::test]
+async fn test_get_user() {
+    let mock_server = MockServer::start().await;
+
+    let examples: Value = serde_json::from_str(include_str!("fixtures/api_examples.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&examples["user"]["example"]))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let user = todoist.get_user().await.unwrap();
+    assert_eq!(user.id, "12345678");
+    assert_eq!(user.name, "John Doe");
+    assert_eq!(user.email, "user@example.com");
+    assert!(user.is_premium);
+    assert!(!user.is_business_account);
+    assert_eq!(
+        user.avatar_url.as_deref(),
+        Some("https://dcff1xvirvpfp.cloudfront.net/avatar_big.jpg")
+    );
+    assert_eq!(user.timezone(), Some("Europe/Paris"));
+    assert_eq!(user.tz_info.unwrap().gmt_string, "+01:00");
+}
+
+/// Respond to a task creation with a task echoing the requested content,
+/// delaying earlier tasks more so responses complete out of order
+fn created_task_response(request: &Request) -> ResponseTemplate {
+    let body: Value = serde_json::from_slice(&request.body).unwrap();
+    let content = body["content"].as_str().unwrap().to_string();
+    let index: u64 = content.trim_start_matches("Task ").parse().unwrap();
+
+    let mut task = task_json(&format!("task_{index}"));
+    task["content"] = json!(content);
+    ResponseTemplate::new(200)
+        .set_body_json(task)
+        .set_delay(std::time::Duration::from_millis(50 - index * 10))
+}
+
+#[tokio::test]
+async fn test_create_tasks_preserves_input_order() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(body_string_contains("Task 3"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({"error": "Invalid content"})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(created_task_response)
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args: Vec<CreateTaskArgs> = (0..5)
+        .map(|i| CreateTaskArgs::builder(format!("Task {i}")).build())
+        .collect();
+    let results = todoist.create_tasks_with_concurrency(&args, 2).await;
+
+    assert_eq!(results.len(), 5);
+    for (i, result) in results.iter().enumerate() {
+        if i == 3 {
+            assert!(result.as_ref().unwrap_err().is_validation_error());
+        } else {
+            assert_eq!(result.as_ref().unwrap().content, format!("Task {i}"));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_create_tasks_retries_rate_limited_requests() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(created_task_response)
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    // No retry policy on the client: the batch falls back to the default one
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args: Vec<CreateTaskArgs> = (1..5)
+        .map(|i| CreateTaskArgs::builder(format!("Task {i}")).build())
+        .collect();
+    let results = todoist.create_tasks(&args).await;
+
+    let contents: Vec<String> = results.into_iter().map(|result| result.unwrap().content).collect();
+    assert_eq!(contents, ["Task 1", "Task 2", "Task 3", "Task 4"]);
+}
+
+#[tokio::test]
+async fn test_create_tasks_retries_server_errors_with_same_request_id() {
+    let mock_server = MockServer::start().await;
+    let failed_id = std::sync::Arc::new(std::sync::Mutex::new(None::<String>));
+
+    let recorded = failed_id.clone();
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(body_string_contains("Task 1"))
+        .respond_with(move |request: &Request| {
+            let request_id = request.headers["x-request-id"].to_str().unwrap().to_string();
+            *recorded.lock().unwrap() = Some(request_id);
+            ResponseTemplate::new(503)
+        })
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let expected = failed_id.clone();
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(move |request: &Request| {
+            if String::from_utf8_lossy(&request.body).contains("Task 1") {
+                let request_id = request.headers["x-request-id"].to_str().unwrap();
+                assert_eq!(expected.lock().unwrap().as_deref(), Some(request_id));
+            }
+            created_task_response(request)
+        })
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        base_delay: std::time::Duration::from_millis(1),
+        ..Default::default()
+    });
+
+    let args: Vec<CreateTaskArgs> = (1..3)
+        .map(|i| CreateTaskArgs::builder(format!("Task {i}")).build())
+        .collect();
+    let results = todoist.create_tasks(&args).await;
+
+    let contents: Vec<String> = results.into_iter().map(|result| result.unwrap().content).collect();
+    assert_eq!(contents, ["Task 1", "Task 2"]);
+}
+
+#[tokio::test]
+async fn test_quick_add_task() {
+    let mock_server = MockServer::start().await;
+
+    let mut parsed = task_json("task_123");
+    parsed["content"] = json!("Buy milk");
+    parsed["project_id"] = json!("groceries");
+    parsed["labels"] = json!(["errand"]);
+    parsed["priority"] = json!(4);
+    parsed["due"] = json!({
+        "string": "tomorrow at 5pm",
+        "date": "2024-01-16",
+        "is_recurring": false,
+        "datetime": "2024-01-16T17:00:00",
+        "timezone": null,
+        "lang": "en"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .and(body_json(
+            json!({"text": "Buy milk tomorrow at 5pm #Groceries p1 @errand"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(parsed))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let task = todoist
+        .quick_add_task("Buy milk tomorrow at 5pm #Groceries p1 @errand")
+        .await
+        .unwrap();
+    assert_eq!(task.content, "Buy milk");
+    assert_eq!(task.project_id, "groceries");
+    assert_eq!(task.labels, vec!["errand".to_string()]);
+    assert_eq!(task.priority, Priority::Urgent);
+    assert_eq!(task.due.unwrap().string, "tomorrow at 5pm");
+}
+
+#[tokio::test]
+async fn test_task_defaults_fill_unset_fields() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(body_json(json!({
+            "content": "Defaults",
+            "project_id": "proj_default",
+            "labels": ["inbox-zero"],
+            "priority": 3
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_1")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .and(body_json(json!({
+            "content": "Explicit",
+            "section_id": "sec_1",
+            "labels": [],
+            "priority": 4
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_2")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let quick = task_json("task_3");
+    let mut moved = quick.clone();
+    moved["project_id"] = json!("proj_default");
+    let mut updated = moved.clone();
+    updated["labels"] = json!(["inbox-zero"]);
+    updated["priority"] = json!(3);
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .and(body_json(json!({"text": "Call mom tomorrow"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quick))
+        .mount(&mock_server)
+        .await;
+    let mut lowest = task_json("task_4");
+    lowest["project_id"] = json!("proj_default");
+    lowest["labels"] = json!(["someday"]);
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .and(body_json(json!({"text": "Read a book p4 @someday"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(lowest))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_4"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_3/move"))
+        .and(body_json(json!({"project_id": "proj_default"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(moved))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_3"))
+        .and(body_json(json!({"labels": ["inbox-zero"], "priority": 3})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(updated))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist =
+        TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_defaults(TaskDefaults {
+            priority: Some(Priority::High),
+            labels: Some(vec!["inbox-zero".to_string()]),
+            project_id: Some("proj_default".to_string()),
+        });
+
+    let args = CreateTaskArgs {
+        content: "Defaults".to_string(),
+        ..Default::default()
+    };
+    todoist.create_task(&args).await.unwrap();
+    let explicit = CreateTaskArgs {
+        content: "Explicit".to_string(),
+        section_id: Some("sec_1".to_string()),
+        labels: Some(vec![]),
+        priority: Some(Priority::Urgent),
+        ..Default::default()
+    };
+    todoist.create_task(&explicit).await.unwrap();
+
+    let task = todoist.quick_add_task("Call mom tomorrow").await.unwrap();
+    assert_eq!(task.project_id, "proj_default");
+    assert_eq!(task.labels, vec!["inbox-zero".to_string()]);
+    assert_eq!(task.priority, Priority::High);
+
+    let task = todoist.quick_add_task("Read a book p4 @someday").await.unwrap();
+    assert_eq!(task.priority, Priority::Normal);
+}
+
+#[tokio::test]
+async fn test_quick_add_task_returns_task_when_defaults_fail() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/quick"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_1")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_1/move"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_1"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist =
+        TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_defaults(TaskDefaults {
+            priority: Some(Priority::High),
+            labels: None,
+            project_id: Some("proj_default".to_string()),
+        });
+
+    let task = todoist.quick_add_task("Call mom tomorrow").await.unwrap();
+    assert_eq!(task.id, "task_1");
+    // The task is returned as created, without the defaults that could not be applied
+    assert_eq!(task.project_id, "proj_1");
+    assert_eq!(task.priority, Priority::Normal);
+}
+
+#[tokio::test]
+async fn test_get_completed_tasks() {
+    let mock_server = MockServer::start().await;
+
+    let mut completed = task_json("task_123");
+    completed["checked"] = json!(true);
+    completed["completed_at"] = json!("2024-01-15T10:00:00Z");
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/completed/by_completion_date"))
+        .and(query_param("since", "2024-01-01T00:00:00Z"))
+        .and(query_param("until", "2024-01-31T23:59:59Z"))
+        .and(query_param("project_id", "proj_1"))
+        .and(query_param("limit", "50"))
+        .and(query_param("cursor", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [completed],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CompletedTasksFilterArgs {
+        project_id: Some("proj_1".to_string()),
+        since: Some("2024-01-01T00:00:00Z".to_string()),
+        until: Some("2024-01-31T23:59:59Z".to_string()),
+        limit: Some(50),
+        cursor: Some("next".to_string()),
+        ..Default::default()
+    };
+
+    let page = todoist.get_completed_tasks(&args).await.unwrap();
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.results[0].id, "task_123");
+    assert!(page.results[0].completed_at.is_some());
+    assert!(page.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_get_completed_tasks_requires_date_range() {
+    let todoist = TodoistWrapper::new("test-token".to_string());
+
+    let args = CompletedTasksFilterArgs {
+        since: Some("2024-01-01T00:00:00Z".to_string()),
+        ..Default::default()
+    };
+    let result = todoist.get_completed_tasks(&args).await;
+    assert!(matches!(
+        result,
+        Err(TodoistError::ValidationError { field: Some(ref f), .. }) if f == "until"
+    ));
+}
+
+#[tokio::test]
+async fn test_complete_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/close"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.complete_task("task_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_complete_task_ignores_response_body() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/close"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_123")))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    assert!(todoist.complete_task("task_123").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_no_content_on_endpoint_with_body_is_an_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateTaskArgs {
+        content: "Task".to_string(),
+        ..Default::default()
+    };
+    match todoist.create_task(&args).await {
+        Err(TodoistError::EmptyResponse { endpoint, .. }) => assert_eq!(endpoint, "/tasks"),
+        other => panic!("Expected EmptyResponse error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_complete_recurring_task_returning_refetches() {
+    let mock_server = MockServer::start().await;
+
+    // Completing a recurring task advances its due date instead of closing it
+    let mut advanced = task_json("task_123");
+    advanced["due"] = json!({
+        "string": "every day",
+        "date": "2024-01-16",
+        "is_recurring": true,
+        "datetime": null,
+        "timezone": null,
+        "lang": "en"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/close"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(advanced))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let task = todoist.complete_task_returning("task_123").await.unwrap();
+    assert!(!task.checked);
+    let due = task.due.unwrap();
+    assert!(due.is_recurring);
+    assert_eq!(due.date.to_string(), "2024-01-16");
+}
+
+#[tokio::test]
+async fn test_complete_recurring_task() {
+    let mock_server = MockServer::start().await;
+
+    let due = |date: &str| {
+        json!({
+            "string": "every day",
+            "date": date,
+            "is_recurring": true,
+            "datetime": null,
+            "timezone": null,
+            "lang": "en"
+        })
+    };
+    let mut recurring = task_json("task_123");
+    recurring["due"] = due("2024-01-15");
+    let mut advanced = task_json("task_123");
+    advanced["due"] = due("2024-01-16");
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/task_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(recurring))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/close"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(advanced))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // A task without a recurring due date is left open
+    Mock::given(method("GET"))
+        .and(path("/tasks/task_once"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_once")))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_once/close"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let task = todoist.complete_recurring_task("task_123").await.unwrap();
+    assert!(task.is_recurring());
+    assert!(!task.checked);
+    assert_eq!(task.due.unwrap().date.to_string(), "2024-01-16");
+
+    let error = todoist.complete_recurring_task("task_once").await.unwrap_err();
+    assert!(error.is_validation_error());
+}
+
+#[tokio::test]
+async fn test_reopen_task_returning_uses_response_body() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/reopen"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_123")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // No follow-up GET is needed when the body carries the task
+    Mock::given(method("GET"))
+        .and(path("/tasks/task_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_123")))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let task = todoist.reopen_task_returning("task_123").await.unwrap();
+    assert_eq!(task.id, "task_123");
+}
+
+#[tokio::test]
+async fn test_reopen_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks/task_123/reopen"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.reopen_task("task_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/tasks/task_123"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.delete_task("task_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_complete_tasks_reports_each_result() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            let sync_status: serde_json::Map<String, Value> = sync_commands(request)
+                .iter()
+                .map(|command| {
+                    assert_eq!(command["type"], "item_close");
+                    let status = match command["args"]["id"].as_str().unwrap() {
+                        "missing" => json!({"error": "Item not found", "error_code": 22, "http_code": 404}),
+                        _ => json!("ok"),
+                    };
+                    (command["uuid"].as_str().unwrap().to_string(), status)
+                })
+                .collect();
+            ResponseTemplate::new(200).set_body_json(json!({"sync_status": sync_status, "temp_id_mapping": {}}))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let results = todoist.complete_tasks(&["task_1", "missing", "task_2"]).await;
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    match &results[1] {
+        Err(TodoistError::NotFound { resource_id, .. }) => assert_eq!(resource_id.as_deref(), Some("missing")),
+        other => panic!("Expected NotFound error, got {:?}", other),
+    }
+    assert!(results[2].is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_tasks_batches_commands() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            assert!(sync_commands(request)
+                .iter()
+                .all(|command| command["type"] == "item_delete"));
+            sync_ok_response(request)
+        })
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    // 150 IDs are more than one Sync API request accepts
+    let ids: Vec<String> = (0..150).map(|i| format!("task_{i}")).collect();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    let results = todoist.delete_tasks(&ids).await;
+    assert_eq!(results.len(), 150);
+    assert!(results.iter().all(Result::is_ok));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let batch_sizes: Vec<usize> = requests.iter().map(|request| sync_commands(request).len()).collect();
+    assert_eq!(batch_sizes, [100, 50]);
+}
+
+#[tokio::test]
+async fn test_get_tasks_for_project() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "task_1",
+                    "creator_id": "user_1",
+                    "content": "Project Task",
+                    "description": "",
+                    "project_id": "proj_123",
+                    "section_id": null,
+                    "parent_id": null,
+                    "added_by_uid": null,
+                    "assigned_by_uid": null,
+                    "responsible_uid": null,
+                    "labels": [],
+                    "deadline": null,
+                    "duration": null,
+                    "added_at": "2024-01-01T00:00:00Z",
+                    "updated_at": null,
+                    "due": null,
+                    "priority": 1,
+                    "child_order": 0,
+                    "note_count": 0,
+                    "day_order": 0,
+                    "is_collapsed": false
+                }
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_tasks_for_project("proj_123", None, None).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].project_id, "proj_123");
+}
+
+#[tokio::test]
+async fn test_get_all_tasks_for_project_follows_cursor() {
+    let mock_server = MockServer::start().await;
+
+    let task = |id: &str| {
+        let mut task = task_json(id);
+        task["project_id"] = json!("proj_123");
+        task
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_123"))
+        .and(query_param("cursor", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task("task_3")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_123"))
+        .and(query_param("limit", "200"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task("task_1"), task("task_2")],
+            "next_cursor": "page_2"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let tasks = todoist.get_all_tasks_for_project("proj_123").await.unwrap();
+    let ids: Vec<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    assert_eq!(ids, ["task_1", "task_2", "task_3"]);
+}
+
+#[tokio::test]
+async fn test_get_subtasks() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("parent_id", "parent_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{
+                "id": "child_1",
+                "user_id": "user_1",
+                "content": "Child",
+                "project_id": "proj_123",
+                "parent_id": "parent_1",
+                "added_at": "2024-01-01T00:00:00Z",
+                "priority": 1
+            }],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let subtasks = todoist.get_subtasks("parent_1").await.unwrap();
+    assert_eq!(subtasks.len(), 1);
+    assert_eq!(subtasks[0].parent_id.as_deref(), Some("parent_1"));
+}
+
+#[tokio::test]
+async fn test_get_tasks_by_ids() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("ids", "task_1,task_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("task_1"), task_json("task_2")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let tasks = todoist.get_tasks_by_ids(&["task_1", "task_2"]).await.unwrap();
+    assert_eq!(tasks.len(), 2);
+
+    // No request for an empty list
+    assert!(todoist.get_tasks_by_ids(&[]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_get_tasks_by_ids_chunks_and_orders() {
+    let mock_server = MockServer::start().await;
+
+    // Each chunk comes back in the API's own order
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("ids", "task_3,task_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("task_1"), task_json("task_3")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("ids", "task_2,missing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("task_2")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let tasks = todoist
+        .get_tasks_by_ids_with_chunk_size(&["task_3", "task_1", "task_3", "task_2", "missing"], 2)
+        .await
+        .unwrap();
+    let ids: Vec<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    assert_eq!(ids, ["task_3", "task_1", "task_2"]);
+}
+
+#[tokio::test]
+async fn test_get_tasks_by_filter() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/filter"))
+        .and(query_param("query", "today"))
+        .and(query_param("lang", "en"))
+        .and(query_param("limit", "20"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = TaskFilterArgs {
+        query: "today".to_string(),
+        lang: Some(Lang::English),
+        limit: Some(20),
+        cursor: None,
+        ..Default::default()
+    };
+
+    let result = todoist.get_tasks_by_filter(&args).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 0);
+}
+
+#[tokio::test]
+async fn test_get_tasks_by_filter_encodes_query_params() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/filter"))
+        .and(query_param("query", "today & @work | #Side project"))
+        .and(query_param("cursor", "abc+def/=="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = TaskFilterArgs {
+        query: "today & @work | #Side project".to_string(),
+        cursor: Some("abc+def/==".to_string()),
+        ..Default::default()
+    };
+    todoist.get_tasks_by_filter(&args).await.unwrap();
+
+    // Spaces, `&`, `+` and `=` are percent-encoded, so no extra parameters appear
+    let requests = mock_server.received_requests().await.unwrap();
+    let url = &requests[0].url;
+    assert_eq!(url.query_pairs().count(), 2);
+    assert!(!url.query().unwrap().contains(' '));
+}
+
+#[tokio::test]
+async fn test_get_tasks_due_today_and_overdue() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/filter"))
+        .and(query_param("query", "today"))
+        .and(query_param("cursor", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("today_2")],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/filter"))
+        .and(query_param("query", "today"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("today_1")],
+            "next_cursor": "page_2"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/filter"))
+        .and(query_param("query", "overdue"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("late")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let ids: Vec<String> = todoist
+        .get_tasks_due_today()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|task| task.id)
+        .collect();
+    assert_eq!(ids, ["today_1", "today_2"]);
+
+    let overdue = todoist.get_overdue_tasks().await.unwrap();
+    assert_eq!(overdue[0].id, "late");
+}
+
+#[tokio::test]
+async fn test_get_id_mappings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/id_mappings/tasks/918273645,123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"old_id": "918273645", "new_id": "6VfWjjjFg2xqX6Pa"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let mappings = todoist
+        .get_id_mappings(IdMappingObject::Tasks, &["918273645", "123"])
+        .await
+        .unwrap();
+    assert_eq!(mappings.len(), 1);
+    assert_eq!(mappings[0].new_id.as_deref(), Some("6VfWjjjFg2xqX6Pa"));
+
+    // No IDs, no request
+    assert!(todoist
+        .get_id_mappings(IdMappingObject::Tasks, &[])
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_get_tasks_by_structured_filters() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("label", "errand"))
+        .and(query_param("project_id", "proj_123"))
+        .and(query_param("ids", "task_1,task_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task_json("task_1")],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = TaskFilterArgs {
+        label: Some("errand".to_string()),
+        project_id: Some("proj_123".to_string()),
+        ids: Some(vec!["task_1".to_string(), "task_2".to_string()]),
+        ..Default::default()
+    };
+    let response = todoist.get_tasks_by_filter(&args).await.unwrap();
+    assert_eq!(response.results.len(), 1);
+
+    // A filter query cannot be combined with structured filters
+    let args = TaskFilterArgs {
+        query: "today".to_string(),
+        label: Some("errand".to_string()),
+        ..Default::default()
+    };
+    let error = todoist.get_tasks_by_filter(&args).await.unwrap_err();
+    assert!(error.is_validation_error());
+}
+
+#[tokio::test]
+async fn test_get_completed_tasks_by_completion_date() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/completed/by_completion_date"))
+        .and(query_param("since", "2024-01-01T00:00:00Z"))
+        .and(query_param("until", "2024-01-31T23:59:59Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CompletedTasksFilterArgs {
+        since: Some("2024-01-01T00:00:00Z".to_string()),
+        until: Some("2024-01-31T23:59:59Z".to_string()),
+        ..Default::default()
+    };
+
+    let result = todoist.get_completed_tasks_by_completion_date(&args).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 0);
+}
+
+#[tokio::test]
+async fn test_get_completed_tasks_by_due_date() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/completed/by_due_date"))
+        .and(query_param("project_id", "proj_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CompletedTasksFilterArgs {
+        project_id: Some("proj_123".to_string()),
+        ..Default::default()
+    };
+
+    let result = todoist.get_completed_tasks_by_due_date(&args).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 0);
+}
+
+// ===== LABEL OPERATIONS =====
+
+#[tokio::test]
+async fn test_get_labels() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "label_1",
+                    "name": "Important",
+                    "color": "red",
+                    "order": 1,
+                    "is_favorite": false
+                }
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_labels(None, None).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].id, "label_1");
+}
+
+#[tokio::test]
+async fn test_etag_cache_reuses_unchanged_response() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(json!({
+                    "results": [{"id": "label_1", "name": "Important", "color": "red", "order": 1, "is_favorite": false}],
+                    "next_cursor": null
+                })),
+        )
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_etag_cache();
+
+    let first = todoist.get_labels(None, None).await.unwrap();
+    let second = todoist.get_labels(None, None).await.unwrap();
+    assert_eq!(second.results, first.results);
+
+    // After clearing, the next request downloads the labels again
+    todoist.clear_etag_cache();
+    todoist.get_labels(None, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_etag_cache_skips_cursor_pages_and_evicts_least_recently_used() {
+    let mock_server = MockServer::start().await;
+
+    // Cursor pages are never cached, so they are never revalidated
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(header("If-None-Match", "\"labels\""))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    // The oldest of 129 labels is evicted, while the newest is still cached
+    Mock::given(method("GET"))
+        .and(path("/labels/label_0"))
+        .and(header("If-None-Match", "\"label_0\""))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/labels/label_128"))
+        .and(header("If-None-Match", "\"label_128\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(|request: &Request| {
+            let id = request.url.path().rsplit('/').next().unwrap().to_string();
+            let body = match id.as_str() {
+                "labels" => json!({"results": [], "next_cursor": null}),
+                _ => json!({"id": id, "name": id, "color": "red", "order": 1, "is_favorite": false}),
+            };
+            ResponseTemplate::new(200)
+                .insert_header("ETag", format!("\"{id}\""))
+                .set_body_json(body)
+        })
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_etag_cache();
+
+    for _ in 0..2 {
+        todoist.get_labels(None, Some("page_2".to_string())).await.unwrap();
+    }
+
+    for i in 0..=128 {
+        todoist.get_label(format!("label_{i}")).await.unwrap();
+    }
+    assert_eq!(todoist.get_label("label_128").await.unwrap().id, "label_128");
+    assert_eq!(todoist.get_label("label_0").await.unwrap().id, "label_0");
+}
+
+#[tokio::test]
+async fn test_get_label() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels/label_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "label_123",
+            "name": "Work",
+            "color": "blue",
+            "order": 2,
+            "is_favorite": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_label("label_123").await;
+    assert!(result.is_ok());
+    let label = result.unwrap();
+    assert_eq!(label.id, "label_123");
+    assert_eq!(label.name, "Work");
+}
+
+#[tokio::test]
+async fn test_create_label() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "new_label",
+            "name": "New Label",
+            "color": "green",
+            "order": 3,
+            "is_favorite": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateLabelArgs {
+        name: "New Label".to_string(),
+        color: Some(Color::Green),
+        order: Some(3),
+        ..Default::default()
+    };
+
+    let result = todoist.create_label(&args).await;
+    assert!(result.is_ok());
+    let label = result.unwrap();
+    assert_eq!(label.id, "new_label");
+    assert_eq!(label.name, "New Label");
+}
+
+#[tokio::test]
+async fn test_create_label_round_trips_color_and_order() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/labels"))
+        .and(body_json(json!({"name": "Errand", "color": "sky_blue", "order": 5})))
+        .respond_with(|request: &Request| {
+            let mut label: Value = serde_json::from_slice(&request.body).unwrap();
+            label["id"] = json!("label_5");
+            label["is_favorite"] = json!(false);
+            ResponseTemplate::new(200).set_body_json(label)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateLabelArgs {
+        name: "Errand".to_string(),
+        color: Some(Color::SkyBlue),
+        order: Some(5),
+        ..Default::default()
+    };
+    let label = todoist.create_label(&args).await.unwrap();
+    assert_eq!(label.color, Color::SkyBlue);
+    assert_eq!(label.order, Some(5));
+}
+
+#[tokio::test]
+async fn test_update_label() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/labels/label_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "label_123",
+            "name": "Updated Label",
+            "color": "grape",
+            "order": 5,
+            "is_favorite": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = UpdateLabelArgs {
+        name: Some("Updated Label".to_string()),
+        color: Some(Color::Grape),
+        order: Some(5),
+        is_favorite: Some(true),
+    };
+
+    let result = todoist.update_label("label_123", &args).await;
+    assert!(result.is_ok());
+    let label = result.unwrap();
+    assert_eq!(label.name, "Updated Label");
+    assert_eq!(label.color, Color::Grape);
+}
+
+#[tokio::test]
+async fn test_shared_labels() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels/shared"))
+        .and(query_param("omit_personal", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": ["errand", "waiting"],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/labels/shared/rename"))
+        .and(body_json(json!({"name": "errand", "new_name": "errands"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(null)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/labels/shared/remove"))
+        .and(body_json(json!({"name": "waiting"})))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let labels = todoist.get_shared_labels(true).await.unwrap();
+    assert_eq!(labels, ["errand", "waiting"]);
+    assert!(todoist.rename_shared_label("errand", "errands").await.is_ok());
+    assert!(todoist.remove_shared_label("waiting").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_label() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/labels/label_123"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.delete_label("label_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_labels_filtered() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(query_param("limit", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "label_1",
+                    "name": "Label 1",
+                    "color": "red",
+                    "order": 1,
+                    "is_favorite": false
+                }
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = LabelFilterArgs {
+        limit: Some(10),
+        cursor: None,
+    };
+
+    let result = todoist.get_labels_filtered(&args).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].id, "label_1");
+    assert!(response.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_get_label_usage() {
+    let mock_server = MockServer::start().await;
+
+    let task = |id: &str, labels: Value| {
+        let mut task = task_json(id);
+        task["labels"] = labels;
+        task
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("cursor", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task("task_3", json!(["work", "shared"]))],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task("task_1", json!(["work"])), task("task_2", json!([]))],
+            "next_cursor": "page_2"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {"id": "label_1", "name": "work", "color": "red", "order": 1, "is_favorite": false},
+                {"id": "label_2", "name": "unused", "color": "blue", "order": 2, "is_favorite": false}
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let usage = todoist.get_label_usage().await.unwrap();
+    assert_eq!(usage.len(), 3);
+    assert_eq!(usage["work"], 2);
+    assert_eq!(usage["unused"], 0);
+    assert_eq!(usage["shared"], 1);
+}
+
+// ===== SECTION OPERATIONS =====
+
+#[tokio::test]
+async fn test_get_sections() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "sec_123",
+                    "creator_id": "user_1",
+                    "project_id": "proj_1",
+                    "added_at": "2024-01-01T00:00:00Z",
+                    "updated_at": null,
+                    "archived_at": null,
+                    "name": "Development",
+                    "section_order": 1,
+                    "is_archived": false,
+                    "is_collapsed": false
+                }
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_sections(None, None).await;
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].id, "sec_123");
+}
+
+#[tokio::test]
+async fn test_get_sections_grouped() {
+    let mock_server = MockServer::start().await;
+
+    let section = |id: &str, project_id: &str, section_order: i32| {
+        json!({
+            "id": id,
+            "user_id": "user_1",
+            "project_id": project_id,
+            "added_at": "2024-01-01T00:00:00Z",
+            "name": id,
+            "section_order": section_order
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .and(query_param("cursor", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [section("work_first", "work", 1)],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [section("work_second", "work", 2), section("home_only", "home", 1)],
+            "next_cursor": "page_2"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let grouped = todoist.get_sections_grouped().await.unwrap();
+    assert_eq!(grouped.len(), 2);
+    let work: Vec<&str> = grouped["work"].iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(work, vec!["work_first", "work_second"]);
+    assert_eq!(grouped["home"][0].id, "home_only");
+}
+
+#[tokio::test]
+async fn test_get_section() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/sections/sec_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sec_123",
+            "creator_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": null,
+            "archived_at": null,
+            "name": "Testing",
+            "section_order": 2,
+            "is_archived": false,
+            "is_collapsed": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.get_section("sec_123").await;
+    assert!(result.is_ok());
+    let section = result.unwrap();
+    assert_eq!(section.id, "sec_123");
+    assert_eq!(section.name, "Testing");
+}
+
+#[tokio::test]
+async fn test_create_section() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sections"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "new_sec",
+            "creator_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": null,
+            "archived_at": null,
+            "name": "New Section",
+            "section_order": 3,
+            "is_archived": false,
+            "is_collapsed": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateSectionArgs {
+        name: "New Section".to_string(),
+        project_id: "proj_1".to_string(),
+        order: Some(3),
+    };
+
+    let result = todoist.create_section(&args).await;
+    assert!(result.is_ok());
+    let section = result.unwrap();
+    assert_eq!(section.id, "new_sec");
+    assert_eq!(section.name, "New Section");
+}
+
+#[tokio::test]
+async fn test_update_section() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sections/sec_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sec_123",
+            "creator_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "archived_at": null,
+            "name": "Updated Section",
+            "section_order": 1,
+            "is_archived": false,
+            "is_collapsed": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = UpdateSectionArgs {
+        name: Some("Updated Section".to_string()),
+        ..Default::default()
+    };
+
+    let result = todoist.update_section("sec_123", &args).await;
+    assert!(result.is_ok());
+    let section = result.unwrap();
+    assert_eq!(section.name, "Updated Section");
+}
+
+#[tokio::test]
+async fn test_update_section_order_only() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("section_reorder"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(
+                commands[0]["args"],
+                json!({"sections": [{"id": "sec_123", "section_order": 4}]})
+            );
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The name must not be overwritten when only the order changes
+    Mock::given(method("POST"))
+        .and(path("/sections/sec_123"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/sections/sec_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sec_123",
+            "user_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "archived_at": null,
+            "name": "Original Name",
+            "section_order": 4,
+            "is_archived": false,
+            "is_collapsed": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = UpdateSectionArgs {
+        order: Some(4),
+        ..Default::default()
+    };
+
+    let section = todoist.update_section("sec_123", &args).await.unwrap();
+    assert_eq!(section.name, "Original Name");
+    assert_eq!(section.section_order, 4);
+
+    let result = todoist.update_section("sec_123", &UpdateSectionArgs::default()).await;
+    assert!(matches!(result, Err(TodoistError::ValidationError { .. })));
+}
+
+#[tokio::test]
+async fn test_section_task_counts() {
+    let mock_server = MockServer::start().await;
+
+    let task = |id: &str, section_id: Option<&str>| {
+        let mut task = task_json(id);
+        task["content"] = json!(id);
+        task["section_id"] = json!(section_id);
+        task
+    };
+    let section = |id: &str, order: i32| {
+        json!({
+            "id": id,
+            "user_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "name": id,
+            "section_order": order
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [section("sec_a", 1), section("sec_b", 2), section("sec_empty", 3)],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                task("task_1", Some("sec_a")),
+                task("task_2", Some("sec_b")),
+                task("task_3", Some("sec_a")),
+                task("task_4", None)
+            ],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("section_id", "sec_a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [task("task_1", Some("sec_a")), task("task_3", Some("sec_a"))],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    assert_eq!(todoist.count_tasks_in_section("sec_a").await.unwrap(), 2);
+
+    let counts: Vec<(String, usize)> = todoist
+        .get_sections_with_counts("proj_1")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(section, count)| (section.id, count))
+        .collect();
+    assert_eq!(
+        counts,
+        [
+            ("sec_a".to_string(), 2),
+            ("sec_b".to_string(), 1),
+            ("sec_empty".to_string(), 0)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_delete_section() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/sections/sec_123"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.delete_section("sec_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_reorder_projects() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("project_reorder"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands.len(), 1);
+            assert_eq!(
+                commands[0]["args"],
+                json!({"projects": [
+                    {"id": "proj_2", "child_order": 1},
+                    {"id": "proj_1", "child_order": 2}
+                ]})
+            );
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let orders = [("proj_2".to_string(), 1), ("proj_1".to_string(), 2)];
+    assert!(todoist.reorder_projects(&orders).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_reorder_sections() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("section_reorder"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(
+                commands[0]["args"],
+                json!({"sections": [{"id": "section_1", "section_order": 3}]})
+            );
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    assert!(todoist.reorder_sections(&[("section_1".to_string(), 3)]).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_move_section() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("section_move"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands[0]["args"], json!({"id": "sec_123", "project_id": "proj_2"}));
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/sections/sec_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sec_123",
+            "user_id": "user_1",
+            "project_id": "proj_2",
+            "added_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "archived_at": null,
+            "name": "Backlog",
+            "section_order": 1,
+            "is_archived": false,
+            "is_collapsed": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let section = todoist.move_section("sec_123", "proj_2").await.unwrap();
+    assert_eq!(section.project_id, "proj_2");
+}
+
+#[tokio::test]
+async fn test_reorder_nothing_sends_no_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    assert!(todoist.reorder_projects(&[]).await.is_ok());
+    assert!(todoist.reorder_sections(&[]).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_sync_commands() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands.len(), 3);
+            assert_eq!(commands[0]["type"], "project_add");
+            assert_eq!(commands[0]["temp_id"], "project_tmp");
+            assert_eq!(commands[1]["args"]["project_id"], "project_tmp");
+            assert!(commands[2].get("temp_id").is_none());
+
+            let uuid = |i: usize| commands[i]["uuid"].as_str().unwrap().to_string();
+            ResponseTemplate::new(200).set_body_json(json!({
+                "sync_status": {
+                    uuid(0): "ok",
+                    uuid(1): "ok",
+                    uuid(2): {
+                        "error": "Item not found",
+                        "error_code": 22,
+                        "error_tag": "ITEM_NOT_FOUND",
+                        "http_code": 404
+                    }
+                },
+                "temp_id_mapping": {
+                    "project_tmp": "6Jf8VQXxpwv56VQ7",
+                    "task_tmp": "6X7rM8997g3RQmvh"
+                }
+            }))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let commands = [
+        SyncCommand::new("project_add", json!({"name": "Groceries"})).with_temp_id("project_tmp"),
+        SyncCommand::new("item_add", json!({"content": "Milk", "project_id": "project_tmp"})).with_temp_id("task_tmp"),
+        SyncCommand::new("item_close", json!({"id": "missing"})),
+    ];
+
+    let result = todoist.sync_commands(&commands).await.unwrap();
+    assert!(!result.is_ok());
+    assert!(matches!(result.status(&commands[0]), Some(Ok(()))));
+    assert!(matches!(result.status(&commands[1]), Some(Ok(()))));
+    let error = result.status(&commands[2]).unwrap().as_ref().unwrap_err();
+    assert!(error.is_not_found());
+    assert!(error.to_string().contains("Item not found"));
+    assert_eq!(result.real_id("project_tmp"), Some("6Jf8VQXxpwv56VQ7"));
+    assert_eq!(result.real_id("unknown"), None);
+
+    // An empty batch is a no-op
+    let result = todoist.sync_commands(&[]).await.unwrap();
+    assert!(result.is_ok());
+    assert!(result.sync_status.is_empty());
+}
+
+#[tokio::test]
+async fn test_collapse_section() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("section_update"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands[0]["args"]["id"], "section_123");
+            assert_eq!(commands[0]["args"]["collapsed"], true);
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.collapse_section("section_123").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_expand_section_sync_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            let uuid = sync_commands(request)[0]["uuid"].as_str().unwrap().to_string();
+            ResponseTemplate::new(200).set_body_json(json!({
+                "sync_status": {
+                    uuid: {"error": "Section not found", "error_code": 21, "http_code": 404}
+                },
+                "temp_id_mapping": {}
+            }))
+        })
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let result = todoist.expand_section("missing").await;
+    match result {
+        Err(TodoistError::NotFound { message, .. }) => assert_eq!(message, "Section not found"),
+        _ => panic!("Expected NotFound error"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_reminders() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .and(body_string_contains("reminders_location"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "reminders": [
+                {
+                    "id": "rem_1",
+                    "notify_uid": "2671355",
+                    "item_id": "task_1",
+                    "type": "relative",
+                    "minute_offset": 30,
+                    "is_deleted": false
+                },
+                {
+                    "id": "rem_2",
+                    "item_id": "task_1",
+                    "type": "absolute",
+                    "due": {
+                        "date": "2016-08-05",
+                        "is_recurring": false,
+                        "string": "Aug 5 at 10:00",
+                        "lang": "en"
+                    },
+                    "is_deleted": true
+                }
+            ],
+            "reminders_location": [{
+                "id": "rem_3",
+                "item_id": "task_2",
+                "type": "location",
+                "name": "Aliados",
+                "loc_lat": "41.148581",
+                "loc_long": "-8.610945",
+                "loc_trigger": "on_enter",
+                "radius": 100
+            }],
+            "sync_token": "token"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let reminders = todoist.get_reminders().await.unwrap();
+    assert_eq!(reminders.len(), 2);
+    assert_eq!(reminders[0].task_id, "task_1");
+    assert_eq!(reminders[0].reminder_type, ReminderType::Relative);
+    assert_eq!(reminders[0].minute_offset, Some(30));
+    assert_eq!(reminders[1].reminder_type, ReminderType::Location);
+    assert_eq!(reminders[1].radius, Some(100));
+}
+
+#[tokio::test]
+async fn test_incremental_sync() {
+    let mock_server = MockServer::start().await;
+
+    let mut deleted = task_json("task_2");
+    deleted["is_deleted"] = json!(true);
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=tok_1"))
+        .respond_with(move |request: &Request| {
+            let form: HashMap<String, String> = serde_urlencoded::from_bytes(&request.body).unwrap();
+            assert_eq!(form["resource_types"], r#"["items","projects"]"#);
+            ResponseTemplate::new(200).set_body_json(json!({
+                "sync_token": "tok_2",
+                "full_sync": false,
+                "items": [deleted.clone()]
+            }))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("sync_token=*"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "sync_token": "tok_1",
+            "full_sync": true,
+            "items": [task_json("task_1"), task_json("task_2")],
+            "projects": [{
+                "id": "proj_1",
+                "name": "Inbox",
+                "color": "grey",
+                "shared": false,
+                "is_favorite": false,
+                "is_inbox_project": true,
+                "view_style": "list"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    let resource_types = [SyncResourceType::Tasks, SyncResourceType::Projects];
+
+    let full = todoist.sync("*", &resource_types).await.unwrap();
+    assert!(full.full_sync);
+    assert_eq!(full.tasks.len(), 2);
+    assert_eq!(full.projects[0].id, "proj_1");
+
+    let changes = todoist.sync(&full.sync_token, &resource_types).await.unwrap();
+    assert!(!changes.full_sync);
+    assert_eq!(changes.sync_token, "tok_2");
+    assert_eq!(changes.tasks.len(), 1);
+    assert!(changes.tasks[0].is_deleted);
+    assert!(changes.projects.is_empty());
+
+    let error = todoist.sync("*", &[]).await.unwrap_err();
+    assert!(error.is_validation_error());
+}
+
+#[tokio::test]
+async fn test_add_reminder() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            let commands = sync_commands(request);
+            assert_eq!(commands[0]["type"], "reminder_add");
+            assert_eq!(
+                commands[0]["args"],
+                json!({
+                    "item_id": "task_1",
+                    "type": "absolute",
+                    "due": {"date": "2024-01-20T09:00:00Z"}
+                })
+            );
+            let uuid = commands[0]["uuid"].as_str().unwrap().to_string();
+            let temp_id = commands[0]["temp_id"].as_str().unwrap().to_string();
+            ResponseTemplate::new(200).set_body_json(json!({
+                "sync_status": {uuid: "ok"},
+                "temp_id_mapping": {temp_id: "rem_new"}
+            }))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = CreateReminderArgs::absolute("2024-01-20T09:00:00Z");
+    let reminder_id = todoist.add_reminder("task_1", &args).await.unwrap();
+    assert_eq!(reminder_id, "rem_new");
+}
+
+#[test]
+fn test_relative_reminder_args_serialization() {
+    let args = CreateReminderArgs::relative(45);
+    assert_eq!(
+        serde_json::to_value(&args).unwrap(),
+        json!({"type": "relative", "minute_offset": 45})
+    );
+}
+
+#[tokio::test]
+async fn test_delete_reminder() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_string_contains("reminder_delete"))
+        .respond_with(|request: &Request| {
+            assert_eq!(sync_commands(request)[0]["args"]["id"], "rem_1");
+            sync_ok_response(request)
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    assert!(todoist.delete_reminder("rem_1").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_activity_log() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/activities"))
+        .and(query_param("object_type", "item"))
+        .and(query_param("object_id", "task_1"))
+        .and(query_param("event_type", "completed"))
+        .and(query_param("parent_item_id", "task_0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{
+                "id": 42,
+                "object_type": "item",
+                "object_id": "task_1",
+                "v2_object_id": "task_1",
+                "event_type": "completed",
+                "event_date": "2024-01-15T10:00:00Z",
+                "parent_project_id": "proj_1",
+                "parent_item_id": "task_0",
+                "initiator_id": null,
+                "extra_data": {"content": "Buy milk", "client": "web"}
+            }],
+            "next_cursor": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let args = ActivityFilterArgs {
+        object_type: Some(ActivityObjectType::Task),
+        object_id: Some("task_1".to_string()),
+        event_type: Some(ActivityEventType::Completed),
+        parent_task_id: Some("task_0".to_string()),
+        ..Default::default()
+    };
+    let page = todoist.get_activity_log(&args).await.unwrap();
+    let event = &page.results[0];
+    assert_eq!(event.object_type, ActivityObjectType::Task);
+    assert_eq!(event.event_type, ActivityEventType::Completed);
+    assert_eq!(event.parent_task_id.as_deref(), Some("task_0"));
+    assert_eq!(event.extra_data.as_ref().unwrap()["content"], "Buy milk");
+
+    let args = ActivityFilterArgs {
+        object_id: Some("task_1".to_string()),
+        ..Default::default()
+    };
+    assert!(todoist.get_activity_log(&args).await.unwrap_err().is_validation_error());
+}
+
+#[tokio::test]
+async fn test_get_sections_filtered() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .and(query_param("project_id", "proj_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "id": "sec_1",
+                    "creator_id": "user_1",
+                    "project_id": "proj_123",
+                    "added_at": "2024-01-01T00:00:00Z",
                     "updated_at": null,
-                    "due": null,
-                    "priority": 1,
-                    "child_order": 0,
-                    "note_count": 0,
-                    "day_order": 0,
+                    "archived_at": null,
+                    "name": "Section 1",
+                    "section_order": 1,
+                    "is_archived": false,
                     "is_collapsed": false
                 }
             ],
@@ -525,24 +3905,38 @@ async fn test_get_tasks_for_project() {
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_tasks_for_project("proj_123", None, None).await;
+    let args = SectionFilterArgs {
+        project_id: Some("proj_123".to_string()),
+        limit: None,
+        cursor: None,
+    };
+
+    let result = todoist.get_sections_filtered(&args).await;
     assert!(result.is_ok());
     let response = result.unwrap();
     assert_eq!(response.results.len(), 1);
     assert_eq!(response.results[0].project_id, "proj_123");
 }
 
+// ===== COMMENT OPERATIONS =====
+
 #[tokio::test]
-async fn test_get_tasks_by_filter() {
+async fn test_get_comments() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/tasks/filter"))
-        .and(query_param("query", "today"))
-        .and(query_param("lang", "en"))
-        .and(query_param("limit", "20"))
+        .and(path("/comments"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [],
+            "results": [
+                {
+                    "id": "comment_1",
+                    "content": "Test comment",
+                    "posted_at": "2024-01-01T00:00:00Z",
+                    "attachment": null,
+                    "project_id": null,
+                    "task_id": "task_1"
+                }
+            ],
             "next_cursor": null
         })))
         .mount(&mock_server)
@@ -550,227 +3944,303 @@ async fn test_get_tasks_by_filter() {
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = TaskFilterArgs {
-        query: "today".to_string(),
-        lang: Some("en".to_string()),
-        limit: Some(20),
-        cursor: None,
-    };
-
-    let result = todoist.get_tasks_by_filter(&args).await;
+    let result = todoist.get_comments().await;
     assert!(result.is_ok());
     let response = result.unwrap();
-    assert_eq!(response.results.len(), 0);
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.results[0].id, "comment_1");
+    assert!(response.next_cursor.is_none());
 }
 
 #[tokio::test]
-async fn test_get_completed_tasks_by_completion_date() {
+async fn test_get_comments_for_task() {
     let mock_server = MockServer::start().await;
 
+    let comment =
+        |id: &str, posted_at: &str| json!({"id": id, "content": id, "posted_at": posted_at, "task_id": "task_1"});
+
     Mock::given(method("GET"))
-        .and(path("/tasks/completed/by_completion_date"))
-        .and(query_param("since", "2024-01-01T00:00:00Z"))
-        .and(query_param("until", "2024-01-31T23:59:59Z"))
+        .and(path("/comments"))
+        .and(query_param("task_id", "task_1"))
+        .and(query_param("cursor", "page_2"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [],
+            "results": [comment("comment_1", "2024-01-01T08:00:00Z")],
             "next_cursor": null
         })))
         .mount(&mock_server)
         .await;
+    Mock::given(method("GET"))
+        .and(path("/comments"))
+        .and(query_param("task_id", "task_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [comment("comment_3", "2024-01-03T08:00:00Z"), comment("comment_2", "2024-01-02T08:00:00Z")],
+            "next_cursor": "page_2"
+        })))
+        .mount(&mock_server)
+        .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = CompletedTasksFilterArgs {
-        since: Some("2024-01-01T00:00:00Z".to_string()),
-        until: Some("2024-01-31T23:59:59Z".to_string()),
-        ..Default::default()
-    };
-
-    let result = todoist.get_completed_tasks_by_completion_date(&args).await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 0);
+    let comments = todoist.get_comments_for_task("task_1").await.unwrap();
+    let ids: Vec<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["comment_1", "comment_2", "comment_3"]);
 }
 
 #[tokio::test]
-async fn test_get_completed_tasks_by_due_date() {
+async fn test_get_collaborators_resolves_comment_authors() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/tasks/completed/by_due_date"))
-        .and(query_param("project_id", "proj_123"))
+        .and(path("/projects/proj_1/collaborators"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [],
+            "results": [
+                {"id": "user_1", "name": "Ada", "email": "ada@example.com"},
+                {"id": "user_2", "name": "Grace", "email": "grace@example.com"}
+            ],
             "next_cursor": null
         })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/projects/missing/collaborators"))
+        .respond_with(ResponseTemplate::new(404))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = CompletedTasksFilterArgs {
-        project_id: Some("proj_123".to_string()),
-        ..Default::default()
+    let collaborators = todoist.get_collaborators("proj_1").await.unwrap();
+    let comment = |posted_uid: Option<&str>| -> Comment {
+        serde_json::from_value(json!({"id": "comment_1", "posted_at": null, "posted_uid": posted_uid})).unwrap()
     };
+    assert_eq!(comment(Some("user_2")).author(&collaborators).unwrap().name, "Grace");
+    assert!(comment(Some("user_3")).author(&collaborators).is_none());
+    assert!(comment(None).author(&collaborators).is_none());
 
-    let result = todoist.get_completed_tasks_by_due_date(&args).await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 0);
+    let error = todoist.get_collaborators("missing").await.unwrap_err();
+    assert!(error.is_not_found());
 }
 
-// ===== LABEL OPERATIONS =====
-
 #[tokio::test]
-async fn test_get_labels() {
+async fn test_invite_and_remove_collaborators() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/labels"))
+        .and(path("/projects/proj_1/collaborators"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "label_1",
-                    "name": "Important",
-                    "color": "red",
-                    "order": 1,
-                    "is_favorite": false
-                }
-            ],
+            "results": [{"id": "user_2", "name": "Grace", "email": "grace@example.com"}],
             "next_cursor": null
         })))
         .mount(&mock_server)
         .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(|request: &Request| {
+            let command = &sync_commands(request)[0];
+            let status = if command["args"]["email"] == "full@example.com" {
+                json!({"error": "Maximum number of collaborators reached", "error_code": 53,
+                       "error_tag": "MAX_COLLABORATORS_LIMIT_REACHED", "http_code": 403})
+            } else {
+                json!("ok")
+            };
+            ResponseTemplate::new(200).set_body_json(json!({
+                "sync_status": {command["uuid"].as_str().unwrap(): status},
+                "temp_id_mapping": {}
+            }))
+        })
+        .mount(&mock_server)
+        .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_labels(None, None).await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].id, "label_1");
+    todoist.invite_collaborator("proj_1", "ada@example.com").await.unwrap();
+    let error = todoist
+        .invite_collaborator("proj_1", "full@example.com")
+        .await
+        .unwrap_err();
+    assert!(error.is_authorization_error());
+    assert_eq!(error.error_tag(), Some("MAX_COLLABORATORS_LIMIT_REACHED"));
+    let error = todoist.invite_collaborator("proj_1", "ada").await.unwrap_err();
+    assert!(error.is_validation_error());
+
+    todoist.remove_collaborator("proj_1", "ada@example.com").await.unwrap();
+    todoist.remove_collaborator("proj_1", "user_2").await.unwrap();
+    let error = todoist.remove_collaborator("proj_1", "user_3").await.unwrap_err();
+    assert!(error.is_not_found());
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let commands: Vec<Value> = requests
+        .iter()
+        .filter(|request| request.url.path() == "/sync")
+        .flat_map(sync_commands)
+        .map(|command| json!([command["type"], command["args"]]))
+        .collect();
+    assert_eq!(
+        commands,
+        [
+            json!(["share_project", {"project_id": "proj_1", "email": "ada@example.com"}]),
+            json!(["share_project", {"project_id": "proj_1", "email": "full@example.com"}]),
+            json!(["delete_collaborator", {"project_id": "proj_1", "email": "ada@example.com"}]),
+            json!(["delete_collaborator", {"project_id": "proj_1", "email": "grace@example.com"}]),
+        ]
+    );
 }
 
 #[tokio::test]
-async fn test_get_label() {
+async fn test_get_comment() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/labels/label_123"))
+        .and(path("/comments/comment_123"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "label_123",
-            "name": "Work",
-            "color": "blue",
-            "order": 2,
-            "is_favorite": true
+            "id": "comment_123",
+            "content": "Single comment",
+            "posted_at": "2024-01-01T00:00:00Z",
+            "attachment": null,
+            "project_id": null,
+            "task_id": "task_1"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_label("label_123").await;
+    let result = todoist.get_comment("comment_123").await;
     assert!(result.is_ok());
-    let label = result.unwrap();
-    assert_eq!(label.id, "label_123");
-    assert_eq!(label.name, "Work");
+    let comment = result.unwrap();
+    assert_eq!(comment.id, "comment_123");
+    assert_eq!(comment.content, "Single comment");
 }
 
 #[tokio::test]
-async fn test_create_label() {
+async fn test_create_comment() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/labels"))
+        .and(path("/comments"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "new_label",
-            "name": "New Label",
-            "color": "green",
-            "order": 3,
-            "is_favorite": false
+            "id": "new_comment",
+            "content": "New comment",
+            "posted_at": "2024-01-01T00:00:00Z",
+            "attachment": null,
+            "project_id": null,
+            "task_id": "task_1"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = CreateLabelArgs {
-        name: "New Label".to_string(),
-        color: Some("green".to_string()),
-        order: Some(3),
-        ..Default::default()
+    let args = CreateCommentArgs {
+        content: "New comment".to_string(),
+        task_id: Some("task_1".to_string()),
+        project_id: None,
+        attachment: None,
     };
 
-    let result = todoist.create_label(&args).await;
+    let result = todoist.create_comment(&args).await;
     assert!(result.is_ok());
-    let label = result.unwrap();
-    assert_eq!(label.id, "new_label");
-    assert_eq!(label.name, "New Label");
+    let comment = result.unwrap();
+    assert_eq!(comment.id, "new_comment");
+    assert_eq!(comment.content, "New comment");
 }
 
 #[tokio::test]
-async fn test_update_label() {
+async fn test_update_comment() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/labels/label_123"))
+        .and(path("/comments/comment_123"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "label_123",
-            "name": "Updated Label",
-            "color": "purple",
-            "order": 5,
-            "is_favorite": true
+            "id": "comment_123",
+            "content": "Updated comment",
+            "posted_at": "2024-01-01T00:00:00Z",
+            "attachment": null,
+            "project_id": null,
+            "task_id": "task_1"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = UpdateLabelArgs {
-        name: Some("Updated Label".to_string()),
-        color: Some("purple".to_string()),
-        order: Some(5),
-        is_favorite: Some(true),
+    let args = UpdateCommentArgs {
+        content: "Updated comment".to_string(),
     };
 
-    let result = todoist.update_label("label_123", &args).await;
+    let result = todoist.update_comment("comment_123", &args).await;
     assert!(result.is_ok());
-    let label = result.unwrap();
-    assert_eq!(label.name, "Updated Label");
-    assert_eq!(label.color, "purple");
+    let comment = result.unwrap();
+    assert_eq!(comment.content, "Updated comment");
 }
 
 #[tokio::test]
-async fn test_delete_label() {
+async fn test_delete_comment() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("DELETE"))
-        .and(path("/labels/label_123"))
+        .and(path("/comments/comment_123"))
         .respond_with(ResponseTemplate::new(204))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.delete_label("label_123").await;
+    let result = todoist.delete_comment("comment_123").await;
     assert!(result.is_ok());
 }
 
 #[tokio::test]
-async fn test_get_labels_filtered() {
+async fn test_download_attachment() {
     let mock_server = MockServer::start().await;
 
+    let png = vec![0x89, b'P', b'N', b'G', 0x00, 0xff];
     Mock::given(method("GET"))
-        .and(path("/labels"))
-        .and(query_param("limit", "10"))
+        .and(path("/files/photo.png"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(png.clone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let attachment = |file_url: String, resource_type: ResourceType| Attachment {
+        file_name: "photo.png".to_string(),
+        file_type: "image/png".to_string(),
+        file_url,
+        resource_type,
+    };
+    let photo = attachment(format!("{}/files/photo.png", mock_server.uri()), ResourceType::Image);
+    assert_eq!(todoist.download_attachment(&photo).await.unwrap(), png);
+
+    let missing = attachment(format!("{}/files/gone.png", mock_server.uri()), ResourceType::Image);
+    assert!(todoist.download_attachment(&missing).await.unwrap_err().is_not_found());
+
+    // The token is never sent to other hosts
+    let website = attachment("https://example.com/photo.png".to_string(), ResourceType::Website);
+    let error = todoist.download_attachment(&website).await.unwrap_err();
+    assert!(error.is_validation_error());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_comments_filtered() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/comments"))
+        .and(query_param("task_id", "task_123"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "results": [
                 {
-                    "id": "label_1",
-                    "name": "Label 1",
-                    "color": "red",
-                    "order": 1,
-                    "is_favorite": false
+                    "id": "comment_1",
+                    "content": "Task comment",
+                    "posted_at": "2024-01-01T00:00:00Z",
+                    "attachment": null,
+                    "project_id": null,
+                    "task_id": "task_123"
                 }
             ],
             "next_cursor": null
@@ -780,491 +4250,1123 @@ async fn test_get_labels_filtered() {
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = LabelFilterArgs {
-        limit: Some(10),
+    let args = CommentFilterArgs {
+        task_id: Some("task_123".to_string()),
+        project_id: None,
+        limit: None,
         cursor: None,
     };
 
-    let result = todoist.get_labels_filtered(&args).await;
+    let result = todoist.get_comments_filtered(&args).await;
     assert!(result.is_ok());
     let response = result.unwrap();
     assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].id, "label_1");
+    assert_eq!(response.results[0].task_id, Some("task_123".to_string()));
     assert!(response.next_cursor.is_none());
 }
 
-// ===== SECTION OPERATIONS =====
+#[tokio::test]
+async fn test_load_workspace() {
+    let mock_server = MockServer::start().await;
+
+    let task = |id: &str, project_id: &str| {
+        let mut task = task_json(id);
+        task["content"] = json!(id);
+        task["project_id"] = json!(project_id);
+        task
+    };
+    let page =
+        |results: Value| ResponseTemplate::new(200).set_body_json(json!({"results": results, "next_cursor": null}));
+
+    Mock::given(method("GET"))
+        .and(path("/projects"))
+        .respond_with(page(
+            json!([{"id": "proj_1", "name": "Work"}, {"id": "proj_2", "name": "Home"}]),
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/sections"))
+        .respond_with(page(json!([{
+            "id": "sec_1",
+            "user_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "name": "Backlog"
+        }])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(page(json!([{"id": "label_1", "name": "errand"}])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(page(json!([
+            task("task_1", "proj_1"),
+            task("task_2", "proj_2"),
+            task("task_3", "proj_1")
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let workspace = todoist.load_workspace().await.unwrap();
+    assert_eq!(workspace.projects.len(), 2);
+    assert_eq!(workspace.sections.len(), 1);
+    assert_eq!(workspace.labels.len(), 1);
+    assert_eq!(workspace.tasks().count(), 3);
+    let work: Vec<&str> = workspace
+        .tasks_for_project("proj_1")
+        .iter()
+        .map(|t| t.id.as_str())
+        .collect();
+    assert_eq!(work, ["task_1", "task_3"]);
+    assert!(workspace.tasks_for_project("proj_3").is_empty());
+}
 
 #[tokio::test]
-async fn test_get_sections() {
+async fn test_load_workspace_fails_if_any_request_fails() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({"error": "Forbidden"})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let error = todoist.load_workspace().await.unwrap_err();
+    assert!(error.is_authorization_error());
+}
+
+#[tokio::test]
+async fn test_get_project_data() {
     let mock_server = MockServer::start().await;
 
+    let task = |id: &str, section_id: Option<&str>, child_order: i32| {
+        let mut task = task_json(id);
+        task["content"] = json!(id);
+        task["section_id"] = json!(section_id);
+        task["child_order"] = json!(child_order);
+        task
+    };
+    let section = |id: &str, section_order: i32| {
+        json!({
+            "id": id,
+            "user_id": "user_1",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z",
+            "name": id,
+            "section_order": section_order
+        })
+    };
+    let page =
+        |results: Value| ResponseTemplate::new(200).set_body_json(json!({"results": results, "next_cursor": null}));
+
+    Mock::given(method("GET"))
+        .and(path("/projects/proj_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "proj_1", "name": "Work"})))
+        .mount(&mock_server)
+        .await;
     Mock::given(method("GET"))
         .and(path("/sections"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "sec_123",
-                    "creator_id": "user_1",
-                    "project_id": "proj_1",
-                    "added_at": "2024-01-01T00:00:00Z",
-                    "updated_at": null,
-                    "archived_at": null,
-                    "name": "Development",
-                    "section_order": 1,
-                    "is_archived": false,
-                    "is_collapsed": false
-                }
-            ],
-            "next_cursor": null
-        })))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(page(json!([section("sec_2", 2), section("sec_1", 1)])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("project_id", "proj_1"))
+        .respond_with(page(json!([
+            task("task_4", Some("sec_1"), 2),
+            task("task_1", None, 1),
+            task("task_3", Some("sec_1"), 1),
+            task("task_5", Some("sec_gone"), 0)
+        ])))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_sections(None, None).await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].id, "sec_123");
+    let data = todoist.get_project_data("proj_1").await.unwrap();
+    assert_eq!(data.project.name, "Work");
+    let sections: Vec<(&str, Vec<&str>)> = data
+        .sections
+        .iter()
+        .map(|s| (s.section.id.as_str(), s.tasks.iter().map(|t| t.id.as_str()).collect()))
+        .collect();
+    assert_eq!(sections, [("sec_1", vec!["task_3", "task_4"]), ("sec_2", vec![])]);
+    // A task whose section was not returned falls back to the unsectioned bucket
+    let unsectioned: Vec<&str> = data.unsectioned_tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(unsectioned, ["task_5", "task_1"]);
+    assert_eq!(data.tasks().count(), 4);
 }
 
+// ===== ERROR HANDLING TESTS =====
+
 #[tokio::test]
-async fn test_get_section() {
+async fn test_error_status_codes_from_responses() {
+    let mock_server = MockServer::start().await;
+
+    for status in [400, 401, 403, 404, 409, 429, 502] {
+        Mock::given(method("GET"))
+            .and(path(format!("/labels/status_{status}")))
+            .respond_with(ResponseTemplate::new(status).set_body_json(json!({"error": "Failed"})))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    for status in [400, 401, 403, 404, 409, 429, 502] {
+        let error = todoist.get_label(format!("status_{status}")).await.unwrap_err();
+        assert_eq!(error.status_code(), Some(status), "{error:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limiting() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/sections/sec_123"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "sec_123",
-            "creator_id": "user_1",
-            "project_id": "proj_1",
-            "added_at": "2024-01-01T00:00:00Z",
-            "updated_at": null,
-            "archived_at": null,
-            "name": "Testing",
-            "section_order": 2,
-            "is_archived": false,
-            "is_collapsed": false
-        })))
+        .and(path("/tasks"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "60")
+                .set_body_json(json!({
+                    "error": "Rate limit exceeded"
+                })),
+        )
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_section("sec_123").await;
-    assert!(result.is_ok());
-    let section = result.unwrap();
-    assert_eq!(section.id, "sec_123");
-    assert_eq!(section.name, "Testing");
+    let result = todoist.get_tasks(None, None).await;
+    assert!(result.is_err());
+    match result {
+        Err(TodoistError::RateLimited {
+            retry_after, message, ..
+        }) => {
+            assert_eq!(retry_after, Some(60));
+            assert!(message.contains("Rate limit exceeded"));
+        }
+        _ => panic!("Expected RateLimited error"),
+    }
 }
 
 #[tokio::test]
-async fn test_create_section() {
+async fn test_rate_limit_status_from_headers() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("POST"))
-        .and(path("/sections"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "new_sec",
-            "creator_id": "user_1",
-            "project_id": "proj_1",
-            "added_at": "2024-01-01T00:00:00Z",
-            "updated_at": null,
-            "archived_at": null,
-            "name": "New Section",
-            "section_order": 3,
-            "is_archived": false,
-            "is_collapsed": false
-        })))
+    Mock::given(method("GET"))
+        .and(path("/labels/plain"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "plain", "name": "plain"})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels/limited"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-RateLimit-Limit", "450")
+                .insert_header("X-RateLimit-Remaining", "12")
+                .insert_header("X-RateLimit-Reset", "600")
+                .set_body_json(json!({"id": "limited", "name": "limited"})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels/exhausted"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("X-RateLimit-Remaining", "0")
+                .set_body_json(json!({"error": "Too many requests"})),
+        )
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    assert_eq!(todoist.rate_limit_status(), None);
 
-    let args = CreateSectionArgs {
-        name: "New Section".to_string(),
-        project_id: "proj_1".to_string(),
-        order: Some(3),
-    };
+    todoist.get_label("plain").await.unwrap();
+    assert_eq!(todoist.rate_limit_status(), None);
 
-    let result = todoist.create_section(&args).await;
-    assert!(result.is_ok());
-    let section = result.unwrap();
-    assert_eq!(section.id, "new_sec");
-    assert_eq!(section.name, "New Section");
+    todoist.get_label("limited").await.unwrap();
+    let expected = RateLimitStatus {
+        limit: Some(450),
+        remaining: Some(12),
+        reset: Some(600),
+    };
+    assert_eq!(todoist.rate_limit_status(), Some(expected));
+
+    // Responses without the headers keep the last status; clones share it
+    todoist.get_label("plain").await.unwrap();
+    assert_eq!(todoist.clone().rate_limit_status(), Some(expected));
+
+    assert!(todoist.get_label("exhausted").await.unwrap_err().is_rate_limited());
+    assert_eq!(
+        todoist.rate_limit_status(),
+        Some(RateLimitStatus {
+            remaining: Some(0),
+            ..Default::default()
+        })
+    );
 }
 
 #[tokio::test]
-async fn test_update_section() {
+async fn test_authentication_error() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("POST"))
-        .and(path("/sections/sec_123"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "sec_123",
-            "creator_id": "user_1",
-            "project_id": "proj_1",
-            "added_at": "2024-01-01T00:00:00Z",
-            "updated_at": "2024-01-02T00:00:00Z",
-            "archived_at": null,
-            "name": "Updated Section",
-            "section_order": 1,
-            "is_archived": false,
-            "is_collapsed": false
+    Mock::given(method("GET"))
+        .and(path("/projects"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Unauthorized"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = UpdateSectionArgs {
-        name: "Updated Section".to_string(),
-    };
-
-    let result = todoist.update_section("sec_123", &args).await;
-    assert!(result.is_ok());
-    let section = result.unwrap();
-    assert_eq!(section.name, "Updated Section");
+    let result = todoist.get_projects(None, None).await;
+    assert!(result.is_err());
+    match result {
+        Err(TodoistError::AuthenticationError { .. }) => (),
+        _ => panic!("Expected AuthenticationError"),
+    }
 }
 
 #[tokio::test]
-async fn test_delete_section() {
+async fn test_error_carries_request_id() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("DELETE"))
-        .and(path("/sections/sec_123"))
-        .respond_with(ResponseTemplate::new(204))
+    Mock::given(method("GET"))
+        .and(path("/tasks/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("X-Request-Id", "req-abc123")
+                .set_body_json(json!({
+                    "error": "Task not found"
+                })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/broken"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Request-Id", "req-def456")
+                .set_body_string("not json"),
+        )
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.delete_section("sec_123").await;
-    assert!(result.is_ok());
+    let error = todoist.get_task("missing").await.unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.request_id(), Some("req-abc123"));
+    assert!(error.to_string().ends_with("(request ID: req-abc123)"));
+
+    let error = todoist.get_task("broken").await.unwrap_err();
+    assert!(matches!(error, TodoistError::ParseError { .. }));
+    assert_eq!(error.request_id(), Some("req-def456"));
 }
 
 #[tokio::test]
-async fn test_get_sections_filtered() {
+async fn test_unexpected_field_casing_fails_loudly() {
     let mock_server = MockServer::start().await;
 
+    // A payload using camelCase names instead of the snake_case the models expect
+    let mut task = task_json("task_1");
+    let project_id = task.as_object_mut().unwrap().remove("project_id").unwrap();
+    task["projectId"] = project_id;
+
     Mock::given(method("GET"))
-        .and(path("/sections"))
-        .and(query_param("project_id", "proj_123"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "sec_1",
-                    "creator_id": "user_1",
-                    "project_id": "proj_123",
-                    "added_at": "2024-01-01T00:00:00Z",
-                    "updated_at": null,
-                    "archived_at": null,
-                    "name": "Section 1",
-                    "section_order": 1,
-                    "is_archived": false,
-                    "is_collapsed": false
-                }
-            ],
-            "next_cursor": null
-        })))
+        .and(path("/tasks/task_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = SectionFilterArgs {
-        project_id: Some("proj_123".to_string()),
-        limit: None,
-        cursor: None,
-    };
-
-    let result = todoist.get_sections_filtered(&args).await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].project_id, "proj_123");
+    let error = todoist.get_task("task_1").await.unwrap_err();
+    assert!(matches!(error, TodoistError::ParseError { .. }));
+    let message = error.to_string();
+    assert!(message.contains("/tasks/task_1"), "{message}");
+    assert!(message.contains("missing field `project_id`"), "{message}");
 }
 
-// ===== COMMENT OPERATIONS =====
-
 #[tokio::test]
-async fn test_get_comments() {
+async fn test_server_error() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/comments"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "comment_1",
-                    "content": "Test comment",
-                    "posted_at": "2024-01-01T00:00:00Z",
-                    "attachment": null,
-                    "project_id": null,
-                    "task_id": "task_1"
-                }
-            ],
-            "next_cursor": null
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "error": "Internal server error"
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_comments().await;
-    assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].id, "comment_1");
-    assert!(response.next_cursor.is_none());
+    let result = todoist.get_labels(None, None).await;
+    assert!(result.is_err());
+    match result {
+        Err(TodoistError::ServerError { status_code, .. }) => {
+            assert_eq!(status_code, 500);
+        }
+        _ => panic!("Expected ServerError"),
+    }
 }
 
 #[tokio::test]
-async fn test_get_comment() {
+async fn test_validation_error() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("GET"))
-        .and(path("/comments/comment_123"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "comment_123",
-            "content": "Single comment",
-            "posted_at": "2024-01-01T00:00:00Z",
-            "attachment": null,
-            "project_id": null,
-            "task_id": "task_1"
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "Invalid argument value",
+            "error_code": 20,
+            "error_tag": "INVALID_ARGUMENT_VALUE",
+            "error_extra": {"argument": "due_string", "event_id": "abc"},
+            "http_code": 400
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.get_comment("comment_123").await;
-    assert!(result.is_ok());
-    let comment = result.unwrap();
-    assert_eq!(comment.id, "comment_123");
-    assert_eq!(comment.content, "Single comment");
+    let args = CreateTaskArgs {
+        content: "Buy milk".to_string(),
+        due_string: Some("someday maybe".to_string()),
+        ..Default::default()
+    };
+
+    let result = todoist.create_task(&args).await;
+    assert!(result.is_err());
+    match result {
+        Err(TodoistError::ValidationError { field, .. }) => assert_eq!(field.as_deref(), Some("due_string")),
+        _ => panic!("Expected ValidationError"),
+    }
 }
 
 #[tokio::test]
-async fn test_create_comment() {
+async fn test_invalid_task_args_rejected_before_sending() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/comments"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "new_comment",
-            "content": "New comment",
-            "posted_at": "2024-01-01T00:00:00Z",
-            "attachment": null,
-            "project_id": null,
-            "task_id": "task_1"
-        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_1")))
+        .expect(0)
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = CreateCommentArgs {
-        content: "New comment".to_string(),
-        task_id: Some("task_1".to_string()),
-        project_id: None,
-        attachment: None,
+    let field = |error: TodoistError| match error {
+        TodoistError::ValidationError { field, .. } => field,
+        other => panic!("Expected ValidationError, got {:?}", other),
+    };
+
+    let error = todoist
+        .create_task(&CreateTaskArgs::builder("  ").build())
+        .await
+        .unwrap_err();
+    assert_eq!(field(error).as_deref(), Some("content"));
+
+    let args = CreateTaskArgs {
+        content: "Buy milk".to_string(),
+        due_string: Some("tomorrow".to_string()),
+        due_date: Some("2025-01-01".to_string()),
+        ..Default::default()
+    };
+    let error = todoist.create_task(&args).await.unwrap_err();
+    assert_eq!(field(error).as_deref(), Some("due_date"));
+
+    let args = CreateTaskArgs {
+        content: "Buy milk".to_string(),
+        duration: Some(30),
+        ..Default::default()
     };
+    let results = todoist.create_tasks(&[args]).await;
+    assert_eq!(field(results[0].clone().unwrap_err()).as_deref(), Some("duration_unit"));
 
-    let result = todoist.create_comment(&args).await;
-    assert!(result.is_ok());
-    let comment = result.unwrap();
-    assert_eq!(comment.id, "new_comment");
-    assert_eq!(comment.content, "New comment");
+    let args = UpdateTaskArgs {
+        content: Some(String::new()),
+        ..Default::default()
+    };
+    let error = todoist.update_task("task_1", &args).await.unwrap_err();
+    assert_eq!(field(error).as_deref(), Some("content"));
+
+    // The API only takes task durations in minutes or days
+    let args = CreateTaskArgs::builder("Deep work")
+        .duration(2)
+        .duration_unit(DurationUnit::Hour)
+        .build();
+    let error = todoist.create_task(&args).await.unwrap_err();
+    assert_eq!(field(error).as_deref(), Some("duration_unit"));
+    let args = UpdateTaskArgs {
+        duration: Some(Some(2)),
+        duration_unit: Some(Some(DurationUnit::Hour)),
+        ..Default::default()
+    };
+    let error = todoist.update_task("task_1", &args).await.unwrap_err();
+    assert_eq!(field(error).as_deref(), Some("duration_unit"));
 }
 
 #[tokio::test]
-async fn test_update_comment() {
+async fn test_structured_error_body_is_parsed() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
-        .and(path("/comments/comment_123"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": "comment_123",
-            "content": "Updated comment",
-            "posted_at": "2024-01-01T00:00:00Z",
-            "attachment": null,
-            "project_id": null,
-            "task_id": "task_1"
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "Invalid date format",
+            "error_code": 22,
+            "error_tag": "INVALID_DATE_FORMAT",
+            "http_code": 400
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "error": "Maximum number of projects reached",
+            "error_code": 49,
+            "error_tag": "MAX_PROJECTS_LIMIT_REACHED",
+            "http_code": 403
         })))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let args = UpdateCommentArgs {
-        content: "Updated comment".to_string(),
-    };
+    let error = todoist
+        .create_task(&CreateTaskArgs::builder("Task").due_date("tomorrow").build())
+        .await
+        .unwrap_err();
+    match &error {
+        TodoistError::ValidationError {
+            message,
+            error_code,
+            error_tag,
+            ..
+        } => {
+            assert_eq!(message, "Invalid date format");
+            assert_eq!(*error_code, Some(22));
+            assert_eq!(error_tag.as_deref(), Some("INVALID_DATE_FORMAT"));
+        }
+        _ => panic!("Expected ValidationError, got {:?}", error),
+    }
 
-    let result = todoist.update_comment("comment_123", &args).await;
-    assert!(result.is_ok());
-    let comment = result.unwrap();
-    assert_eq!(comment.content, "Updated comment");
+    let args = CreateProjectArgs {
+        name: "One too many".to_string(),
+        ..Default::default()
+    };
+    let error = todoist.create_project(&args).await.unwrap_err();
+    assert!(error.is_authorization_error());
+    assert_eq!(error.error_code(), Some(49));
+    assert_eq!(error.error_tag(), Some("MAX_PROJECTS_LIMIT_REACHED"));
+    assert_eq!(
+        error.to_string(),
+        "Authorization error: Maximum number of projects reached"
+    );
 }
 
 #[tokio::test]
-async fn test_delete_comment() {
+async fn test_unstructured_error_body_falls_back_to_raw_text() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("DELETE"))
-        .and(path("/comments/comment_123"))
-        .respond_with(ResponseTemplate::new(204))
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Bad Request"))
         .mount(&mock_server)
         .await;
 
     let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
 
-    let result = todoist.delete_comment("comment_123").await;
-    assert!(result.is_ok());
+    let error = todoist
+        .create_task(&CreateTaskArgs::builder("Task").build())
+        .await
+        .unwrap_err();
+    assert!(error.is_validation_error());
+    assert_eq!(error.error_code(), None);
+    assert_eq!(error.error_tag(), None);
+    assert_eq!(error.to_string(), "Validation error: Bad Request");
 }
 
 #[tokio::test]
-async fn test_get_comments_filtered() {
+async fn test_retry_on_rate_limit_then_success() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/comments"))
-        .and(query_param("task_id", "task_123"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(json!({
+            "error": "Rate limit exceeded"
+        })))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "results": [
-                {
-                    "id": "comment_1",
-                    "content": "Task comment",
-                    "posted_at": "2024-01-01T00:00:00Z",
-                    "attachment": null,
-                    "project_id": null,
-                    "task_id": "task_123"
-                }
-            ],
+            "results": [],
             "next_cursor": null
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
-    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
-
-    let args = CommentFilterArgs {
-        task_id: Some("task_123".to_string()),
-        project_id: None,
-        limit: None,
-        cursor: None,
-    };
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        max_retries: 3,
+        base_delay: std::time::Duration::from_millis(1),
+        ..Default::default()
+    });
 
-    let result = todoist.get_comments_filtered(&args).await;
+    let result = todoist.get_labels(None, None).await;
     assert!(result.is_ok());
-    let response = result.unwrap();
-    assert_eq!(response.results.len(), 1);
-    assert_eq!(response.results[0].task_id, Some("task_123".to_string()));
-    assert!(response.next_cursor.is_none());
 }
 
-// ===== ERROR HANDLING TESTS =====
+#[tokio::test]
+async fn test_retried_create_reuses_request_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(json!({
+            "error": "Rate limit exceeded"
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "task_1",
+            "user_id": "user_1",
+            "content": "Buy milk",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        base_delay: std::time::Duration::from_millis(1),
+        ..Default::default()
+    });
+    let args = CreateTaskArgs::builder("Buy milk").build();
+
+    todoist.create_task(&args).await.unwrap();
+    todoist.create_task(&args).await.unwrap();
+    todoist.create_task_with_request_id(&args, "my-key-1").await.unwrap();
+
+    let ids: Vec<String> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| request.headers["X-Request-Id"].to_str().unwrap().to_string())
+        .collect();
+    assert_eq!(ids.len(), 4);
+    // The rate-limited attempt and its retry share an ID; separate calls do not
+    assert_eq!(ids[0], ids[1]);
+    assert_ne!(ids[1], ids[2]);
+    assert_eq!(ids[3], "my-key-1");
+}
 
 #[tokio::test]
-async fn test_rate_limiting() {
+async fn test_retry_exhausted_returns_rate_limited() {
     let mock_server = MockServer::start().await;
 
-    Mock::given(method("GET"))
+    Mock::given(method("POST"))
         .and(path("/tasks"))
         .respond_with(
             ResponseTemplate::new(429)
-                .insert_header("Retry-After", "60")
+                .insert_header("Retry-After", "0")
                 .set_body_json(json!({
                     "error": "Rate limit exceeded"
                 })),
         )
+        .expect(3)
         .mount(&mock_server)
         .await;
 
-    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        max_retries: 2,
+        base_delay: std::time::Duration::from_millis(1),
+        ..Default::default()
+    });
 
-    let result = todoist.get_tasks(None, None).await;
-    assert!(result.is_err());
+    let args = CreateTaskArgs {
+        content: "Task".to_string(),
+        ..Default::default()
+    };
+    let result = todoist.create_task(&args).await;
     match result {
-        Err(TodoistError::RateLimited { retry_after, message }) => {
-            assert_eq!(retry_after, Some(60));
-            assert!(message.contains("Rate limit exceeded"));
-        }
+        Err(TodoistError::RateLimited { retry_after, .. }) => assert_eq!(retry_after, Some(0)),
         _ => panic!("Expected RateLimited error"),
     }
 }
 
 #[tokio::test]
-async fn test_authentication_error() {
+async fn test_retry_after_exceeding_max_delay_is_not_waited() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
-        .and(path("/projects"))
-        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
-            "error": "Unauthorized"
-        })))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
-    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    let todoist =
+        TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig::default());
 
-    let result = todoist.get_projects(None, None).await;
-    assert!(result.is_err());
-    match result {
-        Err(TodoistError::AuthenticationError { .. }) => (),
-        _ => panic!("Expected AuthenticationError"),
-    }
+    let result = todoist.get_tasks(None, None).await;
+    assert_eq!(result.unwrap_err().retry_after(), Some(3600));
 }
 
 #[tokio::test]
-async fn test_server_error() {
+async fn test_retry_on_server_error_for_get() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("GET"))
         .and(path("/labels"))
-        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
-            "error": "Internal server error"
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "next_cursor": null
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
-    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        base_delay: std::time::Duration::from_millis(1),
+        ..Default::default()
+    });
 
-    let result = todoist.get_labels(None, None).await;
-    assert!(result.is_err());
-    match result {
-        Err(TodoistError::ServerError { status_code, .. }) => {
-            assert_eq!(status_code, 500);
+    assert!(todoist.get_labels(None, None).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_request_observer_sees_each_attempt() {
+    #[derive(Default)]
+    struct Recorder(std::sync::Mutex<Vec<String>>);
+
+    impl RequestObserver for Recorder {
+        fn on_request(&self, request: &RequestInfo<'_>) {
+            let event = format!("request {} {} #{}", request.method, request.endpoint, request.attempt);
+            self.0.lock().unwrap().push(event);
+        }
+
+        fn on_response(&self, response: &ResponseInfo<'_>) {
+            let error = response.error.map_or("ok", TodoistError::variant_name);
+            let event = format!("response {:?} {} #{}", response.status, error, response.attempt);
+            self.0.lock().unwrap().push(event);
         }
-        _ => panic!("Expected ServerError"),
     }
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "next_cursor": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let recorder = std::sync::Arc::new(Recorder::default());
+    let todoist = TodoistWrapper::builder("test-token")
+        .base_url(mock_server.uri())
+        .retry(RetryConfig {
+            base_delay: std::time::Duration::from_millis(1),
+            ..Default::default()
+        })
+        .observer(recorder.clone())
+        .build();
+
+    todoist.get_labels(None, None).await.unwrap();
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        [
+            "request GET /labels #0",
+            "response Some(503) ServerError #0",
+            "request GET /labels #1",
+            "response Some(200) ok #1"
+        ]
+    );
 }
 
 #[tokio::test]
-async fn test_validation_error() {
+async fn test_retry_on_server_error_for_post_with_request_id() {
     let mock_server = MockServer::start().await;
 
     Mock::given(method("POST"))
         .and(path("/tasks"))
-        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
-            "error": "Invalid content"
-        })))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(1)
+        .expect(1)
         .mount(&mock_server)
         .await;
 
-    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+    Mock::given(method("POST"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("task_1")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
-    let args = CreateTaskArgs {
-        content: "".to_string(),
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        base_delay: std::time::Duration::from_millis(1),
+        ..Default::default()
+    });
+
+    let args = CreateTaskArgs::builder("Buy milk").build();
+    assert_eq!(todoist.create_task(&args).await.unwrap().id, "task_1");
+}
+
+#[tokio::test]
+async fn test_sync_post_is_not_retried_on_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri()).with_retry(RetryConfig {
+        base_delay: std::time::Duration::from_millis(1),
         ..Default::default()
+    });
+
+    let result = todoist.collapse_section("section_1").await;
+    assert!(matches!(
+        result,
+        Err(TodoistError::ServerError { status_code: 500, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_rate_limiting_retry_after_http_date() {
+    let mock_server = MockServer::start().await;
+
+    let retry_at = std::time::SystemTime::now() + std::time::Duration::from_secs(120);
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", httpdate::fmt_http_date(retry_at)))
+        .mount(&mock_server)
+        .await;
+
+    let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+    let retry_after = todoist.get_tasks(None, None).await.unwrap_err().retry_after();
+    assert!(
+        matches!(retry_after, Some(118..=120)),
+        "unexpected retry_after: {:?}",
+        retry_after
+    );
+}
+
+#[cfg(feature = "futures")]
+mod streams {
+    use super::task_json;
+    use futures_util::{StreamExt, TryStreamExt};
+    use serde_json::json;
+    use todoist_api::*;
+    use wiremock::{
+        matchers::{method, path, query_param, query_param_is_missing},
+        Mock, MockServer, ResponseTemplate,
     };
 
-    let result = todoist.create_task(&args).await;
-    assert!(result.is_err());
-    match result {
-        Err(TodoistError::ValidationError { .. }) => (),
-        _ => panic!("Expected ValidationError"),
+    #[tokio::test]
+    async fn test_get_all_tasks_stream_follows_cursor() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/tasks"))
+            .and(query_param("limit", "200"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [task_json("task_1"), task_json("task_2")],
+                "next_cursor": "page_2"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/tasks"))
+            .and(query_param("cursor", "page_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [task_json("task_3")],
+                "next_cursor": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+        let tasks: Vec<Task> = todoist.get_all_tasks_stream().try_collect().await.unwrap();
+        let ids: Vec<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, ["task_1", "task_2", "task_3"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_dropped_early_fetches_no_more_pages() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/projects"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [
+                    {"id": "proj_1", "name": "Inbox", "color": "grey", "is_shared": false,
+                     "is_favorite": false, "inbox_project": true, "view_style": "list"},
+                    {"id": "proj_2", "name": "Work", "color": "blue", "is_shared": false,
+                     "is_favorite": false, "inbox_project": false, "view_style": "list"}
+                ],
+                "next_cursor": "page_2"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/projects"))
+            .and(query_param("cursor", "page_2"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+        let first: Vec<TodoistResult<Project>> = todoist.get_all_projects_stream().take(2).collect().await;
+        assert_eq!(first.len(), 2);
+        assert!(first.iter().all(|project| project.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_stream_ends_after_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/comments"))
+            .and(query_param("task_id", "task_1"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{
+                    "id": "comment_1",
+                    "content": "First",
+                    "posted_at": "2024-01-01T00:00:00Z",
+                    "item_id": "task_1"
+                }],
+                "next_cursor": "page_2"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/comments"))
+            .and(query_param("cursor", "page_2"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("Internal error"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let todoist = TodoistWrapper::with_base_url("test-token".to_string(), mock_server.uri());
+
+        let args = CommentFilterArgs {
+            task_id: Some("task_1".to_string()),
+            project_id: None,
+            limit: None,
+            cursor: None,
+        };
+        let items: Vec<TodoistResult<Comment>> = todoist.get_all_comments_stream(&args).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_ok());
+        assert!(matches!(items[1], Err(TodoistError::ServerError { .. })));
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_feature {
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use todoist_api::*;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    /// Minimal subscriber recording this crate's events as `LEVEL field=value ...`
+    #[derive(Clone, Default)]
+    struct Recorder {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct FieldWriter<'a>(&'a mut String);
+
+    impl Visit for FieldWriter<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target().starts_with("todoist_api")
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut line = event.metadata().level().to_string();
+            event.record(&mut FieldWriter(&mut line));
+            self.events.lock().unwrap().push(line);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_traced_without_token() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/labels"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"results": [], "next_cursor": null})))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/projects/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("Not found"))
+            .mount(&mock_server)
+            .await;
+
+        let recorder = Recorder::default();
+        let _guard = tracing::dispatcher::set_default(&tracing::Dispatch::new(recorder.clone()));
+
+        let todoist = TodoistWrapper::with_base_url("secret-token".to_string(), mock_server.uri());
+        todoist.get_labels(None, None).await.unwrap();
+        todoist.get_project("missing").await.unwrap_err();
+
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].starts_with("DEBUG"));
+        assert!(events[0].contains("method=\"GET\""));
+        assert!(events[0].contains("endpoint=\"/labels\""));
+        assert!(events[0].contains("status=200"));
+        assert!(events[0].contains("elapsed_ms="));
+        assert!(events[1].starts_with("WARN"));
+        assert!(events[1].contains("status=404"));
+        assert!(events[1].contains("error=\"NotFound\""));
+        assert!(events.iter().all(|event| !event.contains("secret-token")));
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_feature {
+    use serde_json::json;
+    use todoist_api::blocking;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn test_blocking_client_mirrors_async_methods() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/tasks"))
+            .and(header("Authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "task_1",
+                "user_id": "user_1",
+                "content": "Write report",
+                "description": "",
+                "project_id": "proj_1",
+                "labels": [],
+                "added_at": "2024-01-01T00:00:00Z",
+                "priority": 1,
+                "child_order": 0,
+                "note_count": 0,
+                "day_order": 0,
+                "is_collapsed": false
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/tasks/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": "Task not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let base_url = mock_server.uri();
+        // The blocking client runs its own runtime, so drive it from a blocking thread
+        let (created, deleted) = tokio::task::spawn_blocking(move || {
+            let todoist = blocking::TodoistWrapper::with_base_url("test-token".to_string(), base_url);
+            let args = todoist_api::CreateTaskArgs::builder("Write report").build();
+            (todoist.create_task(&args), todoist.delete_task("missing"))
+        })
+        .await
+        .unwrap();
+
+        let task = created.unwrap();
+        assert_eq!(task.id, "task_1");
+        assert_eq!(task.content, "Write report");
+        assert!(deleted.unwrap_err().is_not_found());
     }
 }
@@ -0,0 +1,74 @@
+use serde_json::json;
+use todoist_api::mock::MockBackend;
+use todoist_api::*;
+
+#[tokio::test]
+async fn test_mock_returns_registered_json() {
+    let mock = MockBackend::new().with_json(
+        "GET",
+        "/tasks/task_1",
+        json!({
+            "id": "task_1",
+            "user_id": "user_1",
+            "content": "Buy milk",
+            "project_id": "proj_1",
+            "added_at": "2024-01-01T00:00:00Z"
+        }),
+    );
+    let todoist = TodoistWrapper::with_mock(mock.clone());
+
+    let task = todoist.get_task("task_1").await.unwrap();
+    assert_eq!(task.content, "Buy milk");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].endpoint, "/tasks/task_1");
+}
+
+#[tokio::test]
+async fn test_mock_records_request_body_and_query() {
+    let mock = MockBackend::new()
+        .with_response("DELETE", "labels/label_1", 204, "")
+        .with_json(
+            "POST",
+            "/labels",
+            json!({"id": "label_2", "name": "errand", "color": "red", "order": 1, "is_favorite": false}),
+        )
+        .with_json("GET", "/labels", json!({"results": [], "next_cursor": null}));
+    let todoist = TodoistWrapper::with_mock(mock.clone());
+
+    todoist.delete_label("label_1").await.unwrap();
+    let args = CreateLabelArgs {
+        name: "errand".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(todoist.create_label(&args).await.unwrap().id, "label_2");
+    todoist.get_labels(Some(5), None).await.unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].method, "DELETE");
+    assert_eq!(requests[0].body, "");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&requests[1].body).unwrap(),
+        json!({"name": "errand"})
+    );
+    assert_eq!(requests[2].query.as_deref(), Some("limit=5"));
+}
+
+#[tokio::test]
+async fn test_mock_unregistered_endpoint_is_not_found() {
+    let todoist = TodoistWrapper::with_mock(MockBackend::new());
+
+    let error = todoist.get_project("proj_1").await.unwrap_err();
+    assert!(error.is_not_found());
+}
+
+#[tokio::test]
+async fn test_mock_error_status() {
+    let mock = MockBackend::new().with_response("GET", "/projects", 401, r#"{"error": "Invalid token"}"#);
+    let todoist = TodoistWrapper::with_mock(mock);
+
+    let error = todoist.get_projects(None, None).await.unwrap_err();
+    assert!(error.is_authentication_error());
+}
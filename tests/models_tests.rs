@@ -18,12 +18,12 @@ fn test_task_creation() {
         duration: None,
         checked: false,
         is_deleted: false,
-        added_at: "2024-01-01T00:00:00Z".to_string(),
+        added_at: "2024-01-01T00:00:00Z".parse().unwrap(),
         completed_at: None,
         completed_by_uid: None,
         updated_at: None,
         due: None,
-        priority: 3,
+        priority: Priority::High,
         child_order: 0,
         note_count: 0,
         day_order: 0,
@@ -34,7 +34,7 @@ fn test_task_creation() {
     assert_eq!(task.content, "Test task");
     assert_eq!(task.description, "Test description");
     assert_eq!(task.project_id, "proj_123");
-    assert_eq!(task.priority, 3);
+    assert_eq!(task.priority, Priority::High);
     assert_eq!(task.labels.len(), 2);
     assert!(task.labels.contains(&"test".to_string()));
     assert!(task.labels.contains(&"important".to_string()));
@@ -45,11 +45,11 @@ fn test_project_creation() {
     let project = Project {
         id: "proj_123".to_string(),
         name: "Test Project".to_string(),
-        color: "blue".to_string(),
+        color: Color::Blue,
         is_shared: false,
         is_favorite: true,
         inbox_project: false,
-        view_style: "list".to_string(),
+        view_style: ViewStyle::List,
         parent_id: None,
         child_order: 0,
         creator_uid: None,
@@ -68,11 +68,11 @@ fn test_project_creation() {
 
     assert_eq!(project.id, "proj_123");
     assert_eq!(project.name, "Test Project");
-    assert_eq!(project.color, "blue");
+    assert_eq!(project.color, Color::Blue);
     assert!(!project.is_shared);
     assert!(project.is_favorite);
     assert!(!project.inbox_project);
-    assert_eq!(project.view_style, "list");
+    assert_eq!(project.view_style, ViewStyle::List);
 }
 
 #[test]
@@ -80,14 +80,15 @@ fn test_label_creation() {
     let label = Label {
         id: "label_123".to_string(),
         name: "Important".to_string(),
-        color: "red".to_string(),
+        color: Color::Red,
         order: Some(1),
         is_favorite: true,
+        is_deleted: false,
     };
 
     assert_eq!(label.id, "label_123");
     assert_eq!(label.name, "Important");
-    assert_eq!(label.color, "red");
+    assert_eq!(label.color, Color::Red);
     assert_eq!(label.order, Some(1));
     assert!(label.is_favorite);
 }
@@ -98,7 +99,7 @@ fn test_section_creation() {
         id: "section_123".to_string(),
         user_id: "user123".to_string(),
         project_id: "proj_123".to_string(),
-        added_at: "2024-01-01T00:00:00Z".to_string(),
+        added_at: "2024-01-01T00:00:00Z".parse().unwrap(),
         updated_at: None,
         archived_at: None,
         name: "Development".to_string(),
@@ -119,7 +120,7 @@ fn test_comment_creation() {
     let comment = Comment {
         id: "comment_123".to_string(),
         content: "This is a comment".to_string(),
-        posted_at: Some("2024-01-01T00:00:00Z".to_string()),
+        posted_at: Some("2024-01-01T00:00:00Z".parse().unwrap()),
         posted_uid: None,
         file_attachment: None,
         uids_to_notify: None,
@@ -131,24 +132,36 @@ fn test_comment_creation() {
 
     assert_eq!(comment.id, "comment_123");
     assert_eq!(comment.content, "This is a comment");
-    assert_eq!(comment.posted_at, Some("2024-01-01T00:00:00Z".to_string()));
+    assert_eq!(comment.posted_at, Some("2024-01-01T00:00:00Z".parse().unwrap()));
     assert!(comment.task_id.is_some());
     assert!(comment.project_id.is_none());
 }
 
+#[test]
+fn test_label_optional_fields() {
+    // REST responses omit the flag; Sync API responses report soft-deleted labels
+    let rest: Label = serde_json::from_str(r#"{"id": "1", "name": "errand"}"#).unwrap();
+    assert!(!rest.is_deleted);
+    // Shared labels may come without an order
+    assert_eq!(rest.order, None);
+
+    let synced: Label = serde_json::from_str(r#"{"id": "1", "name": "errand", "is_deleted": true}"#).unwrap();
+    assert!(synced.is_deleted);
+}
+
 #[test]
 fn test_attachment_creation() {
     let attachment = Attachment {
         file_name: "document.pdf".to_string(),
         file_type: "application/pdf".to_string(),
         file_url: "https://example.com/document.pdf".to_string(),
-        resource_type: "file".to_string(),
+        resource_type: ResourceType::File,
     };
 
     assert_eq!(attachment.file_name, "document.pdf");
     assert_eq!(attachment.file_type, "application/pdf");
     assert_eq!(attachment.file_url, "https://example.com/document.pdf");
-    assert_eq!(attachment.resource_type, "file");
+    assert_eq!(attachment.resource_type, ResourceType::File);
 }
 
 #[test]
@@ -160,6 +173,7 @@ fn test_user_creation() {
         avatar_url: Some("https://example.com/avatar.jpg".to_string()),
         is_premium: true,
         is_business_account: false,
+        tz_info: None,
     };
 
     assert_eq!(user.id, "user_123");
@@ -170,45 +184,114 @@ fn test_user_creation() {
     assert!(!user.is_business_account);
 }
 
+#[test]
+fn test_user_deserialization_from_api_fields() {
+    let json = r#"{
+        "id": "user_123",
+        "full_name": "Jane Doe",
+        "email": "jane@example.com",
+        "is_premium": false,
+        "business_account_id": 42,
+        "tz_info": {"timezone": "America/New_York", "hours": -5, "minutes": 0, "is_dst": 1, "gmt_string": "-05:00"}
+    }"#;
+
+    let user: User = serde_json::from_str(json).unwrap();
+    assert_eq!(user.name, "Jane Doe");
+    assert!(user.avatar_url.is_none());
+    assert!(user.is_business_account);
+    assert_eq!(user.timezone(), Some("America/New_York"));
+    let tz_info = user.tz_info.as_ref().unwrap();
+    assert_eq!(tz_info.hours, -5);
+    assert!(tz_info.is_dst);
+
+    // A serialized user deserializes back to the same values
+    let round_trip: User = serde_json::from_value(serde_json::to_value(&user).unwrap()).unwrap();
+    assert_eq!(round_trip.name, "Jane Doe");
+    assert!(round_trip.is_business_account);
+    assert_eq!(round_trip.timezone(), Some("America/New_York"));
+}
+
 #[test]
 fn test_due_creation() {
     let due = Due {
         string: "tomorrow at 12:00".to_string(),
-        date: "2024-01-02".to_string(),
+        date: "2024-01-02".parse().unwrap(),
         is_recurring: false,
-        datetime: Some("2024-01-02T12:00:00Z".to_string()),
+        datetime: Some("2024-01-02T12:00:00Z".parse().unwrap()),
         timezone: Some("UTC".to_string()),
-        lang: Some("en".to_string()),
+        lang: Some(Lang::English),
     };
 
     assert_eq!(due.string, "tomorrow at 12:00");
-    assert_eq!(due.date, "2024-01-02");
+    assert_eq!(due.date.to_string(), "2024-01-02");
     assert!(!due.is_recurring);
     assert!(due.datetime.is_some());
     assert!(due.timezone.is_some());
-    assert_eq!(due.lang, Some("en".to_string()));
+    assert_eq!(due.lang, Some(Lang::English));
+}
+
+#[test]
+fn test_due_timezone_kinds() {
+    let due = |datetime: Option<&str>, timezone: Option<&str>| -> Due {
+        serde_json::from_value(serde_json::json!({
+            "date": "2024-01-18",
+            "string": "Jan 18",
+            "lang": "en",
+            "is_recurring": false,
+            "datetime": datetime,
+            "timezone": timezone
+        }))
+        .unwrap()
+    };
+
+    let fixed = due(Some("2024-01-18T13:00:00Z"), Some("Europe/Paris"));
+    assert!(fixed.has_time());
+    assert!(!fixed.is_floating());
+    assert_eq!(fixed.timezone.as_deref(), Some("Europe/Paris"));
+
+    // A null timezone marks a floating datetime, which round-trips as null
+    let floating = due(Some("2024-01-18T14:00:00"), None);
+    assert!(floating.has_time());
+    assert!(floating.is_floating());
+    assert_eq!(
+        serde_json::to_value(&floating).unwrap()["timezone"],
+        serde_json::Value::Null
+    );
+
+    let all_day = due(None, None);
+    assert!(!all_day.has_time());
+    assert!(!all_day.is_floating());
 }
 
 #[test]
 fn test_deadline_creation() {
     let deadline = Deadline {
-        date: "2024-01-15".to_string(),
-        lang: Some("en".to_string()),
+        date: "2024-01-15".parse().unwrap(),
+        lang: Some(Lang::English),
     };
 
-    assert_eq!(deadline.date, "2024-01-15");
-    assert_eq!(deadline.lang, Some("en".to_string()));
+    assert_eq!(deadline.date.to_string(), "2024-01-15");
+    assert_eq!(deadline.lang, Some(Lang::English));
+}
+
+#[test]
+fn test_deadline_round_trip() {
+    let json = serde_json::json!({"date": "2024-03-31", "lang": "en"});
+
+    let deadline: Deadline = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(deadline.date.to_string(), "2024-03-31");
+    assert_eq!(serde_json::to_value(&deadline).unwrap(), json);
 }
 
 #[test]
 fn test_duration_creation() {
     let duration = Duration {
         amount: 30,
-        unit: "minute".to_string(),
+        unit: DurationUnit::Minute,
     };
 
     assert_eq!(duration.amount, 30);
-    assert_eq!(duration.unit, "minute");
+    assert_eq!(duration.unit, DurationUnit::Minute);
 }
 
 #[test]
@@ -251,6 +334,106 @@ fn test_update_task_args_default() {
     assert!(args.duration_unit.is_none());
 }
 
+#[test]
+fn test_update_task_args_clearing_fields() {
+    // `None` leaves a field unchanged, `Some(None)` sends an explicit null to remove it
+    let args = UpdateTaskArgs {
+        deadline_date: Some(None),
+        duration: Some(Some(45)),
+        duration_unit: Some(Some(DurationUnit::Minute)),
+        ..Default::default()
+    };
+    assert!(args.has_updates());
+    assert_eq!(
+        serde_json::to_value(&args).unwrap(),
+        serde_json::json!({"deadline_date": null, "duration": 45, "duration_unit": "minute"})
+    );
+
+    assert_eq!(
+        serde_json::to_value(UpdateTaskArgs::clear_due()).unwrap(),
+        serde_json::json!({"due_string": "no date"})
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateTaskArgs::clear_labels()).unwrap(),
+        serde_json::json!({"labels": []})
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateTaskArgs::clear_deadline()).unwrap(),
+        serde_json::json!({"deadline_date": null})
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateTaskArgs::clear_duration()).unwrap(),
+        serde_json::json!({"duration": null, "duration_unit": null})
+    );
+}
+
+#[test]
+fn test_task_args_from_task() {
+    let mut task: Task = serde_json::from_value(serde_json::json!({
+        "id": "task_1",
+        "user_id": "user_1",
+        "content": "Water plants",
+        "description": "",
+        "project_id": "proj_1",
+        "section_id": "sec_1",
+        "parent_id": null,
+        "labels": ["home"],
+        "deadline": {"date": "2024-02-01"},
+        "duration": null,
+        "added_at": "2024-01-01T00:00:00Z",
+        "due": {
+            "date": "2024-01-20",
+            "string": "every saturday",
+            "lang": "en",
+            "is_recurring": true
+        },
+        "priority": 2,
+        "child_order": 0,
+        "day_order": 0,
+        "is_collapsed": false
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(CreateTaskArgs::from(&task)).unwrap(),
+        serde_json::json!({
+            "content": "Water plants",
+            "project_id": "proj_1",
+            "section_id": "sec_1",
+            "priority": 2,
+            "labels": ["home"],
+            "due_string": "every saturday",
+            "due_lang": "en",
+            "deadline_date": "2024-02-01"
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateTaskArgs::from(&task)).unwrap(),
+        serde_json::json!({
+            "content": "Water plants",
+            "description": "",
+            "priority": 2,
+            "labels": ["home"],
+            "due_string": "every saturday",
+            "due_lang": "en",
+            "deadline_date": "2024-02-01"
+        })
+    );
+
+    // A one-off due datetime is copied exactly rather than re-parsed from its string
+    task.due = serde_json::from_value(serde_json::json!({
+        "date": "2024-01-20",
+        "string": "Jan 20 at 9am",
+        "is_recurring": false,
+        "datetime": "2024-01-20T08:00:00Z",
+        "timezone": "Europe/Paris"
+    }))
+    .unwrap();
+    let args = UpdateTaskArgs::from(&task);
+    assert_eq!(args.due_datetime.as_deref(), Some("2024-01-20T08:00:00Z"));
+    assert!(args.due_string.is_none());
+}
+
 #[test]
 fn test_create_project_args_default() {
     let args = CreateProjectArgs::default();
@@ -272,6 +455,53 @@ fn test_update_project_args_default() {
     assert!(args.view_style.is_none());
 }
 
+#[test]
+fn test_update_project_args_only_sends_set_fields() {
+    // Unset fields are omitted, so a partial update never resets the others
+    let cases = [
+        (
+            UpdateProjectArgs {
+                name: Some("Renamed".to_string()),
+                ..Default::default()
+            },
+            serde_json::json!({"name": "Renamed"}),
+        ),
+        (
+            UpdateProjectArgs {
+                color: Some(Color::Teal),
+                ..Default::default()
+            },
+            serde_json::json!({"color": "teal"}),
+        ),
+        (
+            UpdateProjectArgs {
+                is_favorite: Some(false),
+                ..Default::default()
+            },
+            serde_json::json!({"is_favorite": false}),
+        ),
+        (
+            UpdateProjectArgs {
+                view_style: Some(ViewStyle::Board),
+                ..Default::default()
+            },
+            serde_json::json!({"view_style": "board"}),
+        ),
+        (
+            UpdateProjectArgs {
+                name: Some("Renamed".to_string()),
+                view_style: Some(ViewStyle::Calendar),
+                ..Default::default()
+            },
+            serde_json::json!({"name": "Renamed", "view_style": "calendar"}),
+        ),
+    ];
+    for (args, expected) in cases {
+        assert!(args.has_updates());
+        assert_eq!(serde_json::to_value(&args).unwrap(), expected);
+    }
+}
+
 #[test]
 fn test_create_label_args_default() {
     let args = CreateLabelArgs::default();
@@ -305,7 +535,9 @@ fn test_create_section_args_default() {
 fn test_update_section_args_default() {
     let args = UpdateSectionArgs::default();
 
-    assert_eq!(args.name, "");
+    assert_eq!(args.name, None);
+    assert_eq!(args.order, None);
+    assert!(!args.has_updates());
 }
 
 #[test]
@@ -329,13 +561,14 @@ fn test_update_comment_args_default() {
 fn test_filter_args_creation() {
     let task_filter = TaskFilterArgs {
         query: "today".to_string(),
-        lang: Some("en".to_string()),
+        lang: Some(Lang::English),
         limit: Some(20),
         cursor: None,
+        ..Default::default()
     };
 
     assert_eq!(task_filter.query, "today");
-    assert_eq!(task_filter.lang, Some("en".to_string()));
+    assert_eq!(task_filter.lang, Some(Lang::English));
     assert_eq!(task_filter.limit, Some(20));
     assert!(task_filter.cursor.is_none());
 
@@ -393,6 +626,28 @@ fn test_task_deserialization_from_api_format() {
     assert!(!task.is_deleted);
 }
 
+#[test]
+fn test_task_duration_null_or_string_amount() {
+    let mut json = serde_json::to_value(sortable_task("task_1", 1, None, 0)).unwrap();
+
+    json["duration"] = serde_json::Value::Null;
+    let task: Task = serde_json::from_value(json.clone()).unwrap();
+    assert!(task.duration.is_none());
+
+    json.as_object_mut().unwrap().remove("duration");
+    let task: Task = serde_json::from_value(json.clone()).unwrap();
+    assert!(task.duration.is_none());
+
+    json["duration"] = serde_json::json!({"amount": "45", "unit": "minute"});
+    let task: Task = serde_json::from_value(json.clone()).unwrap();
+    let duration = task.duration.unwrap();
+    assert_eq!(duration.amount, 45);
+    assert_eq!(duration.unit, DurationUnit::Minute);
+
+    json["duration"] = serde_json::json!({"amount": "soon", "unit": "minute"});
+    assert!(serde_json::from_value::<Task>(json).is_err());
+}
+
 #[test]
 fn test_project_deserialization_from_api_format() {
     // Test deserialization from actual API format
@@ -452,6 +707,101 @@ fn test_section_deserialization_from_api_format() {
     assert!(!section.is_deleted);
 }
 
+#[test]
+fn test_section_deserialization_without_state_flags() {
+    // Older payloads may omit the archived/deleted/collapsed flags entirely
+    let json = r#"{
+        "id": "6fFPHV272WWh3gpW",
+        "user_id": "12345678",
+        "project_id": "6XGgm6PHrGgMpCFX",
+        "added_at": "2024-01-05T09:00:00Z",
+        "updated_at": null,
+        "archived_at": null,
+        "name": "Backlog",
+        "section_order": 2
+    }"#;
+
+    let section: Section = serde_json::from_str(json).unwrap();
+    assert_eq!(section.name, "Backlog");
+    assert!(!section.is_archived);
+    assert!(!section.is_deleted);
+    assert!(!section.is_collapsed);
+}
+
+#[test]
+fn test_deserialization_from_minimal_payloads() {
+    // Some responses (sync, shared labels, webhooks) omit fields that have an obvious default
+    let task: Task = serde_json::from_str(
+        r#"{
+            "id": "6X7rM8997g3RQmvh",
+            "user_id": "2671355",
+            "content": "Buy Milk",
+            "project_id": "6Jf8VQXxpwv56VQ7",
+            "added_at": "2024-01-05T09:00:00Z"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(task.description, "");
+    assert!(task.labels.is_empty());
+    assert_eq!(task.priority, Priority::Normal);
+    assert_eq!(task.child_order, 0);
+    assert_eq!(task.day_order, 0);
+    assert!(!task.is_collapsed);
+    assert!(task.due.is_none());
+
+    let project: Project = serde_json::from_str(r#"{"id": "6Jf8VQXxpwv56VQ7", "name": "Shopping"}"#).unwrap();
+    assert_eq!(project.color, Color::Charcoal);
+    assert_eq!(project.view_style, ViewStyle::List);
+    assert!(!project.is_shared);
+    assert!(!project.is_favorite);
+    assert!(!project.inbox_project);
+
+    let label: Label = serde_json::from_str(r#"{"id": "2156154810", "name": "errand"}"#).unwrap();
+    assert_eq!(label.color, Color::Charcoal);
+    assert_eq!(label.order, None);
+    assert!(!label.is_favorite);
+
+    let section: Section = serde_json::from_str(
+        r#"{
+            "id": "6fFPHV272WWh3gpW",
+            "user_id": "12345678",
+            "project_id": "6XGgm6PHrGgMpCFX",
+            "added_at": "2024-01-05T09:00:00Z",
+            "name": "Backlog"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(section.section_order, 0);
+}
+
+#[test]
+fn test_models_compare_and_hash() {
+    use std::collections::HashSet;
+
+    let json = r#"{
+        "id": "6X7rM8997g3RQmvh",
+        "user_id": "2671355",
+        "content": "Buy Milk",
+        "project_id": "6Jf8VQXxpwv56VQ7",
+        "added_at": "2024-01-05T09:00:00Z",
+        "due": {"date": "2024-01-18", "string": "Jan 18", "lang": "en", "is_recurring": false},
+        "duration": {"amount": 15, "unit": "minute"}
+    }"#;
+    let task: Task = serde_json::from_str(json).unwrap();
+    let same: Task = serde_json::from_str(json).unwrap();
+    let mut changed = task.clone();
+    changed.content = "Buy oat milk".to_string();
+
+    assert_eq!(task, same);
+    assert_ne!(task, changed);
+
+    let tasks: HashSet<Task> = [task, same, changed].into_iter().collect();
+    assert_eq!(tasks.len(), 2);
+
+    let label: Label = serde_json::from_str(r#"{"id": "1", "name": "errand"}"#).unwrap();
+    assert_eq!(label.clone(), label);
+}
+
 #[test]
 fn test_label_deserialization_with_null_order() {
     // Test deserialization when order is null
@@ -469,6 +819,28 @@ fn test_label_deserialization_with_null_order() {
     assert_eq!(label.order, None);
 }
 
+#[test]
+fn test_label_index_maps_names_and_ids() {
+    let label = |id: &str, name: &str| -> Label {
+        serde_json::from_value(serde_json::json!({"id": id, "name": name})).unwrap()
+    };
+    let index = LabelIndex::new(vec![label("1", "errand"), label("2", "work")]);
+
+    assert_eq!(index.name_to_id("work"), Some("2"));
+    assert_eq!(index.id_to_name("1"), Some("errand"));
+    assert_eq!(index.get_by_name("errand").unwrap().id, "1");
+    assert_eq!(index.name_to_id("Work"), None);
+    assert_eq!(index.id_to_name("3"), None);
+    assert_eq!(index.labels().len(), 2);
+
+    let task_labels = vec!["work".to_string(), "errand".to_string()];
+    assert_eq!(index.resolve_names(&task_labels).unwrap(), ["2", "1"]);
+
+    let error = index.resolve_names(&["work", "shared"]).unwrap_err();
+    assert!(error.is_not_found());
+    assert!(error.to_string().contains("shared"));
+}
+
 #[test]
 fn test_comment_deserialization_from_api_format() {
     // Test deserialization from actual API format (uses file_attachment, not attachment)
@@ -533,3 +905,737 @@ fn test_paginated_response_deserialization() {
         Some("14540000435w8hj8pXXwPQJJch.X9DBH8ya2Xenok55".to_string())
     );
 }
+
+#[test]
+fn test_priority_wire_format() {
+    assert_eq!(serde_json::to_value(Priority::Urgent).unwrap(), serde_json::json!(4));
+    assert_eq!(serde_json::to_value(Priority::Normal).unwrap(), serde_json::json!(1));
+    assert_eq!(serde_json::from_str::<Priority>("3").unwrap(), Priority::High);
+    assert_eq!(i32::from(Priority::Medium), 2);
+    assert_eq!(Priority::try_from(4).unwrap(), Priority::Urgent);
+    assert!(Priority::Urgent > Priority::Normal);
+}
+
+#[test]
+fn test_priority_rejects_out_of_range_values() {
+    assert!(serde_json::from_str::<Priority>("0").is_err());
+    assert!(serde_json::from_str::<Priority>("5").is_err());
+    match Priority::try_from(5) {
+        Err(TodoistError::ValidationError { field, .. }) => assert_eq!(field, Some("priority".to_string())),
+        _ => panic!("Expected ValidationError"),
+    }
+}
+
+#[test]
+fn test_color_wire_format() {
+    assert_eq!(
+        serde_json::to_value(Color::BerryRed).unwrap(),
+        serde_json::json!("berry_red")
+    );
+    assert_eq!(serde_json::from_str::<Color>(r#""sky_blue""#).unwrap(), Color::SkyBlue);
+    assert_eq!(Color::Charcoal.as_str(), "charcoal");
+    assert_eq!(Color::LimeGreen.to_string(), "lime_green");
+    assert_eq!("lavender".parse::<Color>().unwrap(), Color::Lavender);
+}
+
+#[test]
+fn test_color_unknown_name_round_trips() {
+    let color: Color = serde_json::from_str(r#""neon_pink""#).unwrap();
+    assert_eq!(color, Color::Other("neon_pink".to_string()));
+    assert_eq!(color.as_str(), "neon_pink");
+    assert_eq!(serde_json::to_value(&color).unwrap(), serde_json::json!("neon_pink"));
+}
+
+#[test]
+fn test_attachment_resource_type() {
+    let attachment: Attachment = serde_json::from_value(serde_json::json!({
+        "file_name": "photo.jpg",
+        "file_type": "image/jpeg",
+        "file_url": "https://example.com/photo.jpg",
+        "resource_type": "image"
+    }))
+    .unwrap();
+    assert_eq!(attachment.resource_type, ResourceType::Image);
+
+    // Unknown kinds are kept rather than failing the whole comment
+    let resource_type: ResourceType = serde_json::from_str(r#""hologram""#).unwrap();
+    assert_eq!(resource_type, ResourceType::Other("hologram".to_string()));
+    assert_eq!(
+        serde_json::to_value(ResourceType::Website).unwrap(),
+        serde_json::json!("website")
+    );
+}
+
+#[test]
+fn test_lang_wire_format() {
+    assert_eq!(serde_json::to_value(Lang::German).unwrap(), serde_json::json!("de"));
+    assert_eq!(Lang::from("tw"), Lang::TraditionalChinese);
+
+    // Codes are case-sensitive, so a wrong one stays visible instead of being guessed
+    assert_eq!(Lang::from("EN"), Lang::Other("EN".to_string()));
+
+    let args = CreateTaskArgs::builder("Einkaufen")
+        .due_string("morgen")
+        .due_lang(Lang::German)
+        .build();
+    assert_eq!(serde_json::to_value(&args).unwrap()["due_lang"], "de");
+}
+
+#[test]
+fn test_duration_unit_and_view_style_wire_format() {
+    assert_eq!(
+        serde_json::to_value(DurationUnit::Day).unwrap(),
+        serde_json::json!("day")
+    );
+    assert_eq!(
+        serde_json::from_str::<DurationUnit>(r#""minute""#).unwrap(),
+        DurationUnit::Minute
+    );
+    assert_eq!(
+        serde_json::to_value(ViewStyle::Board).unwrap(),
+        serde_json::json!("board")
+    );
+    assert_eq!(
+        serde_json::from_str::<ViewStyle>(r#""calendar""#).unwrap(),
+        ViewStyle::Calendar
+    );
+
+    // Unknown values are preserved rather than rejected
+    let view_style: ViewStyle = serde_json::from_str(r#""timeline""#).unwrap();
+    assert_eq!(view_style, ViewStyle::Other("timeline".to_string()));
+    assert_eq!(
+        serde_json::to_value(&view_style).unwrap(),
+        serde_json::json!("timeline")
+    );
+    assert_eq!("week".parse::<DurationUnit>().unwrap().as_str(), "week");
+
+    let duration: Duration = serde_json::from_str(r#"{"amount": 2, "unit": "day"}"#).unwrap();
+    assert_eq!(duration.unit, DurationUnit::Day);
+}
+
+#[test]
+fn test_create_task_args_priority_serialization() {
+    let args = CreateTaskArgs {
+        content: "Call mom".to_string(),
+        priority: Some(Priority::Urgent),
+        ..Default::default()
+    };
+    let json = serde_json::to_value(&args).unwrap();
+    assert_eq!(json["priority"], 4);
+}
+
+#[test]
+fn test_create_task_args_builder() {
+    let args = CreateTaskArgs::builder("Buy milk")
+        .project_id("123")
+        .priority(Priority::High)
+        .due_string("tomorrow")
+        .labels(["errands", "shopping"])
+        .duration(15)
+        .duration_unit(DurationUnit::Minute)
+        .build();
+
+    assert_eq!(args.content, "Buy milk");
+    assert_eq!(args.project_id, Some("123".to_string()));
+    assert_eq!(args.priority, Some(Priority::High));
+    assert_eq!(args.due_string, Some("tomorrow".to_string()));
+    assert_eq!(args.labels, Some(vec!["errands".to_string(), "shopping".to_string()]));
+    assert_eq!(args.duration, Some(15));
+    assert!(args.section_id.is_none());
+    assert!(args.description.is_none());
+
+    // Unset fields are omitted from the request body
+    let json = serde_json::to_value(&args).unwrap();
+    assert_eq!(json.as_object().unwrap().len(), 7);
+    assert_eq!(json["priority"], 3);
+}
+
+#[test]
+fn test_create_task_args_wire_format() {
+    // `create_task` sends the serialized struct as-is, so every field must use its API name
+    let args = CreateTaskArgs {
+        content: "Plan trip".to_string(),
+        description: Some("Flights and hotel".to_string()),
+        project_id: Some("proj_1".to_string()),
+        section_id: Some("sec_1".to_string()),
+        parent_id: Some("task_0".to_string()),
+        order: Some(2),
+        priority: Some(Priority::Medium),
+        labels: Some(vec!["travel".to_string()]),
+        due_string: Some("next friday".to_string()),
+        due_date: Some("2024-05-03".to_string()),
+        due_datetime: Some("2024-05-03T09:00:00Z".to_string()),
+        due_lang: Some(Lang::English),
+        deadline_date: Some("2024-05-10".to_string()),
+        deadline_lang: Some(Lang::English),
+        duration: Some(90),
+        duration_unit: Some(DurationUnit::Minute),
+    };
+    assert_eq!(
+        serde_json::to_value(&args).unwrap(),
+        serde_json::json!({
+            "content": "Plan trip",
+            "description": "Flights and hotel",
+            "project_id": "proj_1",
+            "section_id": "sec_1",
+            "parent_id": "task_0",
+            "order": 2,
+            "priority": 2,
+            "labels": ["travel"],
+            "due_string": "next friday",
+            "due_date": "2024-05-03",
+            "due_datetime": "2024-05-03T09:00:00Z",
+            "due_lang": "en",
+            "deadline_date": "2024-05-10",
+            "deadline_lang": "en",
+            "duration": 90,
+            "duration_unit": "minute"
+        })
+    );
+}
+
+#[test]
+fn test_create_task_args_builder_content_only() {
+    let json = serde_json::to_value(CreateTaskArgs::builder("Call mom").build()).unwrap();
+    assert_eq!(json, serde_json::json!({"content": "Call mom"}));
+}
+
+#[test]
+fn test_create_task_args_builder_empty_labels() {
+    // An explicitly empty label set is sent, unlike an unset one
+    let args = CreateTaskArgs::builder("Call mom").labels(Vec::<String>::new()).build();
+    assert_eq!(
+        serde_json::to_value(args).unwrap(),
+        serde_json::json!({"content": "Call mom", "labels": []})
+    );
+}
+
+#[test]
+fn test_paginated_response_from_bare_array() {
+    // Endpoints that return a bare array are treated as a single, final page
+    let json = r#"[
+        {"id": "label1", "name": "work", "color": "red", "order": 1, "is_favorite": false},
+        {"id": "label2", "name": "home", "color": "blue", "order": null, "is_favorite": true}
+    ]"#;
+
+    let page: Page<Label> = serde_json::from_str(json).unwrap();
+    assert_eq!(page.results.len(), 2);
+    assert_eq!(page.results[1].name, "home");
+    assert!(page.next_cursor.is_none());
+}
+
+#[test]
+fn test_paginated_response_from_items_key() {
+    // The completed-tasks endpoints return their page under `items`
+    let json = r#"{
+        "items": [{"id": "label1", "name": "work", "color": "red", "order": 1, "is_favorite": false}],
+        "next_cursor": "abc"
+    }"#;
+
+    let page: Page<Label> = serde_json::from_str(json).unwrap();
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.next_cursor, Some("abc".to_string()));
+}
+
+#[test]
+fn test_paginated_response_missing_results() {
+    let result: Result<Page<Label>, _> = serde_json::from_str(r#"{"next_cursor": null}"#);
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("missing field `results`"));
+}
+
+fn sortable_task(id: &str, priority: i32, due: Option<(&str, Option<&str>)>, child_order: i32) -> Task {
+    let mut task: Task = serde_json::from_value(serde_json::json!({
+        "id": id,
+        "user_id": "user123",
+        "content": id,
+        "description": "",
+        "project_id": "proj_123",
+        "section_id": null,
+        "parent_id": null,
+        "added_by_uid": null,
+        "assigned_by_uid": null,
+        "responsible_uid": null,
+        "labels": [],
+        "deadline": null,
+        "duration": null,
+        "added_at": "2024-01-01T00:00:00Z",
+        "completed_at": null,
+        "completed_by_uid": null,
+        "updated_at": null,
+        "due": null,
+        "priority": priority,
+        "child_order": child_order,
+        "day_order": 0,
+        "is_collapsed": false
+    }))
+    .unwrap();
+    task.due = due.map(|(date, datetime)| Due {
+        string: date.to_string(),
+        date: date.parse().unwrap(),
+        is_recurring: false,
+        datetime: datetime.map(|d| d.parse().unwrap()),
+        timezone: None,
+        lang: None,
+    });
+    task
+}
+
+#[test]
+fn test_task_urls() {
+    let task = sortable_task("6X7rM8997g3RQmvh", 1, None, 0);
+    assert_eq!(task.web_url(), "https://app.todoist.com/app/task/6X7rM8997g3RQmvh");
+    assert_eq!(task.web_url(), task_web_url("6X7rM8997g3RQmvh"));
+    assert_eq!(task.app_url(), "todoist://task?id=6X7rM8997g3RQmvh");
+}
+
+#[test]
+fn test_task_is_recurring() {
+    let mut task = sortable_task("task", 1, None, 0);
+    assert!(!task.is_recurring());
+
+    task = sortable_task("task", 1, Some(("2024-01-20", None)), 0);
+    assert!(!task.is_recurring());
+
+    task.due.as_mut().unwrap().is_recurring = true;
+    assert!(task.is_recurring());
+}
+
+#[test]
+fn test_sort_tasks_like_app() {
+    let mut tasks = vec![
+        sortable_task("undated_p1", 1, None, 0),
+        sortable_task("dated_p1_late", 1, Some(("2024-01-21", None)), 0),
+        sortable_task("dated_p1_early", 1, Some(("2024-01-20", None)), 5),
+        sortable_task("dated_p1_early_first", 1, Some(("2024-01-20", None)), 1),
+        sortable_task("undated_urgent", 4, None, 0),
+        sortable_task("dated_high", 3, Some(("2024-01-25", None)), 0),
+    ];
+
+    sort_tasks_like_app(&mut tasks);
+
+    let ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "undated_urgent",
+            "dated_high",
+            "dated_p1_early_first",
+            "dated_p1_early",
+            "dated_p1_late",
+            "undated_p1"
+        ]
+    );
+}
+
+fn ordered_project(id: &str, parent_id: Option<&str>, child_order: i32) -> Project {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "name": id,
+        "parent_id": parent_id,
+        "child_order": child_order
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_sort_tasks_and_sections_by_order() {
+    let mut tasks = vec![
+        sortable_task("third", 4, None, 3),
+        sortable_task("first", 1, None, 1),
+        sortable_task("second", 1, None, 2),
+    ];
+    sort_tasks_by_order(&mut tasks);
+    let ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["first", "second", "third"]);
+
+    let section = |id: &str, section_order: i32| -> Section {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "user_id": "user123",
+            "project_id": "proj_123",
+            "added_at": "2024-01-01T00:00:00Z",
+            "name": id,
+            "section_order": section_order
+        }))
+        .unwrap()
+    };
+    let mut sections = vec![section("later", 2), section("earlier", 1)];
+    sort_sections(&mut sections);
+    assert_eq!(sections[0].id, "earlier");
+    assert_eq!(sections[1].id, "later");
+}
+
+#[test]
+fn test_sort_projects_hierarchical() {
+    let projects = vec![
+        ordered_project("work", None, 2),
+        ordered_project("work_later", Some("work"), 2),
+        ordered_project("home", None, 1),
+        ordered_project("work_first", Some("work"), 1),
+        ordered_project("work_first_child", Some("work_first"), 1),
+        ordered_project("orphan", Some("archived_parent"), 0),
+    ];
+
+    let sorted = sort_projects_hierarchical(&projects);
+    let ordered: Vec<(&str, usize)> = sorted
+        .iter()
+        .map(|(project, depth)| (project.id.as_str(), *depth))
+        .collect();
+    assert_eq!(
+        ordered,
+        vec![
+            ("orphan", 0),
+            ("home", 0),
+            ("work", 0),
+            ("work_first", 1),
+            ("work_first_child", 2),
+            ("work_later", 1),
+        ]
+    );
+}
+
+#[test]
+fn test_sort_key_compares_due_times_across_offsets() {
+    // 09:00 in New York (-05:00) is 14:00 UTC, after 13:00 UTC
+    let new_york = sortable_task(
+        "new_york",
+        1,
+        Some(("2024-01-20", Some("2024-01-20T09:00:00-05:00"))),
+        0,
+    );
+    let utc = sortable_task("utc", 1, Some(("2024-01-20", Some("2024-01-20T13:00:00Z"))), 0);
+    let all_day = sortable_task("all_day", 1, Some(("2024-01-20", None)), 0);
+
+    assert!(utc.default_sort_key() < new_york.default_sort_key());
+    assert!(new_york.default_sort_key() < all_day.default_sort_key());
+}
+
+#[test]
+fn test_sort_key_ignores_time_in_due_date() {
+    // Same order with and without the `chrono` feature: only `datetime` carries a time
+    let mut with_time = sortable_task("with_time", 1, None, 2);
+    with_time.due = Some(
+        serde_json::from_value(serde_json::json!({
+            "string": "Jan 20",
+            "date": "2024-01-20T08:00:00",
+            "is_recurring": false,
+            "datetime": null
+        }))
+        .unwrap(),
+    );
+    let all_day = sortable_task("all_day", 1, Some(("2024-01-20", None)), 1);
+
+    let mut tasks = vec![with_time, all_day];
+    sort_tasks_like_app(&mut tasks);
+    let ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["all_day", "with_time"]);
+}
+
+fn child_task(id: &str, parent_id: Option<&str>) -> Task {
+    let mut task = sortable_task(id, 1, None, 0);
+    task.parent_id = parent_id.map(str::to_string);
+    task
+}
+
+#[test]
+fn test_build_task_tree() {
+    let tasks = vec![
+        child_task("root", None),
+        child_task("child_b", Some("root")),
+        child_task("grandchild", Some("child_a")),
+        child_task("child_a", Some("root")),
+        child_task("orphan", Some("completed_parent")),
+    ];
+
+    let tree = build_task_tree(&tasks);
+
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree[0].task.id, "root");
+    let children: Vec<&str> = tree[0].children.iter().map(|n| n.task.id.as_str()).collect();
+    assert_eq!(children, vec!["child_b", "child_a"]);
+    assert_eq!(tree[0].children[1].children[0].task.id, "grandchild");
+    assert_eq!(tree[1].task.id, "orphan");
+    assert!(tree[1].children.is_empty());
+}
+
+#[test]
+fn test_build_task_tree_breaks_parent_cycles() {
+    let tasks = vec![child_task("a", Some("b")), child_task("b", Some("a"))];
+
+    let tree = build_task_tree(&tasks);
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].task.id, "a");
+    assert_eq!(tree[0].children[0].task.id, "b");
+    assert!(tree[0].children[0].children.is_empty());
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_feature {
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+    use todoist_api::*;
+
+    #[test]
+    fn test_deadline_is_naive_date() {
+        let deadline: Deadline = serde_json::from_str(r#"{"date": "2024-03-31", "lang": "en"}"#).unwrap();
+        assert_eq!(deadline.date, NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+    }
+
+    #[test]
+    fn test_fixed_due_datetime() {
+        let json = r#"{
+            "date": "2024-01-18",
+            "string": "Jan 18 at 2pm",
+            "lang": "en",
+            "is_recurring": false,
+            "datetime": "2024-01-18T14:00:00Z",
+            "timezone": "Europe/Paris"
+        }"#;
+
+        let due: Due = serde_json::from_str(json).unwrap();
+        assert_eq!(due.date, NaiveDate::from_ymd_opt(2024, 1, 18).unwrap());
+        assert_eq!(
+            due.datetime,
+            Some(DueDateTime::Fixed(Utc.with_ymd_and_hms(2024, 1, 18, 14, 0, 0).unwrap()))
+        );
+        assert_eq!(due.timezone.as_deref(), Some("Europe/Paris"));
+    }
+
+    #[test]
+    fn test_floating_due_datetime() {
+        // Floating due datetimes have no offset and no timezone
+        let json = r#"{
+            "date": "2024-01-18",
+            "string": "Jan 18 at 2pm",
+            "lang": "en",
+            "is_recurring": false,
+            "datetime": "2024-01-18T14:00:00",
+            "timezone": null
+        }"#;
+
+        let due: Due = serde_json::from_str(json).unwrap();
+        let datetime = due.datetime.unwrap();
+        assert!(datetime.is_floating());
+        assert_eq!(
+            datetime,
+            DueDateTime::Floating(
+                NaiveDate::from_ymd_opt(2024, 1, 18)
+                    .unwrap()
+                    .and_hms_opt(14, 0, 0)
+                    .unwrap()
+            )
+        );
+        assert!(due.timezone.is_none());
+
+        // Round-trips without gaining an offset
+        let serialized = serde_json::to_value(&due).unwrap();
+        assert_eq!(serialized["datetime"], "2024-01-18T14:00:00");
+    }
+
+    #[test]
+    fn test_is_due_on_converts_fixed_datetimes() {
+        let due: Due = serde_json::from_str(
+            r#"{
+                "date": "2024-01-18",
+                "string": "Jan 18 at 11:30pm",
+                "is_recurring": false,
+                "datetime": "2024-01-18T23:30:00Z",
+                "timezone": "Europe/Paris"
+            }"#,
+        )
+        .unwrap();
+        let jan_18 = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
+        let jan_19 = NaiveDate::from_ymd_opt(2024, 1, 19).unwrap();
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+
+        assert!(due.is_due_on(jan_18, &Utc));
+        // Past midnight in Paris, still the evening before in New York
+        assert!(due.is_due_on(jan_19, &paris));
+        assert!(!due.is_due_on(jan_18, &paris));
+        assert_eq!(due.date_in(&new_york), jan_18);
+    }
+
+    #[test]
+    fn test_is_due_on_floating_and_all_day() {
+        let jan_18 = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
+        let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+        let honolulu = FixedOffset::west_opt(10 * 3600).unwrap();
+
+        // Wall-clock values fall on the same date whatever the timezone
+        let floating: Due = serde_json::from_str(
+            r#"{"date": "2024-01-18", "string": "Jan 18 at 11:30pm", "is_recurring": false,
+                "datetime": "2024-01-18T23:30:00", "timezone": null}"#,
+        )
+        .unwrap();
+        let all_day: Due = serde_json::from_str(
+            r#"{"date": "2024-01-18", "string": "every day", "is_recurring": true,
+                "datetime": null, "timezone": null}"#,
+        )
+        .unwrap();
+        for due in [&floating, &all_day] {
+            assert!(due.is_due_on(jan_18, &tokyo));
+            assert!(due.is_due_on(jan_18, &honolulu));
+            assert!(!due.is_due_on(jan_18.succ_opt().unwrap(), &tokyo));
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence_after_fixed_datetime_near_midnight() {
+        let due: Due = serde_json::from_str(
+            r#"{"date": "2024-01-18", "string": "every 2 days at 11:30pm", "is_recurring": true,
+                "datetime": "2024-01-18T23:30:00Z", "timezone": "UTC"}"#,
+        )
+        .unwrap();
+        let date = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let paris = FixedOffset::east_opt(3600).unwrap();
+
+        assert_eq!(due.next_occurrence_after(date(17), &Utc), Some(date(18)));
+        assert_eq!(due.next_occurrence_after(date(18), &Utc), Some(date(20)));
+        // In Paris every occurrence falls just after midnight, on the next day
+        assert_eq!(due.next_occurrence_after(date(18), &paris), Some(date(19)));
+        assert_eq!(due.next_occurrence_after(date(19), &paris), Some(date(21)));
+        // Overdue: steps past the given date
+        assert_eq!(due.next_occurrence_after(date(25), &Utc), Some(date(26)));
+    }
+
+    #[test]
+    fn test_next_occurrence_after_floating_datetime_near_midnight() {
+        let due: Due = serde_json::from_str(
+            r#"{"date": "2024-01-31", "string": "every month at 11:59pm", "is_recurring": true,
+                "datetime": "2024-01-31T23:59:00", "timezone": null}"#,
+        )
+        .unwrap();
+        let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+        let honolulu = FixedOffset::west_opt(10 * 3600).unwrap();
+        let jan_31 = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let feb_29 = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let mar_31 = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+
+        // A wall-clock time stays on its date whatever the timezone
+        for tz in [tokyo, honolulu] {
+            assert_eq!(due.next_occurrence_after(jan_31, &tz), Some(feb_29));
+            assert_eq!(due.next_occurrence_after(feb_29, &tz), Some(mar_31));
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence_after_unsupported_or_one_off() {
+        let due = |string: &str, is_recurring: bool| -> Due {
+            serde_json::from_value(serde_json::json!({
+                "date": "2024-01-18", "string": string, "is_recurring": is_recurring,
+                "datetime": null, "timezone": null
+            }))
+            .unwrap()
+        };
+        let jan_17 = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap();
+        let jan_18 = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
+
+        assert_eq!(due("Jan 18", false).next_occurrence_after(jan_17, &Utc), Some(jan_18));
+        assert_eq!(due("Jan 18", false).next_occurrence_after(jan_18, &Utc), None);
+        assert_eq!(
+            due("weekly", true).next_occurrence_after(jan_18, &Utc),
+            NaiveDate::from_ymd_opt(2024, 1, 25)
+        );
+        assert_eq!(
+            due("every other year", true).next_occurrence_after(jan_18, &Utc),
+            NaiveDate::from_ymd_opt(2026, 1, 18)
+        );
+        for string in [
+            "every monday",
+            "every! 3 days",
+            "every day until Feb 1",
+            "tous les jours",
+        ] {
+            assert_eq!(
+                due(string, true).next_occurrence_after(jan_18, &Utc),
+                None,
+                "{}",
+                string
+            );
+        }
+    }
+
+    #[test]
+    fn test_due_date_with_time_part() {
+        // Sync payloads may carry a full datetime in `date`; only the date is kept
+        let json = r#"{
+            "date": "2024-01-18T14:00:00",
+            "string": "Jan 18 at 2pm",
+            "is_recurring": false,
+            "datetime": null,
+            "timezone": null,
+            "lang": null
+        }"#;
+
+        let due: Due = serde_json::from_str(json).unwrap();
+        assert_eq!(due.date, NaiveDate::from_ymd_opt(2024, 1, 18).unwrap());
+    }
+
+    #[test]
+    fn test_timestamps_parse_as_utc() {
+        let json = r#"{
+            "id": "8ZjmT7ZVsIxOrLPi",
+            "posted_uid": "12345678",
+            "content": "Comment",
+            "file_attachment": null,
+            "uids_to_notify": null,
+            "is_deleted": false,
+            "posted_at": "2024-01-15T12:30:00+01:00",
+            "reactions": null
+        }"#;
+
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            comment.posted_at,
+            Some(Utc.with_ymd_and_hms(2024, 1, 15, 11, 30, 0).unwrap())
+        );
+    }
+}
+
+/// Assert that every field `model` serializes is present in `payload`, recursing
+/// into nested objects, so a field whose name differs from the wire name fails here
+/// instead of silently deserializing to its default
+fn assert_fields_on_wire(path: &str, model: &serde_json::Value, payload: &serde_json::Value, client_only: &[&str]) {
+    let (Some(model), Some(payload)) = (model.as_object(), payload.as_object()) else {
+        return;
+    };
+    for (key, value) in model {
+        let field = format!("{path}.{key}");
+        if client_only.contains(&field.as_str()) {
+            continue;
+        }
+        let wire = payload
+            .get(key)
+            .unwrap_or_else(|| panic!("`{field}` is not in the captured API payload"));
+        assert_fields_on_wire(&field, value, wire, client_only);
+    }
+}
+
+#[test]
+fn test_models_match_captured_api_payloads() {
+    let examples: serde_json::Value = serde_json::from_str(include_str!("fixtures/api_examples.json")).unwrap();
+
+    fn check<T: serde::de::DeserializeOwned + serde::Serialize>(
+        examples: &serde_json::Value,
+        kind: &str,
+        client_only: &[&str],
+    ) {
+        let samples = examples[kind].as_object().unwrap();
+        let payloads = samples.iter().filter(|(name, _)| name.starts_with("example"));
+        for (name, payload) in payloads {
+            let model: T = serde_json::from_value(payload.clone())
+                .unwrap_or_else(|e| panic!("{kind}.{name} does not deserialize: {e}"));
+            let serialized = serde_json::to_value(&model).unwrap();
+            assert_fields_on_wire(kind, &serialized, payload, client_only);
+        }
+    }
+
+    check::<Task>(&examples, "task", &[]);
+    check::<Project>(&examples, "project", &[]);
+    check::<Section>(&examples, "section", &[]);
+    // Labels from the REST endpoints carry no deletion flag
+    check::<Label>(&examples, "label", &["label.is_deleted"]);
+    check::<Comment>(&examples, "comment", &[]);
+}
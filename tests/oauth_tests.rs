@@ -0,0 +1,65 @@
+use serde_json::json;
+use todoist_api::oauth::*;
+use todoist_api::TodoistError;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_build_authorize_url() {
+    let url = build_authorize_url("client123", &[Scope::DataRead, Scope::DataDelete], "state 1&2");
+    assert_eq!(
+        url,
+        "https://todoist.com/oauth/authorize?client_id=client123&scope=data%3Aread%2Cdata%3Adelete&state=state+1%262"
+    );
+}
+
+#[tokio::test]
+async fn test_exchange_code() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/access_token"))
+        .and(body_string_contains("client_id=client123"))
+        .and(body_string_contains("client_secret=secret"))
+        .and(body_string_contains("code=abcdef"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "0123456789abcdef",
+            "token_type": "Bearer"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let token_url = format!("{}/oauth/access_token", mock_server.uri());
+    let token = exchange_code_with_url(&token_url, "client123", "secret", "abcdef")
+        .await
+        .unwrap();
+
+    assert_eq!(token.access_token, "0123456789abcdef");
+    assert_eq!(token.token_type, "Bearer");
+
+    let debug = format!("{:?}", token);
+    assert!(!debug.contains("0123456789abcdef"));
+    assert!(debug.contains("Bearer"));
+}
+
+#[tokio::test]
+async fn test_exchange_code_rejected() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/access_token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "bad_authorization_code"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let token_url = format!("{}/oauth/access_token", mock_server.uri());
+    let result = exchange_code_with_url(&token_url, "client123", "secret", "expired").await;
+
+    match result {
+        Err(TodoistError::ValidationError { message, .. }) => assert_eq!(message, "bad_authorization_code"),
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+}
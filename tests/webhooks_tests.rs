@@ -0,0 +1,136 @@
+use serde_json::json;
+use todoist_api::webhooks::*;
+
+const CLIENT_SECRET: &str = "client_secret_123";
+const SIGNED_BODY: &[u8] = br#"{"event_name":"item:added","user_id":"2671355"}"#;
+const SIGNATURE: &str = "icjijqGmMmYPNWIiIDMgYg1Kg+MnTEBipNzhwoTjWsc=";
+
+#[test]
+fn test_verify_signature() {
+    assert!(verify_signature(CLIENT_SECRET, SIGNED_BODY, SIGNATURE));
+}
+
+#[test]
+fn test_verify_signature_rejects_mismatches() {
+    assert!(!verify_signature("other_secret", SIGNED_BODY, SIGNATURE));
+    assert!(!verify_signature(
+        CLIENT_SECRET,
+        br#"{"event_name":"item:deleted"}"#,
+        SIGNATURE
+    ));
+    assert!(!verify_signature(CLIENT_SECRET, SIGNED_BODY, "not base64!"));
+    assert!(!verify_signature(CLIENT_SECRET, SIGNED_BODY, ""));
+}
+
+#[test]
+fn test_parse_item_added() {
+    let body = json!({
+        "event_name": "item:added",
+        "user_id": "2671355",
+        "event_data": {
+            "id": "6X7rM8997g3RQmvh",
+            "user_id": "2671355",
+            "project_id": "6Jf8VQXxpwv56VQ7",
+            "content": "Buy Milk",
+            "description": "",
+            "priority": 4,
+            "labels": ["errand"],
+            "checked": false,
+            "is_deleted": false,
+            "section_id": null,
+            "parent_id": null,
+            "added_by_uid": "2671355",
+            "assigned_by_uid": null,
+            "responsible_uid": null,
+            "due": null,
+            "deadline": null,
+            "duration": null,
+            "child_order": 1,
+            "day_order": -1,
+            "is_collapsed": false,
+            "added_at": "2025-02-10T10:33:38.000000Z",
+            "completed_at": null,
+            "updated_at": "2025-02-10T10:33:38.000000Z"
+        },
+        "initiator": {
+            "id": "2671355",
+            "email": "alice@example.com",
+            "full_name": "Alice",
+            "is_premium": true
+        },
+        "triggered_at": "2025-02-10T10:39:38.000000Z",
+        "version": "10"
+    });
+
+    let payload = WebhookPayload::from_slice(body.to_string().as_bytes()).unwrap();
+    assert_eq!(payload.user_id, "2671355");
+    assert_eq!(payload.version.as_deref(), Some("10"));
+    assert_eq!(payload.initiator.unwrap().full_name.as_deref(), Some("Alice"));
+    assert_eq!(payload.event.name(), "item:added");
+    match payload.event {
+        Event::ItemAdded(task) => {
+            assert_eq!(task.content, "Buy Milk");
+            assert_eq!(task.priority, todoist_api::Priority::Urgent);
+        }
+        other => panic!("Expected ItemAdded, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_note_and_project_events() {
+    let note = json!({
+        "event_name": "note:added",
+        "user_id": "2671355",
+        "event_data": {
+            "id": "note_1",
+            "content": "Don't forget oat milk",
+            "posted_at": "2025-02-10T10:40:00Z",
+            "item_id": "6X7rM8997g3RQmvh"
+        }
+    });
+    let payload: WebhookPayload = serde_json::from_value(note).unwrap();
+    assert!(matches!(payload.event, Event::NoteAdded(ref comment) if comment.content == "Don't forget oat milk"));
+
+    let project = json!({
+        "event_name": "project:archived",
+        "user_id": "2671355",
+        "event_data": {
+            "id": "proj_1",
+            "name": "Old stuff",
+            "color": "charcoal",
+            "is_shared": false,
+            "is_favorite": false,
+            "inbox_project": false,
+            "view_style": "list",
+            "is_archived": true
+        }
+    });
+    let payload: WebhookPayload = serde_json::from_value(project).unwrap();
+    match payload.event {
+        Event::ProjectArchived(project) => assert!(project.is_archived),
+        other => panic!("Expected ProjectArchived, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_event_keeps_raw_data() {
+    let body = json!({
+        "event_name": "reminder:fired",
+        "user_id": "2671355",
+        "event_data": {"id": "reminder_1", "item_id": "task_1"}
+    });
+
+    let payload: WebhookPayload = serde_json::from_value(body).unwrap();
+    assert_eq!(payload.event.name(), "reminder:fired");
+    match payload.event {
+        Event::Other { event_data, .. } => assert_eq!(event_data["item_id"], "task_1"),
+        other => panic!("Expected Other, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_malformed_event_data_is_a_parse_error() {
+    let body = br#"{"event_name": "item:added", "user_id": "2671355", "event_data": {"id": 1}}"#;
+    let error = WebhookPayload::from_slice(body).unwrap_err();
+    assert!(matches!(error, todoist_api::TodoistError::ParseError { .. }));
+}
@@ -14,7 +14,7 @@ fn test_create_task_args_builder() {
         content: "Test task".to_string(),
         description: Some("Test description".to_string()),
         project_id: Some("proj_123".to_string()),
-        priority: Some(4),
+        priority: Some(Priority::Urgent),
         labels: Some(vec!["important".to_string(), "work".to_string()]),
         due_string: Some("tomorrow".to_string()),
         ..Default::default()
@@ -23,7 +23,7 @@ fn test_create_task_args_builder() {
     assert_eq!(args.content, "Test task");
     assert_eq!(args.description, Some("Test description".to_string()));
     assert_eq!(args.project_id, Some("proj_123".to_string()));
-    assert_eq!(args.priority, Some(4));
+    assert_eq!(args.priority, Some(Priority::Urgent));
     assert_eq!(args.labels, Some(vec!["important".to_string(), "work".to_string()]));
     assert_eq!(args.due_string, Some("tomorrow".to_string()));
 }
@@ -32,14 +32,14 @@ fn test_create_task_args_builder() {
 fn test_update_task_args_builder() {
     let args = UpdateTaskArgs {
         content: Some("Updated content".to_string()),
-        priority: Some(1),
+        priority: Some(Priority::Normal),
         due_string: Some("next week".to_string()),
         labels: Some(vec!["urgent".to_string()]),
         ..Default::default()
     };
 
     assert_eq!(args.content, Some("Updated content".to_string()));
-    assert_eq!(args.priority, Some(1));
+    assert_eq!(args.priority, Some(Priority::Normal));
     assert_eq!(args.due_string, Some("next week".to_string()));
     assert_eq!(args.labels, Some(vec!["urgent".to_string()]));
 }
@@ -48,16 +48,17 @@ fn test_update_task_args_builder() {
 fn test_create_project_args_builder() {
     let args = CreateProjectArgs {
         name: "New Project".to_string(),
-        color: Some("red".to_string()),
+        color: Some(Color::Red),
         is_favorite: Some(true),
-        view_style: Some("board".to_string()),
+        view_style: Some(ViewStyle::Board),
         parent_id: None,
+        child_order: None,
     };
 
     assert_eq!(args.name, "New Project");
-    assert_eq!(args.color, Some("red".to_string()));
+    assert_eq!(args.color, Some(Color::Red));
     assert_eq!(args.is_favorite, Some(true));
-    assert_eq!(args.view_style, Some("board".to_string()));
+    assert_eq!(args.view_style, Some(ViewStyle::Board));
     assert!(args.parent_id.is_none());
 }
 
@@ -65,28 +66,28 @@ fn test_create_project_args_builder() {
 fn test_update_project_args_builder() {
     let args = UpdateProjectArgs {
         name: Some("Updated Project Name".to_string()),
-        color: Some("blue".to_string()),
+        color: Some(Color::Blue),
         is_favorite: Some(false),
-        view_style: Some("list".to_string()),
+        view_style: Some(ViewStyle::List),
     };
 
     assert_eq!(args.name, Some("Updated Project Name".to_string()));
-    assert_eq!(args.color, Some("blue".to_string()));
+    assert_eq!(args.color, Some(Color::Blue));
     assert_eq!(args.is_favorite, Some(false));
-    assert_eq!(args.view_style, Some("list".to_string()));
+    assert_eq!(args.view_style, Some(ViewStyle::List));
 }
 
 #[test]
 fn test_create_label_args_builder() {
     let args = CreateLabelArgs {
         name: "New Label".to_string(),
-        color: Some("green".to_string()),
+        color: Some(Color::Green),
         order: Some(5),
         is_favorite: Some(false),
     };
 
     assert_eq!(args.name, "New Label");
-    assert_eq!(args.color, Some("green".to_string()));
+    assert_eq!(args.color, Some(Color::Green));
     assert_eq!(args.order, Some(5));
     assert_eq!(args.is_favorite, Some(false));
 }
@@ -95,13 +96,13 @@ fn test_create_label_args_builder() {
 fn test_update_label_args_builder() {
     let args = UpdateLabelArgs {
         name: Some("Very Important".to_string()),
-        color: Some("purple".to_string()),
+        color: Some(Color::Grape),
         order: Some(10),
         is_favorite: Some(true),
     };
 
     assert_eq!(args.name, Some("Very Important".to_string()));
-    assert_eq!(args.color, Some("purple".to_string()));
+    assert_eq!(args.color, Some(Color::Grape));
     assert_eq!(args.order, Some(10));
     assert_eq!(args.is_favorite, Some(true));
 }
@@ -122,10 +123,13 @@ fn test_create_section_args_builder() {
 #[test]
 fn test_update_section_args_builder() {
     let args = UpdateSectionArgs {
-        name: "Updated Section Name".to_string(),
+        name: Some("Updated Section Name".to_string()),
+        order: Some(2),
     };
 
-    assert_eq!(args.name, "Updated Section Name");
+    assert_eq!(args.name, Some("Updated Section Name".to_string()));
+    assert_eq!(args.order, Some(2));
+    assert!(args.has_updates());
 }
 
 #[test]
@@ -134,7 +138,7 @@ fn test_create_comment_args_builder() {
         file_name: "document.pdf".to_string(),
         file_type: "application/pdf".to_string(),
         file_url: "https://example.com/doc.pdf".to_string(),
-        resource_type: "file".to_string(),
+        resource_type: ResourceType::File,
     };
 
     let args = CreateCommentArgs {
@@ -163,13 +167,14 @@ fn test_update_comment_args_builder() {
 fn test_task_filter_args_builder() {
     let args = TaskFilterArgs {
         query: "overdue".to_string(),
-        lang: Some("en".to_string()),
+        lang: Some(Lang::English),
         limit: Some(50),
         cursor: Some("cursor_123".to_string()),
+        ..Default::default()
     };
 
     assert_eq!(args.query, "overdue");
-    assert_eq!(args.lang, Some("en".to_string()));
+    assert_eq!(args.lang, Some(Lang::English));
     assert_eq!(args.limit, Some(50));
     assert_eq!(args.cursor, Some("cursor_123".to_string()));
 }
@@ -242,12 +247,12 @@ fn test_serde_serialization() {
         duration: None,
         checked: false,
         is_deleted: false,
-        added_at: "2024-01-01T00:00:00Z".to_string(),
+        added_at: "2024-01-01T00:00:00Z".parse().unwrap(),
         completed_at: None,
         completed_by_uid: None,
         updated_at: None,
         due: None,
-        priority: 3,
+        priority: Priority::High,
         child_order: 0,
         note_count: 0,
         day_order: 0,
@@ -304,13 +309,13 @@ fn test_serde_deserialization() {
     assert_eq!(task.content, "Deserialized task");
     assert_eq!(task.description, "Test deserialization");
     assert_eq!(task.project_id, "proj_456");
-    assert_eq!(task.priority, 4);
+    assert_eq!(task.priority, Priority::Urgent);
     assert_eq!(task.labels.len(), 2);
     assert!(task.labels.contains(&"deserialized".to_string()));
     assert!(task.labels.contains(&"test".to_string()));
     assert_eq!(task.note_count, 1);
     assert!(task.checked);
-    assert_eq!(task.completed_at, Some("2024-01-02T10:00:00Z".to_string()));
+    assert_eq!(task.completed_at, Some("2024-01-02T10:00:00Z".parse().unwrap()));
 }
 
 #[test]
@@ -331,12 +336,12 @@ fn test_clone_functionality() {
         duration: None,
         checked: false,
         is_deleted: false,
-        added_at: "2024-01-03T00:00:00Z".to_string(),
+        added_at: "2024-01-03T00:00:00Z".parse().unwrap(),
         completed_at: None,
         completed_by_uid: None,
         updated_at: None,
         due: None,
-        priority: 2,
+        priority: Priority::Medium,
         child_order: 0,
         note_count: 0,
         day_order: 0,
@@ -373,12 +378,12 @@ fn test_debug_formatting() {
         duration: None,
         checked: false,
         is_deleted: false,
-        added_at: "2024-01-01T00:00:00Z".to_string(),
+        added_at: "2024-01-01T00:00:00Z".parse().unwrap(),
         completed_at: None,
         completed_by_uid: None,
         updated_at: None,
         due: None,
-        priority: 1,
+        priority: Priority::Normal,
         child_order: 0,
         note_count: 0,
         day_order: 0,